@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use spore_dob_1::decoder::pipeline::dobs_decode_host;
+use spore_dob_1::simulator::{syscall_exit, write_output, Simulator};
+
+// usage: dob1-cli <dob0 output file> <pattern file> [config file] [-o <dir>]
+//
+// decodes like the on-chain binary with the simulator's combiner standing in for the
+// host, then writes the traits and images into `dir`, the working directory by default
+fn main() {
+    let mut dir = PathBuf::from(".");
+    let mut files = Vec::new();
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-o" {
+            let Some(path) = args.next() else {
+                eprintln!("-o needs a directory");
+                syscall_exit(1)
+            };
+            dir = path.into();
+            continue;
+        }
+        let content = std::fs::read(&arg).unwrap_or_else(|err| {
+            eprintln!("{}: {err}", arg.to_string_lossy());
+            syscall_exit(1)
+        });
+        files.push(content);
+    }
+    let output = match dobs_decode_host(files.iter().map(Vec::as_slice).collect(), &mut Simulator) {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("decoding failed: {err} ({})", err.name());
+            syscall_exit(err.code())
+        }
+    };
+    match write_output(&dir, &output) {
+        Ok(paths) => paths.iter().for_each(|path| println!("{}", path.display())),
+        Err(err) => {
+            eprintln!("{}: {err}", dir.display());
+            syscall_exit(1)
+        }
+    }
+}
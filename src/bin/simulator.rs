@@ -0,0 +1,24 @@
+use spore_dob_1::decoder::pipeline::dobs_run_host;
+use spore_dob_1::simulator::{syscall_exit, Simulator};
+
+// usage: simulator <dob0 output> <images base> [config], an argument starting with `@`
+// is read from the file it names
+fn main() {
+    let args = std::env::args_os()
+        .skip(1)
+        .map(|arg| {
+            let arg = arg.into_encoded_bytes();
+            match arg.strip_prefix(b"@") {
+                Some(path) => {
+                    std::fs::read(String::from_utf8_lossy(path).as_ref()).unwrap_or_else(|err| {
+                        eprintln!("{}: {err}", String::from_utf8_lossy(path));
+                        syscall_exit(1)
+                    })
+                }
+                None => arg,
+            }
+        })
+        .collect::<Vec<_>>();
+    let code = dobs_run_host(args.iter().map(Vec::as_slice).collect(), &mut Simulator);
+    syscall_exit(code)
+}
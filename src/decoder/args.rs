@@ -0,0 +1,246 @@
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use serde_json::Value;
+
+use super::loader::inherit_parent_pattern;
+use super::pipeline::{self, Run};
+use super::schema::{
+    decode_pattern_directives, decode_schema_rows, expand_asset_references, expand_schema_macros,
+};
+use super::types::{
+    ChainedOutput, Cluster, Config, DOB0Output, Directives, Error, OutputEncoding, OutputMode,
+    OutputProtocol, Parameters, ReportMode, TraitSchema,
+};
+use super::{input, is_color_value, json, validate, SCHEMA_COLUMNS};
+use crate::log::{self, Diagnostic};
+use crate::stats::Counter;
+
+pub fn dobs_parse_parameters(args: Vec<&[u8]>) -> Result<Parameters, Error> {
+    parse_parameters(args, &Run::detached())
+}
+
+// the parameters of a run, whose host reads the cells the pattern names
+pub(crate) fn parse_parameters(args: Vec<&[u8]>, run: &Run) -> Result<Parameters, Error> {
+    // flags may come anywhere, the other arguments keep their positions among themselves
+    let (flags, args): (Vec<_>, Vec<_>) = args.into_iter().partition(|arg| arg.starts_with(b"--"));
+    let mut report = None;
+    let mut locale = None;
+    let mut commit = false;
+    let mut traits_only = false;
+    for flag in flags {
+        if flag == b"--commit" {
+            commit = true;
+            continue;
+        }
+        if flag == b"--traits-only" {
+            traits_only = true;
+            continue;
+        }
+        if let Some(tag) = flag.strip_prefix(b"--locale=") {
+            let tag = core::str::from_utf8(tag).map_err(|_| Error::ParseInvalidFlag)?;
+            if tag.is_empty() {
+                return Err(Error::ParseInvalidFlag);
+            }
+            locale = Some(tag.to_owned());
+            continue;
+        }
+        report = Some(match flag {
+            b"--report" => ReportMode::Append,
+            b"--report-only" => ReportMode::Only,
+            _ => return Err(Error::ParseInvalidFlag),
+        });
+    }
+    if !(2..=5).contains(&args.len()) {
+        return Err(Error::ParseInvalidArgCount);
+    }
+    let args = args
+        .into_iter()
+        .map(decode_hex_arg)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // the DOB/0 output of one token or `[token_id, dob0_output]` pairs of a batch, decoded
+    // one after the other against the same pattern
+    let (dob0_output, tokens) = {
+        let output = &args[0];
+        match output.split_first() {
+            None => return Err(Error::ParseInvalidDOB0Output),
+            Some((&input::MOLECULE_TAG, data)) => (input::decode_dob0_output(data)?, None),
+            Some(_) if is_batch_input(output) => {
+                let tokens: Vec<(String, Value)> =
+                    json::from_slice(output).map_err(|_| Error::ParseInvalidDOB0Output)?;
+                let tokens = tokens
+                    .into_iter()
+                    .map(|(token_id, output)| Ok((token_id, dob0_outputs(output)?)))
+                    .collect::<Result<_, Error>>()?;
+                (Vec::new(), Some(tokens))
+            }
+            Some(_) => (decode_dob0_json(output)?, None),
+        }
+    };
+    let mut config: Config = match args.get(2) {
+        Some(value) => json::from_slice(value).map_err(|_| Error::ParseInvalidConfig)?,
+        None => Config::default(),
+    };
+    let (directives, images_base) = dobs_parse_images_base(&args[1], config.strict, run)?;
+    if config.rewrite.keys().any(String::is_empty) {
+        return Err(Error::ParseInvalidConfig);
+    }
+    for (prefix, replacement) in &directives.rewrite {
+        if !config.rewrite.contains_key(prefix) {
+            config.rewrite.insert(prefix.clone(), replacement.clone());
+        }
+    }
+    commit |= directives.commit;
+    if config.strict {
+        validate::check_strict(&images_base)?;
+    }
+    let cluster = match args.get(4) {
+        Some(value) => decode_cluster(value)?,
+        None => Cluster::default(),
+    };
+    if config.thumbnail.is_none() {
+        config.thumbnail = cluster.canvas;
+    }
+    // `--traits-only` is the `traits` mode, a config asking for another one contradicts it
+    config.mode = match (traits_only, config.mode) {
+        (true, Some(mode)) if mode != OutputMode::Traits => return Err(Error::ParseInvalidFlag),
+        (true, _) => Some(OutputMode::Traits),
+        (false, mode) => mode.or(directives.mode),
+    };
+    // the frames of one token's output could not be told from the next one's, and the
+    // molecule schema has no batches
+    if tokens.is_some()
+        && (config.output == OutputProtocol::Framed || config.encoding == OutputEncoding::Molecule)
+    {
+        return Err(Error::ParseInvalidConfig);
+    }
+    if config.output == OutputProtocol::Framed && config.encoding != OutputEncoding::Json {
+        return Err(Error::ParseInvalidConfig);
+    }
+    // the molecule `OutputImage` has no field for the referenced image
+    if config.dedupe && config.encoding == OutputEncoding::Molecule {
+        return Err(Error::ParseInvalidConfig);
+    }
+    // nor for the report, which framed runs write after the images they cannot hold back
+    if report.is_some() && config.encoding == OutputEncoding::Molecule
+        || report == Some(ReportMode::Only) && config.output == OutputProtocol::Framed
+    {
+        return Err(Error::ParseInvalidConfig);
+    }
+    // the output of another decoder whose images this pattern stacks onto, see `chained_image`
+    let chained = match args.get(3) {
+        Some(value) => {
+            json::from_slice::<ChainedOutput>(value)
+                .map_err(|_| Error::ParseInvalidChainedOutput)?
+                .images
+        }
+        None => Vec::new(),
+    };
+    Ok(Parameters {
+        dob0_output,
+        images_base,
+        directives,
+        config,
+        tokens,
+        chained,
+        cluster,
+        report,
+        locale,
+        commit,
+    })
+}
+
+// the DOB/0 render as the array itself or as the json string spore-dob-0 hands it over in
+fn decode_dob0_json(output: &[u8]) -> Result<Vec<DOB0Output>, Error> {
+    let render = match json::from_slice(output) {
+        Ok(Value::String(render)) => json::from_slice(render.as_bytes()),
+        render => render,
+    };
+    dob0_outputs(render.map_err(|_| Error::ParseInvalidDOB0Output)?)
+}
+
+// converted by hand, serde would keep no more than the message of a failed trait
+fn dob0_outputs(render: Value) -> Result<Vec<DOB0Output>, Error> {
+    let Value::Array(outputs) = render else {
+        return Err(Error::ParseInvalidDOB0Output);
+    };
+    outputs.into_iter().map(DOB0Output::try_from).collect()
+}
+
+fn decode_cluster(value: &[u8]) -> Result<Cluster, Error> {
+    let cluster: Cluster = json::from_slice(value).map_err(|_| Error::ParseInvalidClusterConfig)?;
+    let valid = cluster.background.as_deref().is_none_or(is_color_value)
+        && cluster.mime.as_deref().is_none_or(pipeline::is_mime_type)
+        && !cluster.uri_aliases.keys().any(String::is_empty);
+    if !valid {
+        return Err(Error::ParseInvalidClusterConfig);
+    }
+    Ok(cluster)
+}
+
+// a batch is an array of arrays where a single DOB/0 output is an array of objects
+fn is_batch_input(output: &[u8]) -> bool {
+    let mut bytes = output.iter().filter(|byte| !byte.is_ascii_whitespace());
+    bytes.next() == Some(&b'[') && bytes.next() == Some(&b'[')
+}
+
+// the pattern argument alone, already hex decoded, `strict` rejects rows with columns past
+// their options
+pub(crate) fn dobs_parse_images_base(
+    value: &[u8],
+    strict: bool,
+    run: &Run,
+) -> Result<(Directives, Vec<TraitSchema>), Error> {
+    let traits_pool: Vec<Vec<Value>> = match value.split_first() {
+        Some((&input::MOLECULE_TAG, data)) => input::decode_traits_pool(data)?,
+        _ => json::from_slice(value).map_err(|_| Error::ParseInvalidTraitsBase)?,
+    };
+    let (directives, traits_pool) = decode_pattern_directives(traits_pool)?;
+    let traits_pool = expand_schema_macros(&directives, traits_pool)?;
+    let mut traits_pool = expand_asset_references(&directives, traits_pool)?;
+    if let Some(hash) = &directives.inherit {
+        traits_pool = inherit_parent_pattern(hash, traits_pool, run)?;
+    }
+    if strict {
+        if let Some(row) = traits_pool
+            .iter()
+            .position(|row| row.len() > SCHEMA_COLUMNS)
+        {
+            log::diagnose(
+                run,
+                &Diagnostic {
+                    row: Some(row),
+                    value: Some(Value::Array(traits_pool[row].clone())),
+                    ..Diagnostic::new(Error::SchemaUnexpectedColumn)
+                },
+            );
+            return Err(Error::SchemaUnexpectedColumn);
+        }
+    }
+    let images_base = decode_schema_rows(traits_pool, run)?;
+    run.count(Counter::SchemasParsed, images_base.len() as u64);
+    Ok((directives, images_base))
+}
+
+// callers may pass any argument hex encoded, `0x` prefixed ones must be valid hex, a bare
+// one is only taken for hex if it isn't json as well, as a number like `10` would be
+pub(crate) fn decode_hex_arg(arg: &[u8]) -> Result<Cow<'_, [u8]>, Error> {
+    if let Some(hex) = arg.strip_prefix(b"0x") {
+        return hex::decode(hex)
+            .map(Cow::Owned)
+            .map_err(|_| Error::ParseInvalidHexArg);
+    }
+    let bare_hex = !arg.is_empty()
+        && arg.len().is_multiple_of(2)
+        && arg.iter().all(u8::is_ascii_hexdigit)
+        && json::from_slice::<Value>(arg).is_err();
+    if bare_hex {
+        return Ok(Cow::Owned(
+            hex::decode(arg).map_err(|_| Error::ParseInvalidHexArg)?,
+        ));
+    }
+    Ok(Cow::Borrowed(arg))
+}
@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+use serde_json::Value;
+
+use super::types::Error;
+
+// major types of rfc 8949
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const TEXT: u8 = 3;
+const ARRAY: u8 = 4;
+const MAP: u8 = 5;
+const SIMPLE: u8 = 7;
+
+const FALSE: u8 = 20;
+const TRUE: u8 = 21;
+const NULL: u8 = 22;
+const FLOAT64: u8 = 27;
+
+// the cbor encoding of the json data model of `value`, maps keep their key order and
+// floats are always written as doubles
+pub fn to_vec<T: serde::Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, Error> {
+    let value = serde_json::to_value(value)?;
+    let mut bytes = Vec::new();
+    encode(&value, &mut bytes);
+    Ok(bytes)
+}
+
+fn encode(value: &Value, bytes: &mut Vec<u8>) {
+    match value {
+        Value::Null => bytes.push(SIMPLE << 5 | NULL),
+        Value::Bool(false) => bytes.push(SIMPLE << 5 | FALSE),
+        Value::Bool(true) => bytes.push(SIMPLE << 5 | TRUE),
+        Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => head(UNSIGNED, value, bytes),
+            (None, Some(value)) => head(NEGATIVE, !(value as u64), bytes),
+            _ => {
+                let value = number.as_f64().unwrap_or(f64::NAN);
+                bytes.push(SIMPLE << 5 | FLOAT64);
+                bytes.extend_from_slice(&value.to_be_bytes());
+            }
+        },
+        Value::String(text) => {
+            head(TEXT, text.len() as u64, bytes);
+            bytes.extend_from_slice(text.as_bytes());
+        }
+        Value::Array(values) => {
+            head(ARRAY, values.len() as u64, bytes);
+            values.iter().for_each(|value| encode(value, bytes));
+        }
+        Value::Object(entries) => {
+            head(MAP, entries.len() as u64, bytes);
+            for (key, value) in entries {
+                head(TEXT, key.len() as u64, bytes);
+                bytes.extend_from_slice(key.as_bytes());
+                encode(value, bytes);
+            }
+        }
+    }
+}
+
+// the initial byte of an item and its argument in the fewest bytes
+fn head(major: u8, argument: u64, bytes: &mut Vec<u8>) {
+    let major = major << 5;
+    match argument {
+        0..=23 => bytes.push(major | argument as u8),
+        24..=0xff => bytes.extend_from_slice(&[major | 24, argument as u8]),
+        0x100..=0xffff => {
+            bytes.push(major | 25);
+            bytes.extend_from_slice(&(argument as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            bytes.push(major | 26);
+            bytes.extend_from_slice(&(argument as u32).to_be_bytes());
+        }
+        _ => {
+            bytes.push(major | 27);
+            bytes.extend_from_slice(&argument.to_be_bytes());
+        }
+    }
+}
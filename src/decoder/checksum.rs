@@ -0,0 +1,12 @@
+// the crc32 of png chunks and gzip trailers, bitwise as images are small enough that a
+// table isn't worth its bytes in the binary
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
@@ -0,0 +1,338 @@
+use alloc::{vec, vec::Vec};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use miniz_oxide::{deflate::compress_to_vec_zlib, inflate::decompress_to_vec_zlib_with_limit};
+
+use super::checksum::crc32;
+use super::types::{Error, ImageType, Layer, Size};
+
+// a fallback for hosts without the image combining syscall, it only understands layers
+// it can render without any network access: flat colors, raw images and `data:` URIs of
+// base64 encoded PNGs
+//
+// the canvas takes the thumbnail size if requested, otherwise the size of the first image
+// layer, every image is drawn unscaled at the top left corner and alpha blended over the
+// layers beneath it
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const DATA_URI_PREFIX: &str = "data:image/png;base64,";
+
+pub(crate) struct Bitmap {
+    pub width: u32,
+    pub height: u32,
+    // straight alpha rgba8, row by row
+    pub pixels: Vec<u8>,
+}
+
+pub fn compose_png(layers: &[Layer], thumbnail: Option<Size>) -> Result<Vec<u8>, Error> {
+    let mut images = Vec::with_capacity(layers.len());
+    for layer in layers {
+        // placing and filling layers is left to the combiner
+        if layer.transform.is_some() || layer.fill.is_some() {
+            return Err(Error::ComposeUnsupportedLayer);
+        }
+        let image = match layer.type_ {
+            // drawing gradients is left to the combiner
+            ImageType::ColorCode if super::parse_gradient(&layer.value)?.is_some() => {
+                return Err(Error::ComposeUnsupportedLayer)
+            }
+            ImageType::ColorCode => None,
+            ImageType::URI => {
+                let data = layer
+                    .value
+                    .strip_prefix(DATA_URI_PREFIX)
+                    .ok_or(Error::ComposeUnsupportedLayer)?;
+                Some(decode_png(&decode_base64(data)?)?)
+            }
+            ImageType::RawImage => {
+                let data = layer
+                    .value
+                    .strip_prefix(DATA_URI_PREFIX)
+                    .unwrap_or(&layer.value);
+                Some(decode_png(&decode_base64(data)?)?)
+            }
+            ImageType::SVG | ImageType::Text => return Err(Error::ComposeUnsupportedLayer),
+        };
+        images.push(image);
+    }
+    let (width, height) = match thumbnail {
+        Some(Size { width, height }) => (width, height),
+        None => images
+            .iter()
+            .flatten()
+            .map(|image| (image.width, image.height))
+            .next()
+            .ok_or(Error::ComposeUnsupportedLayer)?,
+    };
+    let mut canvas = Bitmap::new(width, height)?;
+    for (layer, image) in layers.iter().zip(images) {
+        match image {
+            Some(image) => canvas.draw(&image),
+            None => canvas.fill(parse_color(&layer.value)?),
+        }
+    }
+    encode_png(&canvas)
+}
+
+fn decode_base64(data: &str) -> Result<Vec<u8>, Error> {
+    STANDARD
+        .decode(data)
+        .map_err(|_| Error::ComposeInvalidImage)
+}
+
+// #RGB, #RRGGBB or #RRGGBBAA
+fn parse_color(value: &str) -> Result<[u8; 4], Error> {
+    let hex = value
+        .strip_prefix('#')
+        .ok_or(Error::DecodeBadColorCodeFormat)?;
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::DecodeBadColorCodeFormat)?;
+    match digits.as_slice() {
+        [r, g, b] => Ok([r * 17, g * 17, b * 17, 255]),
+        [r1, r2, g1, g2, b1, b2] => Ok([r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, 255]),
+        [r1, r2, g1, g2, b1, b2, a1, a2] => {
+            Ok([r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2, a1 << 4 | a2])
+        }
+        _ => Err(Error::DecodeBadColorCodeFormat),
+    }
+}
+
+impl Bitmap {
+    fn new(width: u32, height: u32) -> Result<Self, Error> {
+        if width == 0 || height == 0 {
+            return Err(Error::ComposeInvalidImage);
+        }
+        let size = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|size| size.checked_mul(4))
+            .ok_or(Error::ArithmeticOverflow)?;
+        let mut pixels = Vec::new();
+        pixels
+            .try_reserve_exact(size)
+            .map_err(|_| Error::ComposeOutOfMemory)?;
+        pixels.resize(size, 0);
+        Ok(Bitmap {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn fill(&mut self, color: [u8; 4]) {
+        self.pixels
+            .chunks_exact_mut(4)
+            .for_each(|pixel| blend(pixel, &color));
+    }
+
+    fn draw(&mut self, image: &Bitmap) {
+        let width = self.width.min(image.width) as usize * 4;
+        let rows = self.height.min(image.height) as usize;
+        let (stride, image_stride) = (self.width as usize * 4, image.width as usize * 4);
+        for row in 0..rows {
+            let target = &mut self.pixels[row * stride..][..width];
+            let source = &image.pixels[row * image_stride..][..width];
+            target
+                .chunks_exact_mut(4)
+                .zip(source.chunks_exact(4))
+                .for_each(|(pixel, color)| blend(pixel, color));
+        }
+    }
+}
+
+// source-over compositing of straight alpha colors
+fn blend(target: &mut [u8], source: &[u8]) {
+    let (source_alpha, target_alpha) = (source[3] as u32, target[3] as u32);
+    let alpha = source_alpha * 255 + target_alpha * (255 - source_alpha);
+    if alpha == 0 {
+        target.copy_from_slice(&[0; 4]);
+        return;
+    }
+    for channel in 0..3 {
+        let value = source[channel] as u32 * source_alpha * 255
+            + target[channel] as u32 * target_alpha * (255 - source_alpha);
+        target[channel] = ((value + alpha / 2) / alpha) as u8;
+    }
+    target[3] = ((alpha + 127) / 255) as u8;
+}
+
+pub(crate) fn decode_png(data: &[u8]) -> Result<Bitmap, Error> {
+    let mut rest = data
+        .strip_prefix(&PNG_SIGNATURE)
+        .ok_or(Error::ComposeInvalidImage)?;
+    let mut header = None;
+    let mut palette: &[u8] = &[];
+    let mut transparency: &[u8] = &[];
+    let mut compressed = Vec::new();
+    while rest.len() >= 12 {
+        let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let kind = &rest[4..8];
+        let chunk = rest
+            .get(8..8usize.saturating_add(length))
+            .ok_or(Error::ComposeInvalidImage)?;
+        match kind {
+            b"IHDR" if chunk.len() == 13 => header = Some(chunk),
+            b"PLTE" => palette = chunk,
+            b"tRNS" => transparency = chunk,
+            b"IDAT" => compressed.extend_from_slice(chunk),
+            b"IEND" => break,
+            _ => {}
+        }
+        rest = rest.get(12 + length..).ok_or(Error::ComposeInvalidImage)?;
+    }
+    let header = header.ok_or(Error::ComposeInvalidImage)?;
+    let width = u32::from_be_bytes(header[..4].try_into().unwrap());
+    let height = u32::from_be_bytes(header[4..8].try_into().unwrap());
+    let (depth, color_type, interlace) = (header[8], header[9], header[12]);
+    let channels = match (color_type, depth) {
+        (0, 1 | 2 | 4 | 8 | 16) | (3, 1 | 2 | 4 | 8) => 1,
+        (2, 8 | 16) => 3,
+        (4, 8 | 16) => 2,
+        (6, 8 | 16) => 4,
+        _ => return Err(Error::ComposeInvalidImage),
+    };
+    if interlace != 0 {
+        return Err(Error::ComposeInvalidImage);
+    }
+
+    let bits = channels * depth as usize;
+    let stride = (width as usize)
+        .checked_mul(bits)
+        .map(|bits| bits.div_ceil(8))
+        .ok_or(Error::ArithmeticOverflow)?;
+    let mut bitmap = Bitmap::new(width, height)?;
+    let expected = (stride + 1)
+        .checked_mul(height as usize)
+        .ok_or(Error::ArithmeticOverflow)?;
+    let raw = decompress_to_vec_zlib_with_limit(&compressed, expected)
+        .map_err(|_| Error::ComposeInvalidImage)?;
+    if raw.len() < expected {
+        return Err(Error::ComposeInvalidImage);
+    }
+    let scanlines = unfilter(&raw, stride, height as usize, bits.div_ceil(8))?;
+
+    let max = (1u16 << depth.min(8)) - 1;
+    for (row, scanline) in scanlines.chunks_exact(stride).enumerate() {
+        for column in 0..width as usize {
+            let sample = |channel: usize| -> u8 {
+                let index = column * channels + channel;
+                match depth {
+                    16 => scanline[index * 2],
+                    8 => scanline[index],
+                    _ => {
+                        let bit = index * depth as usize;
+                        let value = scanline[bit / 8] >> (8 - depth as usize - bit % 8);
+                        value & max as u8
+                    }
+                }
+            };
+            let scale = |value: u8| (value as u16 * 255 / max) as u8;
+            let pixel = match color_type {
+                0 => {
+                    let gray = if depth >= 8 {
+                        sample(0)
+                    } else {
+                        scale(sample(0))
+                    };
+                    [gray, gray, gray, 255]
+                }
+                2 => [sample(0), sample(1), sample(2), 255],
+                3 => {
+                    let index = sample(0) as usize;
+                    let color = palette
+                        .get(index * 3..index * 3 + 3)
+                        .ok_or(Error::ComposeInvalidImage)?;
+                    let alpha = transparency.get(index).copied().unwrap_or(255);
+                    [color[0], color[1], color[2], alpha]
+                }
+                4 => [sample(0), sample(0), sample(0), sample(1)],
+                _ => [sample(0), sample(1), sample(2), sample(3)],
+            };
+            let offset = (row * width as usize + column) * 4;
+            bitmap.pixels[offset..offset + 4].copy_from_slice(&pixel);
+        }
+    }
+    Ok(bitmap)
+}
+
+fn unfilter(raw: &[u8], stride: usize, height: usize, bpp: usize) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; stride * height];
+    for row in 0..height {
+        let line = &raw[row * (stride + 1)..][..stride + 1];
+        let (filter, line) = (line[0], &line[1..]);
+        let (previous, current) = output.split_at_mut(row * stride);
+        let previous = previous
+            .get(previous.len().saturating_sub(stride)..)
+            .filter(|_| row > 0);
+        let current = &mut current[..stride];
+        for index in 0..stride {
+            let left = if index >= bpp {
+                current[index - bpp]
+            } else {
+                0
+            };
+            let up = previous.map_or(0, |previous| previous[index]);
+            let upper_left = match previous {
+                Some(previous) if index >= bpp => previous[index - bpp],
+                _ => 0,
+            };
+            let predictor = match filter {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => paeth(left, up, upper_left),
+                _ => return Err(Error::ComposeInvalidImage),
+            };
+            current[index] = line[index].wrapping_add(predictor);
+        }
+    }
+    Ok(output)
+}
+
+fn paeth(left: u8, up: u8, upper_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - upper_left as i16;
+    let (a, b, c) = (
+        (estimate - left as i16).abs(),
+        (estimate - up as i16).abs(),
+        (estimate - upper_left as i16).abs(),
+    );
+    if a <= b && a <= c {
+        left
+    } else if b <= c {
+        up
+    } else {
+        upper_left
+    }
+}
+
+fn encode_png(bitmap: &Bitmap) -> Result<Vec<u8>, Error> {
+    let stride = bitmap.width as usize * 4;
+    let mut raw = Vec::new();
+    raw.try_reserve_exact((stride + 1) * bitmap.height as usize)
+        .map_err(|_| Error::ComposeOutOfMemory)?;
+    for row in bitmap.pixels.chunks_exact(stride.max(1)) {
+        raw.push(0); // no filter
+        raw.extend_from_slice(row);
+    }
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&bitmap.width.to_be_bytes());
+    header.extend_from_slice(&bitmap.height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut png = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &compress_to_vec_zlib(&raw, 6));
+    write_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
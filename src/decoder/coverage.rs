@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+use serde_json::Value;
+
+use super::args::{decode_hex_arg, dobs_parse_images_base};
+use super::matcher::{split_divisor, split_expression};
+use super::pipeline::Run;
+use super::types::{CoverageReport, Pattern, RowCoverage, ShadowedEntry, TraitSchema};
+use super::validate::{is_wildcard, range_of, shadowing_entry};
+
+// a dry run for pattern authors, which DOB/0 numbers each schema row covers, which ones
+// fall through it and which args entries can never match, the usual reasons for a group
+// rendering blank
+//
+// entries shadowed by a `["*"]` one are reported rather than rejected as a strict run
+// would, so the pattern is parsed leniently
+pub fn analyze_pattern(images_base: &[u8]) -> CoverageReport {
+    let parsed = decode_hex_arg(images_base)
+        .and_then(|value| dobs_parse_images_base(&value, false, &Run::detached()));
+    match parsed {
+        Ok((_, images_base)) => CoverageReport {
+            error: None,
+            rows: images_base.iter().enumerate().map(row_coverage).collect(),
+        },
+        Err(err) => CoverageReport {
+            error: Some(err as u64),
+            rows: Vec::new(),
+        },
+    }
+}
+
+fn row_coverage((row, schema): (usize, &TraitSchema)) -> RowCoverage {
+    let mut coverage = RowCoverage {
+        group: schema.name.clone(),
+        row,
+        dob0_trait: schema.dob0_trait.clone(),
+        covered: Vec::new(),
+        gaps: Vec::new(),
+        open: false,
+        unreachable: Vec::new(),
+    };
+    // the numbers an entry could possibly match, the remainders of a mod row
+    let (domain, entries) = match (&schema.pattern, &schema.args) {
+        (Pattern::Options | Pattern::Range, Some(Value::Array(entries))) => {
+            ((i128::MIN, i128::MAX), entries.as_slice())
+        }
+        (Pattern::Mod, Some(args)) => match split_divisor(args) {
+            Ok((divisor, entries)) => ((0, divisor - 1), entries),
+            Err(_) => return coverage,
+        },
+        (Pattern::Expr, Some(args)) => match split_expression(args) {
+            Ok((_, entries)) => ((i128::MIN, i128::MAX), entries),
+            Err(_) => return coverage,
+        },
+        (Pattern::Bucket, Some(args)) => {
+            if let Ok((size, buckets)) = split_divisor(args) {
+                let end = size.saturating_mul(buckets.len() as i128) - 1;
+                coverage.covered.push([0, end]);
+                // negative numbers and those past the last bucket
+                coverage.open = true;
+            }
+            return coverage;
+        }
+        // raw, template and weighted random rows render whatever the value
+        _ => return coverage,
+    };
+    let keys = entries
+        .iter()
+        .map(|entry| entry.as_array().and_then(|entry| entry.first()))
+        .collect::<Vec<_>>();
+    // the keys of a multi-trait row hold one key per trait, only a wildcard is understood
+    if !schema.dob0_traits.is_empty() {
+        coverage.open = !keys.iter().flatten().any(|key| is_wildcard(key));
+        return coverage;
+    }
+    let mut ranges = Vec::new();
+    let mut fallback = false;
+    for (index, key) in keys.iter().enumerate() {
+        if let Some(by) = shadowing_entry(&keys, index) {
+            coverage.unreachable.push(ShadowedEntry { index, by });
+            continue;
+        }
+        match key {
+            Some(key) if is_wildcard(key) => fallback = true,
+            Some(key) => ranges
+                .extend(range_of(key).map(|(start, end)| (start.max(domain.0), end.min(domain.1)))),
+            None => {}
+        }
+    }
+    ranges.retain(|(start, end)| start <= end);
+    ranges.sort_unstable();
+    for (start, end) in ranges {
+        match coverage.covered.last_mut() {
+            Some(last) if start <= last[1].saturating_add(1) => last[1] = last[1].max(end),
+            _ => coverage.covered.push([start, end]),
+        }
+    }
+    if fallback {
+        return coverage;
+    }
+    // a range row's span is open ended, only the holes inside what it covers are gaps
+    let span = match schema.pattern {
+        Pattern::Mod => Some(domain),
+        _ => coverage
+            .covered
+            .first()
+            .zip(coverage.covered.last())
+            .map(|(first, last)| (first[0], last[1])),
+    };
+    if let Some((mut next, end)) = span {
+        for [start, stop] in &coverage.covered {
+            if next < *start {
+                coverage.gaps.push([next, start - 1]);
+            }
+            next = stop.saturating_add(1);
+        }
+        if next <= end && coverage.covered.last().is_none_or(|last| last[1] < end) {
+            coverage.gaps.push([next, end]);
+        }
+    }
+    coverage.open = coverage.covered != [[domain.0, domain.1]];
+    coverage
+}
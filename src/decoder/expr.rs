@@ -0,0 +1,186 @@
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+
+use super::types::Error;
+
+// parentheses and unary minus nesting deeper than this are rejected
+const MAX_DEPTH: usize = 32;
+// and so are expressions of more operands, each of them a node the recursive evaluate and
+// drop walk through, so a long `a + a + ...` chain can't run them out of stack
+const MAX_OPERANDS: usize = 256;
+
+// the arithmetic of an expr row, `(Score + Age) / 10`: integers and DOB/0 traits joined by
+// `+ - * / %` with the usual precedence, unary minus and parentheses
+//
+// traits are named bare if the name is an identifier and in braces otherwise, as in
+// `{Background Color} % 4` or `{Age[1]}`, `/` and `%` are euclidean like bucket and mod rows
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Expr {
+    Number(i128),
+    Trait(String),
+    Negate(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone, Copy)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Expr {
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            text,
+            offset: 0,
+            operands: 0,
+        };
+        let expr = parser.sum(0)?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(_) => Err(Error::SchemaInvalidArgs),
+        }
+    }
+
+    // None if `lookup` finds no value for a trait, overflows and division by zero fail
+    pub fn evaluate<F>(&self, lookup: &mut F) -> Result<Option<i128>, Error>
+    where
+        F: FnMut(&str) -> Result<Option<i128>, Error>,
+    {
+        let value = match self {
+            Expr::Number(value) => Some(*value),
+            Expr::Trait(name) => lookup(name)?,
+            Expr::Negate(expr) => match expr.evaluate(lookup)? {
+                Some(value) => Some(overflow(value.checked_neg())?),
+                None => None,
+            },
+            Expr::Binary(operator, left, right) => {
+                let (Some(left), Some(right)) = (left.evaluate(lookup)?, right.evaluate(lookup)?)
+                else {
+                    return Ok(None);
+                };
+                let value = match operator {
+                    Operator::Add => left.checked_add(right),
+                    Operator::Subtract => left.checked_sub(right),
+                    Operator::Multiply => left.checked_mul(right),
+                    Operator::Divide => left.checked_div_euclid(right),
+                    Operator::Remainder => left.checked_rem_euclid(right),
+                };
+                Some(overflow(value)?)
+            }
+        };
+        Ok(value)
+    }
+}
+
+fn overflow(value: Option<i128>) -> Result<i128, Error> {
+    value.ok_or(Error::ArithmeticOverflow)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+    // operands parsed so far, negations and parentheses included
+    operands: usize,
+}
+
+impl Parser<'_> {
+    // the next byte past any whitespace
+    fn peek(&mut self) -> Option<u8> {
+        let rest = &self.text[self.offset..];
+        self.offset += rest.len() - rest.trim_start().len();
+        self.text.as_bytes().get(self.offset).copied()
+    }
+
+    // products joined by `+` and `-`
+    fn sum(&mut self, depth: usize) -> Result<Expr, Error> {
+        let mut expr = self.product(depth)?;
+        loop {
+            let operator = match self.peek() {
+                Some(b'+') => Operator::Add,
+                Some(b'-') => Operator::Subtract,
+                _ => return Ok(expr),
+            };
+            self.offset += 1;
+            let right = self.product(depth)?;
+            expr = Expr::Binary(operator, Box::new(expr), Box::new(right));
+        }
+    }
+
+    // operands joined by `*`, `/` and `%`
+    fn product(&mut self, depth: usize) -> Result<Expr, Error> {
+        let mut expr = self.operand(depth)?;
+        loop {
+            let operator = match self.peek() {
+                Some(b'*') => Operator::Multiply,
+                Some(b'/') => Operator::Divide,
+                Some(b'%') => Operator::Remainder,
+                _ => return Ok(expr),
+            };
+            self.offset += 1;
+            let right = self.operand(depth)?;
+            expr = Expr::Binary(operator, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn operand(&mut self, depth: usize) -> Result<Expr, Error> {
+        self.operands += 1;
+        if depth > MAX_DEPTH || self.operands > MAX_OPERANDS {
+            return Err(Error::SchemaInvalidArgs);
+        }
+        let byte = self.peek().ok_or(Error::SchemaInvalidArgs)?;
+        let rest = &self.text[self.offset..];
+        match byte {
+            b'-' => {
+                self.offset += 1;
+                Ok(Expr::Negate(Box::new(self.operand(depth + 1)?)))
+            }
+            b'(' => {
+                self.offset += 1;
+                let expr = self.sum(depth + 1)?;
+                if self.peek() != Some(b')') {
+                    return Err(Error::SchemaInvalidArgs);
+                }
+                self.offset += 1;
+                Ok(expr)
+            }
+            b'{' => {
+                let end = rest.find('}').ok_or(Error::SchemaInvalidArgs)?;
+                let name = &rest[1..end];
+                if name.trim().is_empty() {
+                    return Err(Error::SchemaInvalidArgs);
+                }
+                self.offset += end + 1;
+                Ok(Expr::Trait(name.to_owned()))
+            }
+            b'0'..=b'9' => {
+                let digits = self.take(|byte| byte.is_ascii_digit());
+                digits
+                    .parse()
+                    .map(Expr::Number)
+                    .map_err(|_| Error::ArithmeticOverflow)
+            }
+            byte if byte.is_ascii_alphabetic() || byte == b'_' => {
+                let name = self.take(|byte| byte.is_ascii_alphanumeric() || byte == b'_');
+                Ok(Expr::Trait(name.to_owned()))
+            }
+            _ => Err(Error::SchemaInvalidArgs),
+        }
+    }
+
+    fn take(&mut self, accept: impl Fn(u8) -> bool) -> &str {
+        let start = self.offset;
+        while self
+            .text
+            .as_bytes()
+            .get(self.offset)
+            .is_some_and(|&byte| accept(byte))
+        {
+            self.offset += 1;
+        }
+        &self.text[start..self.offset]
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
+
+use super::checksum::crc32;
+use super::types::Error;
+
+const MAGIC: [u8; 2] = [0x1f, 0x8b];
+const DEFLATE: u8 = 8;
+
+// header flags, see rfc 1952
+const FHCRC: u8 = 0x02;
+const FEXTRA: u8 = 0x04;
+const FNAME: u8 = 0x08;
+const FCOMMENT: u8 = 0x10;
+
+// inflates a single member gzip stream, its trailer's size bounds the output and its
+// crc32 has to match, anything off is `Error::DecodeCorruptCompressedImage`
+//
+// the trailer's size is the stream's own claim, a size past `limit` is rejected before
+// anything is inflated
+pub fn decompress(stream: &[u8], limit: usize) -> Result<Vec<u8>, Error> {
+    let corrupt = Error::DecodeCorruptCompressedImage;
+    if stream.len() < 18 || stream[..2] != MAGIC || stream[2] != DEFLATE {
+        return Err(corrupt);
+    }
+    let flags = stream[3];
+    let mut offset = 10;
+    if flags & FEXTRA != 0 {
+        let length = stream.get(offset..offset + 2).ok_or(corrupt)?;
+        offset += 2 + u16::from_le_bytes([length[0], length[1]]) as usize;
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let end = stream
+                .get(offset..)
+                .and_then(|rest| rest.iter().position(|&byte| byte == 0));
+            offset += end.ok_or(corrupt)? + 1;
+        }
+    }
+    if flags & FHCRC != 0 {
+        offset += 2;
+    }
+    let trailer = stream.len() - 8;
+    if offset > trailer {
+        return Err(corrupt);
+    }
+    let word = |at: usize| u32::from_le_bytes(stream[at..at + 4].try_into().unwrap());
+    let (crc, size) = (word(trailer), word(trailer + 4));
+    if size as usize > limit {
+        return Err(Error::DecodeRawImageTooLarge);
+    }
+    let data = decompress_to_vec_with_limit(&stream[offset..trailer], (size as usize).min(limit))
+        .map_err(|_| corrupt)?;
+    if data.len() != size as usize || crc32(&data) != crc {
+        return Err(corrupt);
+    }
+    Ok(data)
+}
@@ -0,0 +1,77 @@
+use alloc::{string::String, vec::Vec};
+use molecule::prelude::Reader;
+use serde_json::Value;
+
+use super::json;
+use super::types::{DOB0Output, Error, ParsedTrait};
+use crate::generated::{
+    BytesReader, CellUnionReader, RowVecReader, TraitEntryVecReader, TraitValueUnionReader,
+    Uint64Reader,
+};
+
+// an argument starting with this byte is the molecule encoding defined in
+// `dob1_syscall.mol` rather than json text, which never starts with it
+//
+// argv entries are null terminated, so molecule arguments have to be passed hex encoded
+pub const MOLECULE_TAG: u8 = 0x01;
+
+pub fn decode_dob0_output(data: &[u8]) -> Result<Vec<DOB0Output>, Error> {
+    let entries = TraitEntryVecReader::from_slice(data)?;
+    entries
+        .iter()
+        .map(|entry| {
+            let traits = entry
+                .traits()
+                .iter()
+                .map(|value| match value.to_enum() {
+                    TraitValueUnionReader::Bytes(text) => {
+                        decode_text(text).map(ParsedTrait::String)
+                    }
+                    TraitValueUnionReader::Uint64(number) => {
+                        Some(ParsedTrait::Number(decode_number(number).into()))
+                    }
+                    TraitValueUnionReader::Int128(number) => {
+                        let bytes = number.raw_data().try_into().ok()?;
+                        Some(ParsedTrait::Number(i128::from_le_bytes(bytes)))
+                    }
+                    TraitValueUnionReader::Float64(number) => {
+                        let bytes = number.raw_data().try_into().ok()?;
+                        Some(ParsedTrait::Float(f64::from_le_bytes(bytes)))
+                    }
+                })
+                .collect::<Option<_>>();
+            Some(DOB0Output {
+                name: decode_text(entry.name())?,
+                traits: traits?,
+            })
+        })
+        .collect::<Option<_>>()
+        .ok_or(Error::ParseInvalidDOB0Output)
+}
+
+// the rows of the traits base, each cell as the json value it stands for
+pub fn decode_traits_pool(data: &[u8]) -> Result<Vec<Vec<Value>>, Error> {
+    let rows = RowVecReader::from_slice(data).map_err(|_| Error::ParseInvalidTraitsBase)?;
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell.to_enum() {
+                    CellUnionReader::Bytes(text) => decode_text(text).map(Value::String),
+                    CellUnionReader::Uint64(number) => Some(Value::from(decode_number(number))),
+                    CellUnionReader::Json(json) => json::from_slice(json.raw_data()).ok(),
+                })
+                .collect::<Option<_>>()
+        })
+        .collect::<Option<_>>()
+        .ok_or(Error::ParseInvalidTraitsBase)
+}
+
+fn decode_text(text: BytesReader) -> Option<String> {
+    String::from_utf8(text.raw_data().to_vec()).ok()
+}
+
+fn decode_number(number: Uint64Reader) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(number.raw_data());
+    u64::from_le_bytes(bytes)
+}
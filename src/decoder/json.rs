@@ -0,0 +1,823 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
+
+// nesting deeper than this is rejected, as serde_json does
+const MAX_DEPTH: usize = 128;
+
+// every json document the decoder reads goes through here, `tiny-json` builds parse it
+// with `parse` and leave only serde's `Value` conversions linked in
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_json::Error> {
+    if cfg!(feature = "tiny-json") {
+        serde_json::from_value(parse(bytes)?)
+    } else {
+        serde_json::from_slice(bytes)
+    }
+}
+
+// and every one it writes, `write` puts out the same bytes serde_json does
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    if cfg!(feature = "tiny-json") {
+        let mut bytes = Vec::new();
+        write(value, &mut bytes)?;
+        Ok(bytes)
+    } else {
+        serde_json::to_vec(value)
+    }
+}
+
+pub fn write<T: Serialize + ?Sized>(
+    value: &T,
+    bytes: &mut Vec<u8>,
+) -> Result<(), serde_json::Error> {
+    value.serialize(Writer {
+        bytes,
+        canonical: false,
+    })
+}
+
+// json whose bytes only depend on the value, whichever serde_json the decoder is built
+// with: `write`'s compact output with the keys of every object sorted by their bytes as
+// written, strings escaped only where json requires it and floats as strings of their
+// shortest decimal that reads back to them, see `OutputEncoding::Canonical`
+pub fn to_canonical_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let mut bytes = Vec::new();
+    value.serialize(Writer {
+        bytes: &mut bytes,
+        canonical: true,
+    })?;
+    Ok(bytes)
+}
+
+pub fn parse(bytes: &[u8]) -> Result<Value, serde_json::Error> {
+    let mut reader = Reader { bytes, offset: 0 };
+    let value = reader.value(0)?;
+    reader.skip_whitespace();
+    match reader.peek() {
+        None => Ok(value),
+        Some(_) => Err(reader.error("trailing characters")),
+    }
+}
+
+fn write_string(text: &str, bytes: &mut Vec<u8>) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    bytes.push(b'"');
+    for &byte in text.as_bytes() {
+        match byte {
+            b'"' => bytes.extend_from_slice(b"\\\""),
+            b'\\' => bytes.extend_from_slice(b"\\\\"),
+            b'\n' => bytes.extend_from_slice(b"\\n"),
+            b'\r' => bytes.extend_from_slice(b"\\r"),
+            b'\t' => bytes.extend_from_slice(b"\\t"),
+            0x08 => bytes.extend_from_slice(b"\\b"),
+            0x0c => bytes.extend_from_slice(b"\\f"),
+            0..=0x1f => bytes.extend_from_slice(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX[(byte >> 4) as usize],
+                HEX[(byte & 0xf) as usize],
+            ]),
+            _ => bytes.push(byte),
+        }
+    }
+    bytes.push(b'"');
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, message: &str) -> serde_json::Error {
+        serde::de::Error::custom(format_args!("{message} at offset {}", self.offset))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, serde_json::Error> {
+        let byte = self.peek().ok_or_else(|| self.error("unexpected end"))?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.offset += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &[u8]) -> Result<(), serde_json::Error> {
+        match self.bytes[self.offset..].starts_with(literal) {
+            true => {
+                self.offset += literal.len();
+                Ok(())
+            }
+            false => Err(self.error("expected literal")),
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, serde_json::Error> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("recursion limit exceeded"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.expect(b"null").map(|_| Value::Null),
+            Some(b't') => self.expect(b"true").map(|_| Value::Bool(true)),
+            Some(b'f') => self.expect(b"false").map(|_| Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[') => {
+                self.offset += 1;
+                let mut values = Vec::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.offset += 1;
+                    return Ok(Value::Array(values));
+                }
+                loop {
+                    values.push(self.value(depth + 1)?);
+                    self.skip_whitespace();
+                    match self.next()? {
+                        b',' => continue,
+                        b']' => return Ok(Value::Array(values)),
+                        _ => return Err(self.error("expected `,` or `]`")),
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.offset += 1;
+                let mut entries = Map::new();
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.offset += 1;
+                    return Ok(Value::Object(entries));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() != Some(b'"') {
+                        return Err(self.error("expected key"));
+                    }
+                    let key = self.string()?;
+                    self.skip_whitespace();
+                    if self.next()? != b':' {
+                        return Err(self.error("expected `:`"));
+                    }
+                    let value = self.value(depth + 1)?;
+                    entries.insert(key, value);
+                    self.skip_whitespace();
+                    match self.next()? {
+                        b',' => continue,
+                        b'}' => return Ok(Value::Object(entries)),
+                        _ => return Err(self.error("expected `,` or `}`")),
+                    }
+                }
+            }
+            Some(b'-' | b'0'..=b'9') => self.number().map(Value::Number),
+            _ => Err(self.error("expected value")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, serde_json::Error> {
+        self.offset += 1;
+        let mut text = Vec::new();
+        loop {
+            match self.next()? {
+                b'"' => break,
+                b'\\' => match self.next()? {
+                    b'"' => text.push(b'"'),
+                    b'\\' => text.push(b'\\'),
+                    b'/' => text.push(b'/'),
+                    b'b' => text.push(0x08),
+                    b'f' => text.push(0x0c),
+                    b'n' => text.push(b'\n'),
+                    b'r' => text.push(b'\r'),
+                    b't' => text.push(b'\t'),
+                    b'u' => {
+                        let char = self.escaped_char()?;
+                        text.extend_from_slice(char.encode_utf8(&mut [0; 4]).as_bytes());
+                    }
+                    _ => return Err(self.error("invalid escape")),
+                },
+                0..=0x1f => return Err(self.error("control character in string")),
+                byte => text.push(byte),
+            }
+        }
+        String::from_utf8(text).map_err(|_| self.error("invalid utf-8"))
+    }
+
+    // the char of a `\u` escape, surrogate pairs spanning two escapes
+    fn escaped_char(&mut self) -> Result<char, serde_json::Error> {
+        let high = self.hex4()?;
+        let code = match high {
+            0xd800..=0xdbff => {
+                self.expect(b"\\u")?;
+                let low = self.hex4()?;
+                if !(0xdc00..=0xdfff).contains(&low) {
+                    return Err(self.error("unpaired surrogate"));
+                }
+                0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+            }
+            0xdc00..=0xdfff => return Err(self.error("unpaired surrogate")),
+            code => code,
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid escape"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, serde_json::Error> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = (self.next()? as char)
+                .to_digit(16)
+                .ok_or_else(|| self.error("invalid escape"))?;
+            code = code << 4 | digit;
+        }
+        Ok(code)
+    }
+
+    // integers stay exact while they fit 64 bits, anything else is a double scaled from
+    // its first 19 digits, as close as serde_json gets without `float_roundtrip`
+    fn number(&mut self) -> Result<Number, serde_json::Error> {
+        let negative = self.peek() == Some(b'-');
+        if negative {
+            self.offset += 1;
+        }
+        let mut significand = 0u64;
+        let mut exponent = 0i32;
+        let mut exact = true;
+        match self.peek() {
+            Some(b'0') => self.offset += 1,
+            Some(b'1'..=b'9') => {
+                while let Some(digit) = self.digit() {
+                    match significand
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(digit))
+                    {
+                        Some(n) => significand = n,
+                        None => {
+                            exact = false;
+                            exponent = exponent.saturating_add(1);
+                        }
+                    }
+                }
+            }
+            _ => return Err(self.error("invalid number")),
+        }
+        let mut integer = true;
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+            integer = false;
+            let mut digits = 0;
+            while let Some(digit) = self.digit() {
+                digits += 1;
+                if let Some(n) = significand
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(digit))
+                {
+                    significand = n;
+                    exponent = exponent.saturating_sub(1);
+                }
+            }
+            if digits == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.offset += 1;
+            integer = false;
+            let sign = match self.peek() {
+                Some(b'-') => -1,
+                Some(b'+') => 1,
+                _ => 0,
+            };
+            if sign != 0 {
+                self.offset += 1;
+            }
+            let mut explicit = 0i32;
+            let mut digits = 0;
+            while let Some(digit) = self.digit() {
+                digits += 1;
+                explicit = explicit.saturating_mul(10).saturating_add(digit as i32);
+            }
+            if digits == 0 {
+                return Err(self.error("invalid number"));
+            }
+            exponent = exponent.saturating_add(if sign < 0 { -explicit } else { explicit });
+        }
+        if integer && exact {
+            match negative {
+                false => return Ok(significand.into()),
+                // `-0` is the double serde_json reads it as
+                true if significand != 0 && significand <= i64::MIN.unsigned_abs() => {
+                    return Ok((significand as i64).wrapping_neg().into())
+                }
+                true => {}
+            }
+        }
+        let value = scale(significand as f64, exponent);
+        Number::from_f64(if negative { -value } else { value })
+            .ok_or_else(|| self.error("number out of range"))
+    }
+
+    fn digit(&mut self) -> Option<u64> {
+        let digit = self.peek().filter(u8::is_ascii_digit)?;
+        self.offset += 1;
+        Some((digit - b'0') as u64)
+    }
+}
+
+// `value` times ten to the `exponent`
+fn scale(value: f64, exponent: i32) -> f64 {
+    let mut power = 1.0;
+    let mut base = 10.0;
+    let mut rest = exponent.unsigned_abs();
+    while rest > 0 {
+        if rest & 1 == 1 {
+            power *= base;
+        }
+        base *= base;
+        rest >>= 1;
+    }
+    if exponent < 0 {
+        value / power
+    } else {
+        value * power
+    }
+}
+
+// a compact serializer straight into the output, fields in declaration order like
+// serde_json's own
+struct Writer<'a> {
+    bytes: &'a mut Vec<u8>,
+    canonical: bool,
+}
+
+impl<'a> Writer<'a> {
+    fn display(self, value: impl core::fmt::Display) -> Result<(), serde_json::Error> {
+        let mut text = String::new();
+        let _ = write!(text, "{value}");
+        self.bytes.extend_from_slice(text.as_bytes());
+        Ok(())
+    }
+
+    fn compound(self, open: u8) -> Compound<'a> {
+        self.bytes.push(open);
+        Compound {
+            bytes: self.bytes,
+            canonical: self.canonical,
+            entries: (self.canonical && open == b'{').then(Vec::new),
+            first: true,
+            close: b"",
+        }
+    }
+
+    // `{"variant":` opened around the value of an enum variant
+    fn variant(self, variant: &str, open: u8) -> Compound<'a> {
+        self.bytes.push(b'{');
+        write_string(variant, self.bytes);
+        self.bytes.push(b':');
+        self.bytes.push(open);
+        Compound {
+            bytes: self.bytes,
+            canonical: self.canonical,
+            entries: (self.canonical && open == b'{').then(Vec::new),
+            first: true,
+            close: b"}",
+        }
+    }
+}
+
+fn key_error() -> serde_json::Error {
+    serde::ser::Error::custom("key must be a string")
+}
+
+impl<'a> serde::Serializer for Writer<'a> {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, value: bool) -> Result<(), serde_json::Error> {
+        self.bytes
+            .extend_from_slice(if value { b"true" } else { b"false" });
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<(), serde_json::Error> {
+        self.display(value)
+    }
+
+    // the decoder writes no f32, they go out as the double they widen to
+    fn serialize_f32(self, value: f32) -> Result<(), serde_json::Error> {
+        self.serialize_f64(value as f64)
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), serde_json::Error> {
+        match Number::from_f64(value) {
+            // core's shortest round trip digits, never in exponent notation
+            Some(_) if self.canonical => {
+                let mut text = String::new();
+                let _ = write!(text, "{value}");
+                self.serialize_str(&text)
+            }
+            Some(number) => self.display(number),
+            None => self.serialize_unit(),
+        }
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), serde_json::Error> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), serde_json::Error> {
+        write_string(value, self.bytes);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), serde_json::Error> {
+        use serde::ser::SerializeSeq;
+        let mut seq = self.compound(b'[');
+        for byte in value {
+            seq.serialize_element(byte)?;
+        }
+        seq.end()
+    }
+
+    fn serialize_none(self) -> Result<(), serde_json::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), serde_json::Error> {
+        self.bytes.extend_from_slice(b"null");
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), serde_json::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), serde_json::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.bytes.push(b'{');
+        write_string(variant, self.bytes);
+        self.bytes.push(b':');
+        value.serialize(Writer {
+            bytes: self.bytes,
+            canonical: self.canonical,
+        })?;
+        self.bytes.push(b'}');
+        Ok(())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.compound(b'['))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.compound(b'['))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.compound(b'['))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.variant(variant, b'['))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.compound(b'{'))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.compound(b'{'))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a>, serde_json::Error> {
+        Ok(self.variant(variant, b'{'))
+    }
+}
+
+struct Compound<'a> {
+    bytes: &'a mut Vec<u8>,
+    canonical: bool,
+    // where each entry of a canonical object starts, sorted once it closes
+    entries: Option<Vec<usize>>,
+    first: bool,
+    // what closes an enclosing variant object
+    close: &'static [u8],
+}
+
+impl Compound<'_> {
+    fn separator(&mut self) {
+        if !self.first {
+            self.bytes.push(b',');
+        }
+        self.first = false;
+        if let Some(entries) = &mut self.entries {
+            entries.push(self.bytes.len());
+        }
+    }
+
+    fn writer(&mut self) -> Writer<'_> {
+        Writer {
+            bytes: self.bytes,
+            canonical: self.canonical,
+        }
+    }
+
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+        self.separator();
+        value.serialize(self.writer())
+    }
+
+    fn field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.separator();
+        write_string(key, self.bytes);
+        self.bytes.push(b':');
+        value.serialize(self.writer())
+    }
+
+    fn close(mut self, close: u8) -> Result<(), serde_json::Error> {
+        if let Some(entries) = self.entries.take() {
+            self.sort_entries(&entries);
+        }
+        self.bytes.push(close);
+        self.bytes.extend_from_slice(self.close);
+        Ok(())
+    }
+}
+
+impl Compound<'_> {
+    // rewrites the entries written since `entries[0]` ordered by their keys
+    fn sort_entries(&mut self, entries: &[usize]) {
+        let Some(&start) = entries.first() else {
+            return;
+        };
+        let body = self.bytes.split_off(start);
+        let mut parts = entries
+            .iter()
+            .enumerate()
+            .map(|(index, offset)| {
+                // the next entry starts after the comma ending this one
+                let end = entries
+                    .get(index + 1)
+                    .map_or(body.len(), |next| next - 1 - start);
+                &body[offset - start..end]
+            })
+            .collect::<Vec<_>>();
+        parts.sort_by_key(|part| &part[..key_length(part)]);
+        for (index, part) in parts.into_iter().enumerate() {
+            if index > 0 {
+                self.bytes.push(b',');
+            }
+            self.bytes.extend_from_slice(part);
+        }
+    }
+}
+
+// the length of the json string leading an object entry, its key
+fn key_length(entry: &[u8]) -> usize {
+    let mut escaped = false;
+    for (index, &byte) in entry.iter().enumerate().skip(1) {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'"' => return index + 1,
+            _ => {}
+        }
+    }
+    entry.len()
+}
+
+impl serde::ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b']')
+    }
+}
+
+impl serde::ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b']')
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b']')
+    }
+}
+
+impl serde::ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b']')
+    }
+}
+
+// keys are written as values and keep serde_json's rules: strings as they are, numbers
+// and bools quoted, anything else refused
+impl serde::ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), serde_json::Error> {
+        self.separator();
+        let mut bytes = Vec::new();
+        key.serialize(Writer {
+            bytes: &mut bytes,
+            canonical: self.canonical,
+        })?;
+        match bytes.first() {
+            Some(b'"') => self.bytes.extend_from_slice(&bytes),
+            Some(b'-' | b'0'..=b'9' | b't' | b'f') => {
+                self.bytes.push(b'"');
+                self.bytes.extend_from_slice(&bytes);
+                self.bytes.push(b'"');
+            }
+            _ => return Err(key_error()),
+        }
+        self.bytes.push(b':');
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        value.serialize(self.writer())
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b'}')
+    }
+}
+
+impl serde::ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b'}')
+    }
+}
+
+impl serde::ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        self.close(b'}')
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+use serde_json::Value;
+
+use super::json;
+use super::pipeline::Run;
+use super::schema::{decode_pattern_directives, expand_asset_references, expand_schema_macros};
+use super::types::Error;
+
+// a cell dep the pattern reads, see `Host::load_cell`
+pub enum CellReference {
+    DataHash([u8; 32]),
+    OutPoint([u8; 32], u32),
+}
+
+// `ckbfs://<hash>` or `ckbfs://<hash>i<index>` with the hash in hex, `0x` prefixed or not
+fn parse_ckbfs_uri(value: &str) -> Option<CellReference> {
+    let reference = value.strip_prefix("ckbfs://")?;
+    let (hash, index) = match reference.split_once('i') {
+        Some((hash, index)) => (hash, Some(index.parse::<u32>().ok()?)),
+        None => (reference, None),
+    };
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hash.strip_prefix("0x").unwrap_or(hash), &mut bytes).ok()?;
+    Some(match index {
+        Some(index) => CellReference::OutPoint(bytes, index),
+        None => CellReference::DataHash(bytes),
+    })
+}
+
+// the image bytes a `ckbfs://` URI points at, read from the cell deps so that collections
+// kept on CKB need no host support for the scheme, None leaves the URI to the combiner
+// when it names no cell or the host reads none
+pub(crate) fn load_ckbfs_image(value: &str, run: &Run) -> Result<Option<Vec<u8>>, Error> {
+    let Some(cell) = parse_ckbfs_uri(value).filter(|_| run.reads_cells()) else {
+        return Ok(None);
+    };
+    run.load_cell(&cell)
+        .map(Some)
+        .ok_or(Error::DecodeCellDataNotFound)
+}
+
+// the parent cell data is the parent's images_base json, its groups sit beneath the local
+// ones: every parent group the local pattern doesn't redefine comes first, in parent order
+//
+// only the parent's macros apply to its rows, its other directives are dropped and an
+// inherit directive of its own is not followed
+pub(crate) fn inherit_parent_pattern(
+    hash: &[u8; 32],
+    traits_pool: Vec<Vec<Value>>,
+    run: &Run,
+) -> Result<Vec<Vec<Value>>, Error> {
+    let parent = run
+        .load_cell(&CellReference::DataHash(*hash))
+        .ok_or(Error::ParseParentPatternNotFound)?;
+    let parent: Vec<Vec<Value>> =
+        json::from_slice(&parent).map_err(|_| Error::ParseInvalidParentPattern)?;
+    let (parent_directives, parent) = decode_pattern_directives(parent)?;
+    let parent = expand_schema_macros(&parent_directives, parent)?;
+    let parent = expand_asset_references(&parent_directives, parent)?;
+    let local_names = traits_pool
+        .iter()
+        .filter_map(|row| row.first().and_then(Value::as_str))
+        .collect::<BTreeSet<_>>();
+    let mut merged = parent
+        .into_iter()
+        .filter(|row| {
+            row.first()
+                .and_then(Value::as_str)
+                .is_none_or(|name| !local_names.contains(name))
+        })
+        .collect::<Vec<_>>();
+    merged.extend(traits_pool);
+    Ok(merged)
+}
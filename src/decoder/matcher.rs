@@ -0,0 +1,578 @@
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use serde_json::Value;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use super::expr::Expr;
+use super::rng::Rng;
+use super::types::{
+    Config, DOB0Output, Endian, Error, Occurrence, Parameters, ParsedTrait, Pattern, TraitSchema,
+};
+use super::{display_trait_value, fill_template, sha256_digest, STATIC_TRAIT};
+
+// the layer values an args entry resolves to and the content hash of a single value
+type Dob1Values = (Vec<String>, Option<String>);
+
+// leading names of the array keys that match by something other than equality or range
+pub(crate) const MATCH_OPERATORS: [&str; 5] = ["!", "not-in", "prefix", "suffix", "contains"];
+
+// zero width space, non-joiner, joiner, word joiner and byte order mark
+const ZERO_WIDTH_CHARS: [char; 5] = ['\u{200b}', '\u{200c}', '\u{200d}', '\u{2060}', '\u{feff}'];
+
+// see `SchemaOptions.guard`
+pub(crate) fn guard_passes(
+    guard: &BTreeMap<String, Vec<Value>>,
+    parameters: &Parameters,
+) -> Result<bool, Error> {
+    for (trait_name, keys) in guard {
+        let Some(value) = get_trait_value(trait_name, parameters) else {
+            return Ok(false);
+        };
+        let mut matched = false;
+        for key in keys {
+            if key_matches(key, &value, &parameters.config, false)? {
+                matched = true;
+                break;
+            }
+        }
+        if !matched {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// the layer values the row's args pick for one set of DOB/0 values, None if no entry matches
+pub(crate) fn match_row(
+    image: &TraitSchema,
+    row: usize,
+    name: &str,
+    values: Vec<ParsedTrait>,
+    parameters: &Parameters,
+) -> Result<Option<Dob1Values>, Error> {
+    Ok(match image.pattern {
+        Pattern::Options => {
+            let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
+            let entries = args.as_array().ok_or(Error::SchemaInvalidArgs)?;
+            let loose = image.options.loose;
+            get_dob1_value_by_dob0_value(entries, &values, &parameters.config, loose)?
+        }
+        Pattern::Range => {
+            let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
+            let values = values
+                .into_iter()
+                .map(|value| hex_trait_as_number(value, image.options.endian))
+                .collect::<Vec<_>>();
+            let entries = args.as_array().ok_or(Error::SchemaInvalidArgs)?;
+            get_dob1_value_by_dob0_value(entries, &values, &parameters.config, false)?
+        }
+        Pattern::Mod | Pattern::Bucket => {
+            let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
+            let (divisor, entries) = split_divisor(args)?;
+            let value = hex_trait_as_number(values[0].clone(), image.options.endian);
+            let value = integer_value(&value, &parameters.config)?;
+            if image.pattern == Pattern::Mod {
+                let remainder = ParsedTrait::Number(value.rem_euclid(divisor));
+                get_dob1_value_by_dob0_value(entries, &[remainder], &parameters.config, false)?
+            } else {
+                usize::try_from(value.div_euclid(divisor))
+                    .ok()
+                    .and_then(|bucket| entries.get(bucket))
+                    .map(|dob1_value| select_dob1_values(dob1_value, &parameters.config))
+                    .transpose()?
+                    .map(|dob1_values| (dob1_values, None))
+            }
+        }
+        Pattern::Raw => {
+            let value = match (&values[0], image.options.number) {
+                (ParsedTrait::Number(number), Some(format)) => format.format(*number)?,
+                (value, _) => value
+                    .get_string()
+                    .cloned()
+                    .map_err(|_| Error::DecodeInvalidRawValue)?,
+            };
+            // the args string of a raw row wraps the DOB/0 value at its `{}`
+            let value = match image.args.as_ref().and_then(Value::as_str) {
+                Some(wrapper) if image.dob0_trait != STATIC_TRAIT && wrapper.contains("{}") => {
+                    wrapper.replacen("{}", &value, 1)
+                }
+                _ => value,
+            };
+            Some((vec![value], None))
+        }
+        Pattern::Expr => {
+            let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
+            let (expression, entries) = split_expression(args)?;
+            let value = expression.evaluate(&mut |trait_name| {
+                get_trait_value(trait_name, parameters)
+                    .map(|value| {
+                        let value = hex_trait_as_number(value, image.options.endian);
+                        integer_value(&value, &parameters.config)
+                    })
+                    .transpose()
+            })?;
+            match value {
+                Some(value) => {
+                    let value = [ParsedTrait::Number(value)];
+                    get_dob1_value_by_dob0_value(entries, &value, &parameters.config, false)?
+                }
+                None => None,
+            }
+        }
+        Pattern::WeightedRandom => {
+            let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
+            // rows of one group seeded by the same trait still draw independently
+            let mut rng = Rng::from_trait(&values[0]).fork(format!("{name}:{row}").as_bytes());
+            Some(get_weighted_dob1_value(args, &mut rng, &parameters.config)?)
+        }
+        Pattern::Template => {
+            let template = image
+                .args
+                .as_ref()
+                .and_then(Value::as_str)
+                .ok_or(Error::SchemaInvalidArgs)?;
+            fill_template(template, |trait_name| {
+                get_trait_value(trait_name, parameters).map(display_trait_value)
+            })
+            .map(|value| (vec![value], None))
+        }
+    })
+}
+
+// `0x` prefixed hex strings, typically the DNA, compare against numeric ranges as the
+// number their bytes encode, anything else, a bare `0x` included, is left for the pattern
+// to reject
+//
+// longer hex keeps its 8 least significant bytes, the last ones big endian and the first
+// ones little endian, so a 32 byte DNA reads as its value modulo 2^64
+pub fn hex_trait_as_number(value: ParsedTrait, endian: Endian) -> ParsedTrait {
+    let ParsedTrait::String(string) = &value else {
+        return value;
+    };
+    let Some(bytes) = string
+        .strip_prefix("0x")
+        .and_then(|hex| hex::decode(hex).ok())
+        .filter(|bytes| !bytes.is_empty())
+    else {
+        return value;
+    };
+    let mut number = [0u8; 8];
+    let number = match endian {
+        Endian::Big => {
+            let bytes = &bytes[bytes.len().saturating_sub(8)..];
+            number[8 - bytes.len()..].copy_from_slice(bytes);
+            u64::from_be_bytes(number)
+        }
+        Endian::Little => {
+            let bytes = &bytes[..bytes.len().min(8)];
+            number[..bytes.len()].copy_from_slice(bytes);
+            u64::from_le_bytes(number)
+        }
+    };
+    ParsedTrait::Number(number.into())
+}
+
+// reserved `#name` selectors read the `name` member of the config context instead of
+// the DOB/0 output, e.g. "#spore_id" with {"context": {"spore_id": "0x..."}}
+pub(crate) fn get_trait_value(trait_name: &str, parameters: &Parameters) -> Option<ParsedTrait> {
+    let Some(key) = trait_name.strip_prefix('#') else {
+        return get_dob0_value_by_name(trait_name, &parameters.dob0_output);
+    };
+    match parameters.config.context.as_ref()?.get(key)? {
+        Value::String(value) => Some(ParsedTrait::String(value.clone())),
+        value @ Value::Number(_) => json_integer(value)
+            .map(ParsedTrait::Number)
+            .or_else(|| value.as_f64().map(ParsedTrait::Float)),
+        _ => None,
+    }
+}
+
+// as `get_trait_value`, one value for each DOB/0 entry of the name `occurrence` picks
+pub(crate) fn get_trait_values(
+    trait_name: &str,
+    parameters: &Parameters,
+    occurrence: Occurrence,
+) -> Vec<ParsedTrait> {
+    if trait_name.starts_with('#') {
+        return get_trait_value(trait_name, parameters)
+            .into_iter()
+            .collect();
+    }
+    let mut values = get_dob0_values_by_name(trait_name, &parameters.dob0_output);
+    match occurrence {
+        _ if glob_prefix(split_trait_index(trait_name).0).is_some() => values.collect(),
+        Occurrence::First => values.next().into_iter().collect(),
+        Occurrence::Last => values.last().into_iter().collect(),
+        Occurrence::Nth(index) => values.nth(index).into_iter().collect(),
+        Occurrence::All => values.collect(),
+    }
+}
+
+// `Age[1]` picks the second value of the `Age` trait, a bare name the first one
+pub(crate) fn get_dob0_value_by_name(
+    trait_name: &str,
+    dob0_output: &[DOB0Output],
+) -> Option<ParsedTrait> {
+    get_dob0_values_by_name(trait_name, dob0_output).next()
+}
+
+// the value of every DOB/0 entry of the name in output order, entries too short for the
+// index are passed over
+fn get_dob0_values_by_name<'a>(
+    trait_name: &'a str,
+    dob0_output: &'a [DOB0Output],
+) -> impl Iterator<Item = ParsedTrait> + 'a {
+    let (trait_name, index) = split_trait_index(trait_name);
+    let prefix = glob_prefix(trait_name);
+    dob0_output
+        .iter()
+        .filter(move |output| match prefix {
+            Some(prefix) => output.name.starts_with(prefix),
+            None => output.name == trait_name,
+        })
+        .filter_map(move |output| output.traits.get(index).cloned())
+}
+
+// `Accessory*` reads every DOB/0 entry whose name starts with `Accessory`, a layer each
+pub(crate) fn glob_prefix(trait_name: &str) -> Option<&str> {
+    trait_name
+        .strip_suffix('*')
+        .filter(|prefix| !prefix.is_empty())
+}
+
+pub(crate) fn split_trait_index(trait_name: &str) -> (&str, usize) {
+    trait_name
+        .strip_suffix(']')
+        .and_then(|name| name.rsplit_once('['))
+        .and_then(|(name, index)| Some((name, index.parse().ok()?)))
+        .unwrap_or((trait_name, 0))
+}
+
+// in coerce mode decimal strings match numeric keys and numbers match string keys
+fn coerce_number(value: &ParsedTrait, config: &Config) -> Result<i128, Error> {
+    match value {
+        ParsedTrait::String(value) if config.coerce => value
+            .parse()
+            .map_err(|_| Error::SchemaInvalidParsedTraitType),
+        value => value.get_number(),
+    }
+}
+
+fn coerce_string<'a>(value: &'a ParsedTrait, config: &Config) -> Result<Cow<'a, str>, Error> {
+    match value {
+        ParsedTrait::Number(value) if config.coerce => Ok(Cow::Owned(value.to_string())),
+        ParsedTrait::Float(value) if config.coerce => Ok(Cow::Owned(value.to_string())),
+        value => value
+            .get_string()
+            .map(|value| Cow::Borrowed(value.as_str())),
+    }
+}
+
+// strings are compared in NFC so that composed and decomposed accents match, ascii and
+// already composed strings are borrowed as is
+pub fn normalize_str<'a>(value: &'a str, config: &Config) -> Cow<'a, str> {
+    let zero_width = |c: char| config.strip_zero_width && ZERO_WIDTH_CHARS.contains(&c);
+    let composed = is_nfc_quick(value.chars()) == IsNormalized::Yes;
+    if composed && !value.chars().any(zero_width) {
+        return Cow::Borrowed(value);
+    }
+    Cow::Owned(value.chars().filter(|c| !zero_width(*c)).nfc().collect())
+}
+
+// a dob1 value is either a plain string or an object of variants which must contain a
+// `default` member, e.g. {"default": "btcfs://...", "dark": "btcfs://...", "animated": "btcfs://..."}
+//
+// with the animated set requested, `<theme>:animated` and `animated` are tried before
+// the static `<theme>` and `default` variants
+fn select_dob1_variant<'a>(dob1_value: &'a Value, config: &Config) -> Result<&'a str, Error> {
+    let Some(variants) = dob1_value.as_object() else {
+        return dob1_value.as_str().ok_or(Error::SchemaInvalidArgsElement);
+    };
+    let theme = config.theme.as_deref();
+    let mut candidates = Vec::with_capacity(4);
+    if config.animated {
+        if let Some(theme) = theme {
+            candidates.push(format!("{theme}:animated"));
+        }
+        candidates.push("animated".to_owned());
+    }
+    if let Some(theme) = theme {
+        candidates.push(theme.to_owned());
+    }
+    candidates.push("default".to_owned());
+    let variant = candidates
+        .iter()
+        .find_map(|key| variants.get(key))
+        .ok_or(Error::SchemaInvalidArgsElement)?;
+    variant.as_str().ok_or(Error::SchemaInvalidArgsElement)
+}
+
+// the leading positive divisor or bucket size of mod and bucket args and the entries after it
+pub(crate) fn split_divisor(args: &Value) -> Result<(i128, &[Value]), Error> {
+    match args.as_array().map(Vec::as_slice) {
+        Some([divisor, entries @ ..]) if !entries.is_empty() => json_integer(divisor)
+            .filter(|divisor| *divisor > 0)
+            .map(|divisor| (divisor, entries))
+            .ok_or(Error::SchemaInvalidArgs),
+        _ => Err(Error::SchemaInvalidArgs),
+    }
+}
+
+// the leading expression of expr args and the entries its value is matched against
+pub(crate) fn split_expression(args: &Value) -> Result<(Expr, &[Value]), Error> {
+    match args.as_array().map(Vec::as_slice) {
+        Some([Value::String(expression), entries @ ..]) if !entries.is_empty() => {
+            Ok((Expr::parse(expression)?, entries))
+        }
+        _ => Err(Error::SchemaInvalidArgs),
+    }
+}
+
+// an array of dob1 values stacks one layer per element, bottom to top
+fn select_dob1_values(dob1_value: &Value, config: &Config) -> Result<Vec<String>, Error> {
+    match dob1_value {
+        Value::Array(dob1_values) if !dob1_values.is_empty() => dob1_values
+            .iter()
+            .map(|dob1_value| select_dob1_variant(dob1_value, config).map(ToOwned::to_owned))
+            .collect(),
+        Value::Array(_) => Err(Error::SchemaInvalidArgsElement),
+        dob1_value => Ok(vec![select_dob1_variant(dob1_value, config)?.to_owned()]),
+    }
+}
+
+// the optional third element of an args entry is the expected content hash of the value,
+// only a single value can carry one, a `sha256:<hex>` hash is also checked by the combiner
+fn dob1_entry_values(item: &[Value], config: &Config) -> Result<Dob1Values, Error> {
+    let dob1_value = item.get(1).ok_or(Error::SchemaInvalidArgsElement)?;
+    let dob1_values = select_dob1_values(dob1_value, config)?;
+    let hash = item
+        .get(2)
+        .map(|hash| hash.as_str().ok_or(Error::SchemaInvalidArgsElement))
+        .transpose()?
+        .map(ToOwned::to_owned);
+    if hash.is_some() && dob1_value.is_array() {
+        return Err(Error::SchemaInvalidArgsElement);
+    }
+    if let Some(hash) = &hash {
+        sha256_digest(hash)?;
+    }
+    Ok((dob1_values, hash))
+}
+
+fn get_dob1_value_by_dob0_value(
+    entries: &[Value],
+    parsed_dob0_values: &[ParsedTrait],
+    config: &Config,
+    loose: bool,
+) -> Result<Option<Dob1Values>, Error> {
+    for pattern in entries {
+        let item = pattern.as_array().ok_or(Error::SchemaInvalidArgsElement)?;
+        let dob0_value = item.first().ok_or(Error::SchemaInvalidArgsElement)?;
+        let dob1_values = dob1_entry_values(item, config)?;
+        if args_key_matches(dob0_value, parsed_dob0_values, config, loose)? {
+            return Ok(Some(dob1_values));
+        }
+    }
+    Ok(None)
+}
+
+// the entry picked with a chance proportional to its weight, zero weights are never picked
+fn get_weighted_dob1_value(
+    args: &Value,
+    rng: &mut Rng,
+    config: &Config,
+) -> Result<Dob1Values, Error> {
+    let entries = args.as_array().ok_or(Error::SchemaInvalidArgs)?;
+    let mut weights = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let weight = entry
+            .as_array()
+            .and_then(|item| item.first())
+            .and_then(Value::as_u64)
+            .ok_or(Error::SchemaInvalidArgsElement)?;
+        weights.push(weight);
+    }
+    let total = weights
+        .iter()
+        .try_fold(0u64, |total, weight| total.checked_add(*weight))
+        .ok_or(Error::ArithmeticOverflow)?;
+    if total == 0 {
+        return Err(Error::SchemaInvalidArgs);
+    }
+    let mut point = rng.below(total);
+    let index = weights
+        .iter()
+        .position(|weight| {
+            let hit = point < *weight;
+            point = point.saturating_sub(*weight);
+            hit
+        })
+        .unwrap_or_default();
+    dob1_entry_values(entries[index].as_array().unwrap(), config)
+}
+
+// a multi-trait row keys its entries by one key per trait, all of them must match unless
+// the entry is the `["*"]` catch-all
+fn args_key_matches(
+    dob0_value: &Value,
+    parsed_dob0_values: &[ParsedTrait],
+    config: &Config,
+    loose: bool,
+) -> Result<bool, Error> {
+    if let [parsed_dob0_value] = parsed_dob0_values {
+        return key_matches(dob0_value, parsed_dob0_value, config, loose);
+    }
+    let keys = dob0_value
+        .as_array()
+        .ok_or(Error::SchemaInvalidArgsElement)?;
+    if let [key] = keys.as_slice() {
+        if key.as_str() == Some("*") {
+            return Ok(true);
+        }
+    }
+    if keys.len() != parsed_dob0_values.len() {
+        return Err(Error::SchemaInvalidArgsElement);
+    }
+    for (key, parsed_dob0_value) in keys.iter().zip(parsed_dob0_values) {
+        if !key_matches(key, parsed_dob0_value, config, loose)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// `[start, end]` of integers, either of them may be `*` to leave that side open
+pub(crate) fn range_bounds(range: &[Value]) -> Result<(i128, i128), Error> {
+    // bounds past 64 bits are decimal strings, see `ParsedTrait`
+    let bound = |value: &Value, open: i128| match value {
+        Value::String(wildcard) if wildcard == "*" => Ok(open),
+        Value::String(digits) => digits.parse().map_err(|_| Error::SchemaInvalidArgsElement),
+        value => json_integer(value).ok_or(Error::SchemaInvalidArgsElement),
+    };
+    let [start, end] = range else {
+        return Err(Error::SchemaInvalidArgsElement);
+    };
+    let (start, end) = (bound(start, i128::MIN)?, bound(end, i128::MAX)?);
+    if start > end {
+        return Err(Error::SchemaInvalidRange);
+    }
+    Ok((start, end))
+}
+
+// a range with any fractional bound, it only matches float traits which are compared as is
+pub(crate) fn float_range_bounds(range: &[Value]) -> Result<(f64, f64), Error> {
+    let bound = |value: &Value, open: f64| match value {
+        Value::String(wildcard) if wildcard == "*" => Ok(open),
+        value => value.as_f64().ok_or(Error::SchemaInvalidArgsElement),
+    };
+    let [start, end] = range else {
+        return Err(Error::SchemaInvalidArgsElement);
+    };
+    let (start, end) = (bound(start, f64::NEG_INFINITY)?, bound(end, f64::INFINITY)?);
+    if start > end {
+        return Err(Error::SchemaInvalidRange);
+    }
+    Ok((start, end))
+}
+
+// a float trait matched against integer keys and ranges is rounded to the nearest
+// integer first, halves away from zero, so 2.5 matches 3 and -2.5 matches -3
+fn integer_value(value: &ParsedTrait, config: &Config) -> Result<i128, Error> {
+    let ParsedTrait::Float(value) = value else {
+        return coerce_number(value, config);
+    };
+    // the cast below saturates, which would let huge values match the outermost ranges
+    if !(-1e30..=1e30).contains(value) {
+        return Err(Error::ArithmeticOverflow);
+    }
+    let truncated = *value as i128;
+    let fraction = value - truncated as f64;
+    Ok(if fraction >= 0.5 {
+        truncated + 1
+    } else if fraction <= -0.5 {
+        truncated - 1
+    } else {
+        truncated
+    })
+}
+
+// signed or unsigned integer, floats never match
+pub(crate) fn json_integer(value: &Value) -> Option<i128> {
+    value
+        .as_u64()
+        .map(i128::from)
+        .or_else(|| value.as_i64().map(i128::from))
+}
+
+fn key_matches(
+    dob0_value: &Value,
+    parsed_dob0_value: &ParsedTrait,
+    config: &Config,
+    loose: bool,
+) -> Result<bool, Error> {
+    let normalize = |value| match loose {
+        true => Cow::Owned(normalize_str(value, config).trim().to_lowercase()),
+        false => normalize_str(value, config),
+    };
+    if dob0_value.is_number() {
+        match (json_integer(dob0_value), parsed_dob0_value) {
+            (Some(key), parsed_dob0_value) => Ok(integer_value(parsed_dob0_value, config)? == key),
+            (None, ParsedTrait::Float(value)) => Ok(dob0_value.as_f64() == Some(*value)),
+            (None, _) => Err(Error::DecodeFloatKeyMismatch),
+        }
+    } else if dob0_value.is_string() {
+        let value = coerce_string(parsed_dob0_value, config)?;
+        Ok(normalize(&value) == normalize(dob0_value.as_str().unwrap()))
+    } else if dob0_value.is_array() {
+        let range = dob0_value.as_array().unwrap();
+        match (range.first().and_then(Value::as_str), range.as_slice()) {
+            (Some("*"), [_]) => return Ok(true),
+            // `["!", key]` matches whatever `key` doesn't
+            (Some("!"), [_, key]) => {
+                return Ok(!key_matches(key, parsed_dob0_value, config, loose)?)
+            }
+            // `["not-in", [key, ...]]` matches whatever none of the keys do
+            (Some("not-in"), [_, Value::Array(keys)]) => {
+                for key in keys {
+                    if key_matches(key, parsed_dob0_value, config, loose)? {
+                        return Ok(false);
+                    }
+                }
+                return Ok(true);
+            }
+            // `["prefix", "Fire"]`, `["suffix", ".eth"]` or `["contains", "Dragon"]` match
+            // part of a string trait, normalized like exact string keys
+            (Some(operator @ ("prefix" | "suffix" | "contains")), [_, Value::String(part)]) => {
+                let value = coerce_string(parsed_dob0_value, config)?;
+                let (value, part) = (normalize(&value), normalize(part));
+                return Ok(match operator {
+                    "prefix" => value.starts_with(&*part),
+                    "suffix" => value.ends_with(&*part),
+                    _ => value.contains(&*part),
+                });
+            }
+            (Some(operator), _) if MATCH_OPERATORS.contains(&operator) => {
+                return Err(Error::SchemaInvalidArgsElement)
+            }
+            _ => {}
+        }
+        if range.iter().any(Value::is_f64) {
+            let (start, end) = float_range_bounds(range)?;
+            let ParsedTrait::Float(value) = parsed_dob0_value else {
+                return Err(Error::DecodeFloatKeyMismatch);
+            };
+            return Ok((start..=end).contains(value));
+        }
+        let (start, end) = range_bounds(range)?;
+        let value = integer_value(parsed_dob0_value, config)?;
+        Ok((start..=end).contains(&value))
+    } else {
+        Err(Error::SchemaInvalidArgsElement)
+    }
+}
@@ -1,178 +1,1100 @@
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
+pub mod args;
+pub mod cbor;
+#[cfg(any(feature = "compositor", feature = "gzip"))]
+mod checksum;
+#[cfg(feature = "compositor")]
+pub mod compositor;
+#[cfg(feature = "std")]
+pub mod coverage;
+pub mod expr;
+#[cfg(feature = "gzip")]
+pub mod gzip;
+pub mod input;
+pub mod json;
+pub mod loader;
+pub mod matcher;
+pub mod output;
+pub mod pattern_builder;
+pub mod pipeline;
+pub mod rng;
+pub mod schema;
 pub mod types;
-use crate::generated::{Color, Item, ItemUnion, ItemVec, RawImage, URI};
+pub mod validate;
+use crate::generated::{
+    Byte32, Bytes, Canvas as CanvasItem, Color, ColorVec, Fill as FillItem, Frames as FramesItem,
+    Gradient as GradientItem, Int32, Integrity, Item, ItemUnion, ItemVec, Mask, OutputSize,
+    RawImage, Text as TextItem, Transform as TransformItem, URIVec, Uint32, URI,
+};
+use crate::log::{self, Diagnostic};
+use crate::log_debug;
+use crate::stats::Counter;
+pub use args::dobs_parse_parameters;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use loader::load_ckbfs_image;
+pub use loader::CellReference;
+use matcher::{get_dob0_value_by_name, get_trait_value, get_trait_values, guard_passes, match_row};
+pub use matcher::{hex_trait_as_number, normalize_str};
 use molecule::prelude::{Builder, Byte, Entity};
+pub use pipeline::dobs_decode;
+use pipeline::Run;
+use rng::Rng;
+pub use schema::{decode_trait_schema, encode_trait_schema};
 use serde_json::Value;
-use types::{DOB0Output, Error, ImageType, Parameters, ParsedTrait, Pattern, TraitSchema};
+use types::{
+    Animation, Canvas, ChainedImage, Cluster, Config, DOB0Output, DisplayTrait, Error,
+    FallbackBranch, Fill, Gradient, GradientKind, GroupStats, ImageFormat, ImageMetadata,
+    ImageType, Layer, LayerGroup, LayerSource, MissingTrait, Parameters, ParsedTrait, Pattern,
+    Preview, PreviewKeep, RenderCache, Shape, Size, TextStyle, TraitSchema, TraitTranslation,
+    TraitsFilter, Transform, SVG_TYPE,
+};
 
-macro_rules! item {
-    ($itemty: ident, $value: ident) => {
-        $itemty::new_builder()
-            .set($value.as_bytes().iter().map(|v| Byte::new(*v)).collect())
-            .build()
+const DEFAULT_SEED_TRAIT: &str = "DNA";
+
+// `dob0_trait` of a raw layer always included with its args string as the value
+pub const STATIC_TRAIT: &str = "*";
+// leads an args value naming an asset of the defs directive
+pub const ASSET_REFERENCE: char = '@';
+// the type of a `[name, "canvas", {"width", "height", "background"}]` row
+pub const CANVAS_TYPE: &str = "canvas";
+// the type of a `[name, "mime", type]` row declaring the type of that image
+pub const MIME_TYPE: &str = "mime";
+// the most bytes a raw image layer may decode to, `Config.max_image_bytes` lowers it
+pub const MAX_RAW_IMAGE_BYTES: usize = 4 << 20;
+
+// the types a mime row may declare
+pub const IMAGE_MIME_TYPES: [&str; 7] = [
+    "image/png",
+    "image/jpeg",
+    "image/webp",
+    "image/gif",
+    "image/avif",
+    "image/apng",
+    "image/svg+xml",
+];
+
+// `[name, type, dob0_trait, pattern, args, options]`, later columns are ignored unless strict
+pub const SCHEMA_COLUMNS: usize = 6;
+
+// first element of the optional version header `["dob1", version]` leading a pattern
+pub const SCHEMA_VERSION_HEADER: &str = "dob1";
+
+// the newest pattern version this decoder reads, patterns without a header are version 1
+pub const SCHEMA_VERSION: u64 = 1;
+
+// leads a raw image value taking the image of that name from the chained DOB1Output
+pub const CHAINED_PREFIX: &str = "dob1:";
+
+// `dob0_trait` selectors resolved from the config context, see `get_trait_value`
+pub const RESERVED_SELECTORS: [&str; 3] = ["#dna", "#spore_id", "#index"];
+
+pub fn dobs_resolve_layers(parameters: &Parameters) -> Result<Vec<LayerGroup>, Error> {
+    dobs_resolve_layer_groups(parameters, &Run::detached())
+        .into_iter()
+        .map(|group| group.map_err(|(_, err)| err))
+        .collect()
+}
+
+// resolves every image group on its own, a failed group carries its name along
+pub fn dobs_resolve_layer_groups(
+    parameters: &Parameters,
+    run: &Run,
+) -> Vec<Result<LayerGroup, (String, Error)>> {
+    let mut offset = 0;
+    parameters
+        .images_base
+        .chunk_by(|a, b| a.name == b.name)
+        .map(|images| {
+            let mut failed = None;
+            let group = resolve_layer_group(images, parameters, &mut failed, run);
+            let first = offset;
+            offset += images.len();
+            group.map_err(|err| {
+                let row = failed.as_ref().map(|(row, _)| *row);
+                log::diagnose(
+                    run,
+                    &Diagnostic {
+                        row: row.map(|row| first + row),
+                        trait_name: row.map(|row| images[row].dob0_trait.as_str()),
+                        value: failed.and_then(|(_, value)| value),
+                        ..Diagnostic::new(err)
+                    },
+                );
+                (images[0].name.clone(), err)
+            })
+        })
+        .collect()
+}
+
+// `failed` is left at the row being resolved and the DOB/0 values it got, if any, so an
+// error can be told apart from the group it fails
+fn resolve_layer_group(
+    images: &[TraitSchema],
+    parameters: &Parameters,
+    failed: &mut Option<(usize, Option<Value>)>,
+    run: &Run,
+) -> Result<LayerGroup, Error> {
+    let dob0_output = &parameters.dob0_output;
+    let mut layers = Vec::new();
+    let mut traits = Vec::new();
+    let mut name = String::new();
+    let mut metadata = ImageMetadata::default();
+    images
+        .iter()
+        .for_each(|image| metadata.merge(&image.options.metadata));
+    if parameters.config.extensions {
+        images.iter().for_each(|image| {
+            metadata.merge(&ImageMetadata {
+                extensions: image.options.extensions.clone(),
+                ..Default::default()
+            })
+        });
+    }
+    let alt = images
+        .iter()
+        .find_map(|image| image.options.alt.as_ref())
+        .map(|template| render_template(template, dob0_output));
+    // rows adding at least one layer
+    let mut added = 0;
+    // exclusive chains one of whose rows added its layers, see `SchemaOptions.exclusive`
+    let mut settled = BTreeSet::new();
+    for (row, image) in images.iter().enumerate() {
+        name.clone_from(&image.name); // names are the same
+        *failed = Some((row, None));
+        let chain = image.options.exclusive.as_deref();
+        if chain.is_some_and(|chain| settled.contains(chain)) {
+            log_debug!(
+                run,
+                "group {name}: row {row} skipped, an earlier row of its chain matched"
+            );
+            continue;
+        }
+        if !guard_passes(&image.options.guard, parameters)? {
+            log_debug!(run, "group {name}: row {row} skipped by its guard");
+            continue;
+        }
+        // the values of each DOB/0 entry the row reads, see `SchemaOptions.occurrence`
+        let occurrences = if image.dob0_trait == STATIC_TRAIT {
+            // the fixed value of a static layer is its args string, see `decode_trait_schema`
+            image
+                .args
+                .as_ref()
+                .and_then(Value::as_str)
+                .map(|value| vec![vec![ParsedTrait::String(value.to_owned())]])
+        } else if image.dob0_traits.is_empty() {
+            let values = get_trait_values(&image.dob0_trait, parameters, image.options.occurrence);
+            (!values.is_empty()).then(|| values.into_iter().map(|value| vec![value]).collect())
+        } else {
+            image
+                .dob0_traits
+                .iter()
+                .map(|dob0_trait| get_trait_value(dob0_trait, parameters))
+                .collect::<Option<Vec<_>>>()
+                .map(|values| vec![values])
+        };
+        let Some(occurrences) = occurrences else {
+            log_debug!(run, "group {name}: trait {} missing", image.dob0_trait);
+            match &image.options.missing {
+                // the next row of the chain is the fallback
+                MissingTrait::Truncate if chain.is_some() => continue,
+                MissingTrait::Truncate => break,
+                MissingTrait::Skip => continue,
+                MissingTrait::Fail => return Err(Error::DecodeMissingTrait),
+                MissingTrait::Default(value) => {
+                    let value = checked_layer_value(image, value.clone(), parameters)?;
+                    run.count(Counter::LayersMatched, 1);
+                    added += 1;
+                    settled.extend(chain);
+                    layers.push((
+                        image.options.z.unwrap_or_default(),
+                        Layer {
+                            type_: image.type_.clone(),
+                            value,
+                            hash: None,
+                            transform: image.options.transform,
+                            style: image.options.text.clone(),
+                            animation: None,
+                            fill: image.options.fill(),
+                        },
+                    ));
+                    continue;
+                }
+            }
+        };
+        let mut matched = Vec::new();
+        for values in occurrences {
+            if log::DIAGNOSTICS {
+                let value = values.iter().cloned().map(display_trait_value);
+                *failed = Some((row, Some(Value::from_iter(value))));
+            }
+            matched.extend(match_row(image, row, &name, values, parameters)?);
+        }
+        // with every occurrence, entries matching nothing are left out
+        if matched.is_empty() {
+            log_debug!(
+                run,
+                "group {name}: no args entry matches {}",
+                image.dob0_trait
+            );
+            if chain.is_some() {
+                continue;
+            }
+            break;
+        }
+        added += 1;
+        settled.extend(chain);
+        let selected = !matches!(image.pattern, Pattern::Raw | Pattern::Template);
+        if let Some(trait_name) = image.options.as_trait.as_ref().filter(|_| selected) {
+            traits.push(DOB0Output {
+                name: trait_name.clone(),
+                traits: matched
+                    .iter()
+                    .flat_map(|(values, _)| values.iter().cloned().map(ParsedTrait::String))
+                    .collect(),
+            });
+        }
+        for (values, hash) in matched {
+            run.count(Counter::LayersMatched, 1);
+            let values = values
+                .into_iter()
+                .map(|value| checked_layer_value(image, value, parameters))
+                .collect::<Result<Vec<_>, _>>()?;
+            let layer = |value: String, animation| Layer {
+                type_: image.type_.clone(),
+                value,
+                hash: hash.clone(),
+                transform: image.options.transform,
+                style: image.options.text.clone(),
+                animation,
+                fill: image.options.fill(),
+            };
+            let z = image.options.z.unwrap_or_default();
+            match (image.options.frames, values.first()) {
+                (Some(duration), Some(first)) => {
+                    let animation = Animation {
+                        duration,
+                        frames: values.clone(),
+                    };
+                    layers.push((z, layer(first.clone(), Some(animation))));
+                }
+                _ => layers.extend(values.into_iter().map(|value| (z, layer(value, None)))),
+            }
+        }
+    }
+    // a stable sort, so rows of equal `z` stay in schema order
+    layers.sort_by_key(|(z, _)| *z);
+    let background = parameters.cluster.background.as_ref().map(|color| Layer {
+        type_: ImageType::ColorCode,
+        value: color.clone(),
+        hash: None,
+        transform: None,
+        style: None,
+        animation: None,
+        fill: None,
+    });
+    let layers = background
+        .into_iter()
+        .chain(layers.into_iter().map(|(_, layer)| layer))
+        .collect::<Vec<_>>();
+    // svg fragments can only be composed by the decoder, whatever format the group asked for
+    let format = if layers.iter().any(|layer| layer.type_ == ImageType::SVG) {
+        ImageFormat::Svg
+    } else {
+        images
+            .iter()
+            .find_map(|image| image.options.format)
+            .unwrap_or_default()
     };
+    let canvas = parameters.directives.canvas.get(&name).cloned();
+    Ok(LayerGroup {
+        name,
+        layers,
+        metadata,
+        alt,
+        format,
+        traits,
+        canvas,
+        skipped: images.len() - added,
+    })
+}
+
+// the layer value of `image` once checked, compressed raw images come out inflated and
+// base64 encoded and `dob1:<name>` ones as the chained image of that name
+fn checked_layer_value(
+    image: &TraitSchema,
+    value: String,
+    parameters: &Parameters,
+) -> Result<String, Error> {
+    let config = &parameters.config;
+    let value = match image.type_ {
+        ImageType::URI => expand_uri_alias(value, &parameters.cluster),
+        _ => value,
+    };
+    if let (ImageType::RawImage, Some(name)) = (&image.type_, value.strip_prefix(CHAINED_PREFIX)) {
+        return chained_image(name, &parameters.chained);
+    }
+    check_layer_value(&image.type_, &value, config)?;
+    // the parser only sets a compression when its decoder is built in
+    #[cfg(feature = "gzip")]
+    if let Some(types::Compression::Gzip) = image.compression {
+        let stream = decode_raw_image(&value).ok_or(Error::DecodeInvalidRawValue)?;
+        let image = gzip::decompress(&stream, raw_image_limit(config))?;
+        check_raw_image(&image, config)?;
+        return Ok(STANDARD.encode(image));
+    }
+    match image.type_ {
+        ImageType::RawImage => normalize_raw_image(value, config),
+        _ => Ok(value),
+    }
+}
+
+// hex raw images as the base64 the combiner reads, data URIs keep their declared type
+fn normalize_raw_image(value: String, config: &Config) -> Result<String, Error> {
+    // neither encoding decodes to more bytes than three quarters of its text
+    if value.len() / 4 * 3 > raw_image_limit(config) {
+        return Err(Error::DecodeRawImageTooLarge);
+    }
+    let image = decode_raw_image(&value).ok_or(Error::DecodeInvalidRawValue)?;
+    check_raw_image(&image, config)?;
+    if value.starts_with("data:") {
+        return Ok(value);
+    }
+    Ok(STANDARD.encode(image))
 }
 
-pub fn dobs_parse_parameters(args: Vec<&[u8]>) -> Result<Parameters, Error> {
-    if args.len() != 2 {
-        return Err(Error::ParseInvalidArgCount);
+fn raw_image_limit(config: &Config) -> usize {
+    let limit = config.max_image_bytes.unwrap_or(u64::MAX);
+    usize::try_from(limit).map_or(MAX_RAW_IMAGE_BYTES, |limit| limit.min(MAX_RAW_IMAGE_BYTES))
+}
+
+fn check_raw_image(image: &[u8], config: &Config) -> Result<(), Error> {
+    if image.len() > raw_image_limit(config) {
+        return Err(Error::DecodeRawImageTooLarge);
+    }
+    raw_image_mime(image).ok_or(Error::DecodeUnknownRawImageFormat)?;
+    Ok(())
+}
+
+// the type of raw image bytes by their magic number, None for formats layers can't hold
+pub(crate) fn raw_image_mime(image: &[u8]) -> Option<&'static str> {
+    if image.starts_with(b"\x89PNG") {
+        Some("image/png")
+    } else if image.starts_with(&[0xff, 0xd8, 0xff]) {
+        Some("image/jpeg")
+    } else if image.starts_with(b"RIFF") && image.get(8..12) == Some(b"WEBP") {
+        Some("image/webp")
+    } else {
+        None
+    }
+}
+
+fn expand_uri_alias(value: String, cluster: &Cluster) -> String {
+    let expanded = value.split_once("://").and_then(|(scheme, rest)| {
+        let prefix = cluster.uri_aliases.get(scheme)?;
+        Some(format!("{prefix}{rest}"))
+    });
+    expanded.unwrap_or(value)
+}
+
+// a data uri of the named image, base64 images are taken as they are and svg documents
+// encoded, anything else such as an error image can't be stacked
+fn chained_image(name: &str, chained: &[ChainedImage]) -> Result<String, Error> {
+    let find = |name: &str| {
+        chained
+            .iter()
+            .find(|image| image.name == name)
+            .ok_or(Error::DecodeChainedImageNotFound)
+    };
+    // a deduplicated image names the one holding its content, which is never a reference
+    let mut image = find(name)?;
+    if let Some(name) = &image.ref_ {
+        image = find(name)?;
+    }
+    match image.type_.as_str() {
+        SVG_TYPE => Ok(format!(
+            "data:{SVG_TYPE};base64,{}",
+            STANDARD.encode(&image.content)
+        )),
+        type_ if type_.ends_with(";base64") && is_raw_image(&image.content) => {
+            Ok(format!("data:{type_},{}", image.content))
+        }
+        _ => Err(Error::DecodeChainedImageNotFound),
     }
+}
 
-    let dob0_output: Vec<DOB0Output> = {
-        let output = args[0];
-        if output.is_empty() {
-            return Err(Error::ParseInvalidDOB0Output);
+// rejects layer values the combiner could not make sense of before any item is built
+fn check_layer_value(type_: &ImageType, value: &str, config: &Config) -> Result<(), Error> {
+    match type_ {
+        ImageType::ColorCode => {
+            if !is_color_code(value) && parse_gradient(value)?.is_none() {
+                return Err(Error::DecodeBadColorCodeFormat);
+            }
+        }
+        // on-chain patterns may point anywhere, unless the config restricts them
+        ImageType::URI => {
+            let allowed = config
+                .uri_schemes
+                .as_ref()
+                .is_none_or(|schemes| has_uri_scheme(value, schemes));
+            if !allowed {
+                return Err(Error::DecodeUnsupportedURIScheme);
+            }
         }
-        serde_json::from_slice(output).map_err(|_| Error::ParseInvalidDOB0Output)?
+        ImageType::RawImage => {
+            if !is_raw_image(value) {
+                return Err(Error::DecodeInvalidRawValue);
+            }
+        }
+        ImageType::SVG | ImageType::Text => {}
+    }
+    Ok(())
+}
+
+// a color code or a gradient spec
+pub(crate) fn is_color_value(value: &str) -> bool {
+    is_color_code(value) || parse_gradient(value).is_ok_and(|gradient| gradient.is_some())
+}
+
+pub(crate) fn has_uri_scheme<S: AsRef<str>>(value: &str, schemes: &[S]) -> bool {
+    value
+        .split_once(':')
+        .is_some_and(|(scheme, _)| schemes.iter().any(|allowed| allowed.as_ref() == scheme))
+}
+
+// base64 or hex image bytes, the former optionally as a `data:` URI
+pub(crate) fn is_raw_image(value: &str) -> bool {
+    let data = match value.strip_prefix("data:") {
+        Some(uri) => match uri.split_once(";base64,") {
+            Some((_, data)) => data,
+            None => return false,
+        },
+        None => value,
     };
-    let images_base = {
-        let value = args[1];
-        let traits_pool: Vec<Vec<Value>> =
-            serde_json::from_slice(value).map_err(|_| Error::ParseInvalidTraitsBase)?;
-        decode_trait_schema(traits_pool)?
+    if data.is_empty() {
+        return false;
+    }
+    let hex = data.strip_prefix("0x").unwrap_or(data);
+    (hex.len().is_multiple_of(2) && hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+        || STANDARD.decode(data).is_ok()
+}
+
+// the bytes of a raw image value, plain hex taking precedence over base64 like in
+// `is_raw_image`
+pub(crate) fn decode_raw_image(value: &str) -> Option<Vec<u8>> {
+    if let Some(uri) = value.strip_prefix("data:") {
+        return STANDARD.decode(uri.split_once(";base64,")?.1).ok();
+    }
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .ok()
+        .or_else(|| STANDARD.decode(value).ok())
+}
+
+// `linear:#FF0000,#0000FF,45deg` or `radial:#FFFFFF,#000000`, the angle of a linear
+// gradient defaults to 180deg, top to bottom, None if the value is no gradient at all
+pub fn parse_gradient(value: &str) -> Result<Option<Gradient>, Error> {
+    let (kind, spec) = if let Some(spec) = value.strip_prefix("linear:") {
+        (GradientKind::Linear, spec)
+    } else if let Some(spec) = value.strip_prefix("radial:") {
+        (GradientKind::Radial, spec)
+    } else {
+        return Ok(None);
+    };
+    let mut stops = spec.split(',').map(str::trim).collect::<Vec<_>>();
+    let mut angle = match kind {
+        GradientKind::Linear => 180,
+        GradientKind::Radial => 0,
     };
-    Ok(Parameters {
-        dob0_output,
-        images_base,
+    if let Some(degrees) = stops.last().and_then(|stop| stop.strip_suffix("deg")) {
+        if matches!(kind, GradientKind::Radial) {
+            return Err(Error::DecodeBadColorCodeFormat);
+        }
+        angle = degrees
+            .parse::<u32>()
+            .ok()
+            .filter(|angle| *angle < 360)
+            .ok_or(Error::DecodeBadColorCodeFormat)?;
+        stops.pop();
+    }
+    if stops.len() < 2 || !stops.iter().all(|stop| is_color_code(stop)) {
+        return Err(Error::DecodeBadColorCodeFormat);
+    }
+    Ok(Some(Gradient {
+        kind,
+        angle,
+        stops: stops.into_iter().map(ToOwned::to_owned).collect(),
+    }))
+}
+
+// `#RGB`, `#RRGGBB` or `#RRGGBBAA`
+pub(crate) fn is_color_code(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 6 | 8) && hex.bytes().all(|byte| byte.is_ascii_hexdigit())
     })
 }
 
-pub fn dobs_parse_syscall_parameters(
-    parameters: &Parameters,
-) -> Result<Vec<(String, ItemVec)>, Error> {
-    let Parameters {
-        dob0_output,
-        images_base,
-    } = parameters;
+fn transform_item(transform: &Transform) -> TransformItem {
+    // both are checked to be in range by `decode_schema_options`
+    let scale = (transform.scale * 1000.0 + 0.5) as u32;
+    let opacity = (transform.opacity * 255.0 + 0.5) as u8;
+    TransformItem::new_builder()
+        .x(int32(transform.x))
+        .y(int32(transform.y))
+        .scale(uint32(scale))
+        .rotation(uint32(transform.rotation.rem_euclid(360) as u32))
+        .opacity(Byte::new(opacity))
+        .build()
+}
+
+fn fill_item(fill: &Fill) -> FillItem {
+    let (mode, [top, right, bottom, left]) = match *fill {
+        Fill::Tile => (0, [0; 4]),
+        Fill::Slice(insets) => (1, insets),
+    };
+    FillItem::new_builder()
+        .mode(Byte::new(mode))
+        .top(uint32(top))
+        .right(uint32(right))
+        .bottom(uint32(bottom))
+        .left(uint32(left))
+        .build()
+}
+
+// the digest of a `sha256:<hex>` content hash, None for hashes of any other kind
+pub(crate) fn sha256_digest(hash: &str) -> Result<Option<[u8; 32]>, Error> {
+    let Some(digest) = hash.strip_prefix("sha256:") else {
+        return Ok(None);
+    };
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(digest.strip_prefix("0x").unwrap_or(digest), &mut bytes)
+        .map_err(|_| Error::SchemaInvalidArgsElement)?;
+    Ok(Some(bytes))
+}
+
+fn text_item(content: &str, style: &TextStyle) -> TextItem {
+    // thousandths, checked to be in range by `decode_schema_options`
+    let thousandths = |fraction: f64| uint32((fraction * 1000.0 + 0.5) as u32);
+    let color = style.color.as_str();
+    TextItem::new_builder()
+        .content(Bytes::from(content))
+        .x(thousandths(style.x))
+        .y(thousandths(style.y))
+        .size(thousandths(style.size))
+        .color(Color::from(color))
+        .build()
+}
+
+fn canvas_item(canvas: &Canvas) -> CanvasItem {
+    let background = canvas.background.as_deref().unwrap_or_default();
+    CanvasItem::new_builder()
+        .width(uint32(canvas.width))
+        .height(uint32(canvas.height))
+        .background(Color::from(background))
+        .build()
+}
 
-    let syscall_parameters = images_base
+// `uri` with its longest prefix among the `Config.rewrite` rules replaced
+fn rewrite_uri<'a>(uri: &'a str, config: &Config) -> Cow<'a, str> {
+    let rule = config
+        .rewrite
+        .iter()
+        .filter(|(prefix, _)| uri.starts_with(prefix.as_str()))
+        .max_by_key(|(prefix, _)| prefix.len());
+    match rule {
+        Some((prefix, replacement)) => Cow::Owned(format!("{replacement}{}", &uri[prefix.len()..])),
+        None => Cow::Borrowed(uri),
+    }
+}
+
+fn frames_item(animation: &Animation, config: &Config) -> FramesItem {
+    let uris = animation
+        .frames
+        .iter()
+        .map(|uri| URI::from(rewrite_uri(uri, config).as_ref()));
+    FramesItem::new_builder()
+        .duration(uint32(animation.duration))
+        .uris(URIVec::new_builder().extend(uris).build())
+        .build()
+}
+
+fn gradient_item(gradient: &Gradient) -> GradientItem {
+    let kind = match gradient.kind {
+        GradientKind::Linear => 0,
+        GradientKind::Radial => 1,
+    };
+    let stops = gradient
+        .stops
+        .iter()
+        .map(|stop| Color::from(stop.as_str()))
+        .collect::<Vec<_>>();
+    GradientItem::new_builder()
+        .kind(Byte::new(kind))
+        .angle(uint32(gradient.angle))
+        .stops(ColorVec::new_builder().set(stops).build())
+        .build()
+}
+
+// the rarity space of every image group, assuming each referenced trait is present:
+// a row either renders one of its distinct args values or, lacking a `["*"]` fallback,
+// may match nothing and end the group right there
+pub fn pattern_stats(images_base: &[TraitSchema]) -> Vec<GroupStats> {
+    images_base
         .chunk_by(|a, b| a.name == b.name)
         .map(|images| {
-            let mut items = ItemVec::new_builder();
-            let mut name = String::new();
-            for image in images.iter() {
-                name.clone_from(&image.name); // names are the same
-                let Some(value) = get_dob0_value_by_name(&image.dob0_trait, dob0_output) else {
-                    break;
-                };
-                let value = match image.pattern {
-                    Pattern::Options | Pattern::Range => {
-                        let args = image.args.as_ref().ok_or(Error::DecodeInvalidOptionArgs)?;
-                        get_dob1_value_by_dob0_value(args, value)?
+            let mut fallbacks = Vec::new();
+            let mut unbounded = false;
+            let mut combinations = 1u64;
+            for (row, image) in images.iter().enumerate().rev() {
+                let (values, can_miss) = match (&image.pattern, image.args.as_ref()) {
+                    (Pattern::Raw, _) => {
+                        unbounded |= image.dob0_trait != STATIC_TRAIT;
+                        (1, false)
                     }
-                    Pattern::Raw => Some(
-                        value
-                            .get_string()
-                            .cloned()
-                            .map_err(|_| Error::DecodeInvalidRawValue)?,
-                    ),
-                };
-                let Some(value) = value else {
-                    break;
-                };
-                let item = match image.type_ {
-                    ImageType::ColorCode => ItemUnion::from(item!(Color, value)),
-                    ImageType::URI => ItemUnion::from(item!(URI, value)),
-                    ImageType::RawImage => ItemUnion::from(item!(RawImage, value)),
+                    (Pattern::Template, _) => {
+                        unbounded = true;
+                        (1, false)
+                    }
+                    (Pattern::Bucket, Some(Value::Array(args))) => {
+                        let values = args
+                            .iter()
+                            .skip(1)
+                            .map(Value::to_string)
+                            .collect::<BTreeSet<_>>();
+                        (values.len() as u64, true)
+                    }
+                    (Pattern::WeightedRandom, Some(Value::Array(args))) => {
+                        let values = args
+                            .iter()
+                            .filter_map(Value::as_array)
+                            .filter(|entry| entry.first().and_then(Value::as_u64) != Some(0))
+                            .filter_map(|entry| entry.get(1).map(Value::to_string))
+                            .collect::<BTreeSet<_>>();
+                        (values.len() as u64, false)
+                    }
+                    (_, Some(Value::Array(args))) => {
+                        let mut values = BTreeSet::new();
+                        let mut fallback = None;
+                        for (index, entry) in args.iter().enumerate() {
+                            let Some(entry) = entry.as_array() else {
+                                continue;
+                            };
+                            if let Some(value) = entry.get(1) {
+                                values.insert(value.to_string());
+                            }
+                            let wildcard = matches!(
+                                entry.first().and_then(Value::as_array).map(Vec::as_slice),
+                                Some([Value::String(name)]) if name == "*"
+                            );
+                            if wildcard {
+                                fallback = Some(index);
+                                break;
+                            }
+                        }
+                        if let Some(index) = fallback {
+                            fallbacks.push(FallbackBranch {
+                                row,
+                                dob0_trait: image.dob0_trait.clone(),
+                                index,
+                            });
+                        }
+                        (values.len() as u64, fallback.is_none())
+                    }
+                    _ => (0, true),
                 };
-                items = items.push(Item::new_builder().set(item).build());
+                let rest = combinations;
+                combinations = rest.saturating_mul(values).saturating_add(can_miss as u64);
+                // a guarded row may be skipped with the rest of the group rendering on
+                if !image.options.guard.is_empty() {
+                    combinations = combinations.saturating_add(rest);
+                }
+            }
+            fallbacks.reverse();
+            GroupStats {
+                name: images[0].name.clone(),
+                combinations,
+                unbounded,
+                fallbacks,
             }
-            Ok((name, items.build()))
         })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    Ok(syscall_parameters)
+        .collect()
 }
 
-pub(crate) fn decode_trait_schema(traits_pool: Vec<Vec<Value>>) -> Result<Vec<TraitSchema>, Error> {
-    let traits_base = traits_pool
+pub fn dobs_parse_syscall_parameters(
+    parameters: &Parameters,
+) -> Result<Vec<(String, ItemVec)>, Error> {
+    dobs_resolve_layers(parameters)?
         .into_iter()
-        .map(|schema| {
-            if schema.len() < 4 {
-                return Err(Error::SchemaInsufficientElements);
+        .map(|group| {
+            let items = build_item_vec(&group, &parameters.config, &Run::detached())?;
+            Ok((group.name, items))
+        })
+        .collect()
+}
+
+pub fn build_item_vec(group: &LayerGroup, config: &Config, run: &Run) -> Result<ItemVec, Error> {
+    let mut items = ItemVec::new_builder();
+    if let Some(canvas) = &group.canvas {
+        items = items.push(Item::new_builder().set(canvas_item(canvas)).build());
+    }
+    if let Some(Size { width, height }) = config.thumbnail {
+        let size = OutputSize::new_builder()
+            .width(uint32(width))
+            .height(uint32(height))
+            .build();
+        items = items.push(Item::new_builder().set(size).build());
+    }
+    if let (Some(shape), true) = (group.metadata.shape, group.metadata.mask) {
+        let (shape, radius) = match shape {
+            Shape::Square => (0, 0),
+            Shape::Circle => (1, 0),
+            Shape::RoundedRect(radius) => (2, radius),
+        };
+        let mask = Mask::new_builder()
+            .shape(Byte::new(shape))
+            .radius(uint32(radius))
+            .build();
+        items = items.push(Item::new_builder().set(mask).build());
+    }
+    for Layer {
+        type_,
+        value,
+        hash,
+        transform,
+        style,
+        animation,
+        fill,
+    } in &group.layers
+    {
+        if let (Some(transform), false) = (transform, *type_ == ImageType::SVG) {
+            items = items.push(Item::new_builder().set(transform_item(transform)).build());
+        }
+        if let Some(fill) = fill {
+            items = items.push(Item::new_builder().set(fill_item(fill)).build());
+        }
+        let digest = hash.as_deref().map(sha256_digest).transpose()?.flatten();
+        // the digest of a single asset can't cover the frames of an animation
+        if let (Some(digest), ImageType::URI, None) = (digest, type_, animation) {
+            let integrity = Integrity::new_builder()
+                .sha256(Byte32::new_unchecked(digest.to_vec().into()))
+                .build();
+            items = items.push(Item::new_builder().set(integrity).build());
+        }
+        let item = match type_ {
+            ImageType::ColorCode => match parse_gradient(value) {
+                Ok(Some(gradient)) => ItemUnion::from(gradient_item(&gradient)),
+                _ => ItemUnion::from(Color::from(value.as_str())),
+            },
+            ImageType::URI => match animation {
+                Some(animation) => ItemUnion::from(frames_item(animation, config)),
+                None => {
+                    let value = rewrite_uri(value, config);
+                    match load_ckbfs_image(&value, run)? {
+                        Some(image) => {
+                            let image = STANDARD.encode(image);
+                            ItemUnion::from(RawImage::from(image.as_str()))
+                        }
+                        None => ItemUnion::from(URI::from(value.as_ref())),
+                    }
+                }
+            },
+            ImageType::RawImage => ItemUnion::from(RawImage::from(value.as_str())),
+            ImageType::Text => {
+                ItemUnion::from(text_item(value, &style.clone().unwrap_or_default()))
             }
-            let name = schema[0].as_str().ok_or(Error::SchemaInvalidName)?;
-            let type_ = match schema[1].as_str().ok_or(Error::SchemaInvalidType)? {
-                "color" => ImageType::ColorCode,
-                "uri" => ImageType::URI,
-                "image" => ImageType::RawImage,
-                _ => return Err(Error::SchemaTypeMismatch),
-            };
-            let dob0_trait = schema[2].as_str().ok_or(Error::SchemaInvalidTraitName)?;
-            let pattern_str = schema[3].as_str().ok_or(Error::SchemaInvalidPattern)?;
-            let pattern = match (pattern_str, &type_) {
-                ("options", ImageType::ColorCode | ImageType::URI) => Pattern::Options,
-                ("range", ImageType::ColorCode | ImageType::URI) => Pattern::Range,
-                ("raw", ImageType::RawImage | ImageType::URI) => Pattern::Raw,
-                _ => return Err(Error::SchemaPatternMismatch),
+            // never sent to the combiner, see `resolve_layer_group`
+            ImageType::SVG => continue,
+        };
+        items = items.push(Item::new_builder().set(item).build());
+    }
+    Ok(items.build())
+}
+
+// stacks the layers bottom to top, each filling the whole canvas whose view box follows
+// the group's aspect ratio
+pub fn compose_svg(group: &LayerGroup) -> String {
+    let [width, height] = group.metadata.aspect_ratio.unwrap_or([1, 1]);
+    let mut svg =
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\">");
+    for (index, layer) in group.layers.iter().enumerate() {
+        let element = svg_layer(layer, index, [width, height]);
+        // the view box has no pixel size, only the opacity of a transform carries over
+        match layer.transform.filter(|transform| transform.opacity < 1.0) {
+            Some(transform) => svg.push_str(&format!(
+                "<g opacity=\"{}\">{element}</g>",
+                transform.opacity
+            )),
+            None => svg.push_str(&element),
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+fn svg_layer(layer: &Layer, index: usize, view_box: [u32; 2]) -> String {
+    if layer.type_ == ImageType::SVG {
+        return layer.value.clone();
+    }
+    if layer.type_ == ImageType::Text {
+        return svg_text(layer, view_box);
+    }
+    if let Ok(Some(gradient)) = parse_gradient(&layer.value) {
+        return svg_gradient(&gradient, index);
+    }
+    let value = escape_xml(&layer.value);
+    match layer.type_ {
+        ImageType::ColorCode => format!("<rect width=\"100%\" height=\"100%\" fill=\"{value}\"/>"),
+        ImageType::URI => format!("<image href=\"{value}\" width=\"100%\" height=\"100%\"/>"),
+        ImageType::RawImage => {
+            let prefix = if value.starts_with("data:") {
+                ""
+            } else {
+                "data:image/png;base64,"
             };
-            let args = schema.get(4).cloned();
-            Ok(TraitSchema {
-                name: name.to_owned(),
-                type_,
-                dob0_trait: dob0_trait.to_owned(),
-                pattern,
-                args,
-            })
+            format!("<image href=\"{prefix}{value}\" width=\"100%\" height=\"100%\"/>")
+        }
+        ImageType::SVG | ImageType::Text => unreachable!(),
+    }
+}
+
+// centered on its position, the view box units scale the style's fractions
+fn svg_text(layer: &Layer, [width, height]: [u32; 2]) -> String {
+    let style = layer.style.clone().unwrap_or_default();
+    format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\" text-anchor=\"middle\" \
+         dominant-baseline=\"middle\">{}</text>",
+        style.x * width as f64,
+        style.y * height as f64,
+        style.size * height as f64,
+        escape_xml(&style.color),
+        escape_xml(&layer.value)
+    )
+}
+
+// a canvas filling rect painted by the gradient, svg gradients run left to right so
+// linear ones are turned by the css angle minus 90 degrees
+fn svg_gradient(gradient: &Gradient, index: usize) -> String {
+    let last = gradient.stops.len() - 1;
+    let stops = gradient
+        .stops
+        .iter()
+        .enumerate()
+        .map(|(i, stop)| {
+            format!(
+                "<stop offset=\"{}%\" stop-color=\"{stop}\"/>",
+                i * 100 / last
+            )
         })
-        .collect::<Result<Vec<_>, _>>()?;
-    Ok(traits_base)
+        .collect::<String>();
+    let id = format!("gradient{index}");
+    let definition = match gradient.kind {
+        GradientKind::Linear => format!(
+            "<linearGradient id=\"{id}\" gradientTransform=\"rotate({} 0.5 0.5)\">{stops}</linearGradient>",
+            gradient.angle as i64 - 90
+        ),
+        GradientKind::Radial => format!("<radialGradient id=\"{id}\">{stops}</radialGradient>"),
+    };
+    format!("<defs>{definition}</defs><rect width=\"100%\" height=\"100%\" fill=\"url(#{id})\"/>")
 }
 
-fn get_dob0_value_by_name(trait_name: &str, dob0_output: &[DOB0Output]) -> Option<ParsedTrait> {
-    dob0_output.iter().find_map(|output| {
-        if output.name == trait_name {
-            output.traits.first().cloned()
-        } else {
-            None
+fn escape_xml(value: &str) -> Cow<'_, str> {
+    if !value.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
         }
-    })
+    }
+    Cow::Owned(escaped)
 }
 
-fn get_dob1_value_by_dob0_value(
-    args: &Value,
-    parsed_dob0_value: ParsedTrait,
-) -> Result<Option<String>, Error> {
-    for pattern in args.as_array().ok_or(Error::SchemaInvalidArgs)? {
-        let item = pattern.as_array().ok_or(Error::SchemaInvalidArgsElement)?;
-        let (Some(dob0_value), Some(dob1_value)) = (item.first(), item.get(1)) else {
-            return Err(Error::SchemaInvalidArgsElement);
-        };
-        let dob1_value = dob1_value
-            .as_str()
-            .ok_or(Error::SchemaInvalidArgsElement)?
-            .to_owned();
-        if dob0_value.is_number() {
-            let value = parsed_dob0_value.get_number()?;
-            if value == dob0_value.as_u64().unwrap() {
-                return Ok(Some(dob1_value));
-            }
-        } else if dob0_value.is_string() {
-            let value = parsed_dob0_value.get_string()?;
-            if value == dob0_value.as_str().unwrap() {
-                return Ok(Some(dob1_value));
+// the URI layers of the group one per line, colors and raw images are left out
+pub fn uri_list(group: &LayerGroup) -> String {
+    group
+        .layers
+        .iter()
+        .filter(|layer| layer.type_ == ImageType::URI)
+        .flat_map(|layer| match &layer.animation {
+            Some(animation) => animation.frames.iter().map(String::as_str).collect(),
+            None => vec![layer.value.as_str()],
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn uint32(value: u32) -> Uint32 {
+    Uint32::from(value)
+}
+
+fn int32(value: i32) -> Int32 {
+    Int32::new_builder()
+        .set(value.to_le_bytes().map(Byte::new))
+        .build()
+}
+
+// returns whether any layer was dropped
+pub fn truncate_layers(layers: &mut Vec<Layer>, preview: &Preview) -> bool {
+    if layers.len() <= preview.layers {
+        return false;
+    }
+    match preview.keep {
+        PreviewKeep::Top => {
+            layers.drain(..layers.len() - preview.layers);
+        }
+        PreviewKeep::Base => layers.truncate(preview.layers),
+    }
+    true
+}
+
+// a group is served from the host cache only if every layer value has a known fingerprint
+// and the composition of exactly those fingerprints was rendered before
+pub fn cached_fingerprint<'a>(cache: &'a RenderCache, layers: &[Layer]) -> Option<&'a String> {
+    if layers.is_empty() {
+        return None;
+    }
+    let fingerprints = layers
+        .iter()
+        .map(|layer| cache.layers.get(&layer.value).map(String::as_str))
+        .collect::<Option<Vec<_>>>()?;
+    cache.composed.get(&fingerprints.join(","))
+}
+
+pub fn layer_sources(layers: &[Layer]) -> Vec<LayerSource> {
+    layers
+        .iter()
+        .filter_map(|layer| match (&layer.type_, &layer.hash) {
+            (ImageType::URI, Some(hash)) => Some(LayerSource {
+                uri: layer.value.clone(),
+                hash: hash.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+// the DOB/0 traits as the output echoes them, renamed and filtered by the pattern's
+// `traits` directive, then translated if the run selected a locale
+pub fn dobs_output_traits(
+    filter: &TraitsFilter,
+    translations: Option<&BTreeMap<String, TraitTranslation>>,
+    dob0_output: Vec<DOB0Output>,
+) -> Vec<DOB0Output> {
+    dob0_output
+        .into_iter()
+        .filter_map(|mut output| {
+            let translation = translations.and_then(|traits| traits.get(&output.name));
+            output.name = filter.output_name(&output.name)?.to_owned();
+            if let Some(translation) = translation {
+                translate_trait(translation, &mut output);
             }
-        } else if dob0_value.is_array() {
-            let range = dob0_value.as_array().unwrap();
-            if Some(Some("*")) == range.first().map(|v| v.as_str()) {
-                return Ok(Some(dob1_value));
-            } else {
-                if range.len() != 2 {
-                    return Err(Error::SchemaInvalidArgsElement);
-                }
-                let (start, end) = (
-                    range[0].as_u64().ok_or(Error::SchemaInvalidArgsElement)?,
-                    range[1].as_u64().ok_or(Error::SchemaInvalidArgsElement)?,
-                );
-                let value = parsed_dob0_value.get_number()?;
-                if start <= value && value <= end {
-                    return Ok(Some(dob1_value));
-                }
+            Some(output)
+        })
+        .collect()
+}
+
+// every layer is matched against the canonical values by the time the output is written,
+// so translated names and values only ever reach the viewer
+pub fn translate_trait(translation: &TraitTranslation, output: &mut DOB0Output) {
+    if let Some(name) = &translation.name {
+        output.name = name.clone();
+    }
+    for value in &mut output.traits {
+        if let Some(translated) = translation.values.get(&display_trait_value(value.clone())) {
+            *value = ParsedTrait::String(translated.clone());
+        }
+    }
+}
+
+// translate the first value of every echoed DOB/0 trait into `locale`, anything without
+// a translation is displayed under its output name
+pub fn dobs_display_traits(parameters: &Parameters, locale: &str) -> Vec<DisplayTrait> {
+    let translations = parameters.directives.i18n.get(locale);
+    let filter = &parameters.directives.traits;
+    parameters
+        .dob0_output
+        .iter()
+        .filter_map(|output| Some((output, filter.output_name(&output.name)?)))
+        .map(|(output, output_name)| {
+            let translation = translations.and_then(|traits| traits.get(&output.name));
+            let value = output
+                .traits
+                .first()
+                .cloned()
+                .map(display_trait_value)
+                .unwrap_or_default();
+            DisplayTrait {
+                name: translation
+                    .and_then(|translation| translation.name.clone())
+                    .unwrap_or_else(|| output_name.to_owned()),
+                value: translation
+                    .and_then(|translation| translation.values.get(&value).cloned())
+                    .unwrap_or(value),
             }
-        } else {
-            return Err(Error::SchemaInvalidArgsElement);
+        })
+        .collect()
+}
+
+// replace every `{TraitName}` with the first value of that DOB/0 trait, unknown traits
+// render as empty text
+pub fn render_template(template: &str, dob0_output: &[DOB0Output]) -> String {
+    fill_template(template, |trait_name| {
+        Some(
+            get_dob0_value_by_name(trait_name, dob0_output)
+                .map(display_trait_value)
+                .unwrap_or_default(),
+        )
+    })
+    .unwrap_or_default()
+}
+
+// the template with each `{TraitName}` replaced through `lookup`, None as soon as a
+// lookup finds nothing
+pub(crate) fn fill_template<F>(template: &str, mut lookup: F) -> Option<String>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
         };
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&lookup(&rest[start + 1..start + end])?);
+        rest = &rest[start + end + 1..];
+    }
+    rendered.push_str(rest);
+    Some(rendered)
+}
+
+pub(crate) fn display_trait_value(value: ParsedTrait) -> String {
+    match value {
+        ParsedTrait::String(value) => value,
+        ParsedTrait::Number(value) => value.to_string(),
+        ParsedTrait::Float(value) => value.to_string(),
     }
-    Ok(None)
+}
+
+// the generator shared by randomized patterns, seeded from the trait named by the
+// pattern's seed directive or the `DNA` trait, None if the DOB/0 output lacks it
+pub fn dobs_rng(parameters: &Parameters) -> Option<Rng> {
+    let seed = parameters
+        .directives
+        .seed
+        .as_deref()
+        .unwrap_or(DEFAULT_SEED_TRAIT);
+    get_trait_value(seed, parameters).map(|value| Rng::from_trait(&value))
 }
@@ -0,0 +1,249 @@
+use alloc::{format, vec, vec::Vec};
+
+use molecule::prelude::{Builder, Byte, Entity};
+use serde_json::Value;
+
+use super::types::{
+    DOB0Output, DOB1Output, DisplayTrait, Error, LayerGroup, OutputEncoding, ParsedTrait,
+};
+use super::{cbor, json};
+use crate::generated::{
+    Bytes, Float64, Int128, Output, OutputImage, OutputImageVec, TraitEntry, TraitEntryVec,
+    TraitValue, TraitValueUnion, TraitValueVec, Uint64, Uint64Opt,
+};
+
+// frame layout: [channel byte][8 lowercase hex digits of payload length][payload][\0]
+//
+// the header is kept textual so that every frame stays a valid C string for the
+// null-terminated debug syscall, payloads are json and never contain \0 either
+pub const FRAME_HEADER_SIZE: usize = 9;
+
+// chunk frames carry `[8 hex digits of the chunk index][8 hex digits of the chunk count]`
+// ahead of their slice of the output
+pub const CHUNK_HEADER_SIZE: usize = 16;
+pub const DEFAULT_CHUNK_SIZE: usize = 32 * 1024;
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Channel {
+    Traits = b'T',
+    Image = b'I',
+    Layers = b'L',
+    Display = b'S',
+    Context = b'C',
+    Diagnostic = b'D',
+    Chunk = b'K',
+}
+
+impl Channel {
+    pub fn from_byte(value: u8) -> Option<Self> {
+        match value {
+            b'T' => Some(Channel::Traits),
+            b'I' => Some(Channel::Image),
+            b'L' => Some(Channel::Layers),
+            b'S' => Some(Channel::Display),
+            b'C' => Some(Channel::Context),
+            b'D' => Some(Channel::Diagnostic),
+            b'K' => Some(Channel::Chunk),
+            _ => None,
+        }
+    }
+}
+
+pub fn frame(channel: Channel, payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(FRAME_HEADER_SIZE + payload.len() + 1);
+    frame.push(channel as u8);
+    frame.extend_from_slice(format!("{:08x}", payload.len()).as_bytes());
+    frame.extend_from_slice(payload);
+    frame.push(0);
+    frame
+}
+
+// returns the channel, payload and the remaining bytes after the parsed frame
+pub fn unframe(bytes: &[u8]) -> Option<(Channel, &[u8], &[u8])> {
+    if bytes.len() < FRAME_HEADER_SIZE {
+        return None;
+    }
+    let channel = Channel::from_byte(bytes[0])?;
+    let length = core::str::from_utf8(&bytes[1..FRAME_HEADER_SIZE]).ok()?;
+    let length = usize::from_str_radix(length, 16).ok()?;
+    let end = FRAME_HEADER_SIZE.checked_add(length)?;
+    if bytes.len() <= end || bytes[end] != 0 {
+        return None;
+    }
+    Some((channel, &bytes[FRAME_HEADER_SIZE..end], &bytes[end + 1..]))
+}
+
+pub fn frame_json<T: serde::Serialize + ?Sized>(
+    channel: Channel,
+    value: &T,
+) -> Result<Vec<u8>, Error> {
+    let payload = json::to_vec(value)?;
+    Ok(frame(channel, &payload))
+}
+
+// frames of everything known before composition starts: the traits, then the display
+// section, the resolved layers and the caller context if present, hosts may forward
+// them while images render
+pub fn frame_dob1_prelude(
+    traits: Option<&Vec<DOB0Output>>,
+    display: Option<&Vec<DisplayTrait>>,
+    layers: Option<&Vec<LayerGroup>>,
+    context: Option<&Value>,
+) -> Result<Vec<Vec<u8>>, Error> {
+    let mut frames = vec![];
+    if let Some(traits) = traits {
+        frames.push(frame_json(Channel::Traits, traits)?);
+    }
+    if let Some(display) = display {
+        frames.push(frame_json(Channel::Display, display)?);
+    }
+    if let Some(layers) = layers {
+        frames.push(frame_json(Channel::Layers, layers)?);
+    }
+    if let Some(context) = context {
+        frames.push(frame_json(Channel::Context, context)?);
+    }
+    Ok(frames)
+}
+
+// split a DOB1Output into its prelude frames followed by one frame per image
+pub fn frame_dob1_output(output: &DOB1Output) -> Result<Vec<Vec<u8>>, Error> {
+    let mut frames = frame_dob1_prelude(
+        output.traits.as_ref(),
+        output.display.as_ref(),
+        output.layers.as_ref(),
+        output.context.as_ref(),
+    )?;
+    for image in &output.images {
+        frames.push(frame_json(Channel::Image, image)?);
+    }
+    Ok(frames)
+}
+
+// blob or chunked output in `encoding`, binary encodings are hex so the output stays a
+// C string, molecule has no schema for anything but a `DOB1Output` and falls back to json
+pub fn encode_output<T: serde::Serialize + ?Sized>(
+    value: &T,
+    encoding: OutputEncoding,
+) -> Result<Vec<u8>, Error> {
+    match encoding {
+        OutputEncoding::Cbor => Ok(hex::encode(cbor::to_vec(value)?).into_bytes()),
+        OutputEncoding::Json | OutputEncoding::Molecule => Ok(json::to_vec(value)?),
+        OutputEncoding::Canonical => Ok(json::to_canonical_vec(value)?),
+    }
+}
+
+// as `encode_output`, the molecule `Output` only keeps the traits and the images
+pub fn encode_dob1_output(output: &DOB1Output, encoding: OutputEncoding) -> Result<Vec<u8>, Error> {
+    match encoding {
+        OutputEncoding::Molecule => {
+            Ok(hex::encode(molecule_output(output).as_slice()).into_bytes())
+        }
+        _ => encode_output(output, encoding),
+    }
+}
+
+pub fn molecule_output(output: &DOB1Output) -> Output {
+    let traits = output.traits.iter().flatten().map(|entry| {
+        let traits = entry.traits.iter().map(|value| {
+            let value = match value {
+                ParsedTrait::String(text) => TraitValueUnion::from(molecule_bytes(text.as_bytes())),
+                ParsedTrait::Number(number) => match u64::try_from(*number) {
+                    Ok(number) => TraitValueUnion::from(
+                        Uint64::new_builder()
+                            .set(number.to_le_bytes().map(Byte::new))
+                            .build(),
+                    ),
+                    Err(_) => TraitValueUnion::from(
+                        Int128::new_builder()
+                            .set(number.to_le_bytes().map(Byte::new))
+                            .build(),
+                    ),
+                },
+                ParsedTrait::Float(number) => TraitValueUnion::from(
+                    Float64::new_builder()
+                        .set(number.to_le_bytes().map(Byte::new))
+                        .build(),
+                ),
+            };
+            TraitValue::new_builder().set(value).build()
+        });
+        TraitEntry::new_builder()
+            .name(molecule_bytes(entry.name.as_bytes()))
+            .traits(TraitValueVec::new_builder().extend(traits).build())
+            .build()
+    });
+    let images = output.images.iter().map(|image| {
+        let error = image.error.map(|code| {
+            Uint64::new_builder()
+                .set(code.to_le_bytes().map(Byte::new))
+                .build()
+        });
+        OutputImage::new_builder()
+            .name(molecule_bytes(image.name.as_bytes()))
+            .image_type(molecule_bytes(image.type_.as_bytes()))
+            .content(molecule_bytes(image.content.as_bytes()))
+            .error(Uint64Opt::new_builder().set(error).build())
+            .build()
+    });
+    Output::new_builder()
+        .traits(TraitEntryVec::new_builder().extend(traits).build())
+        .images(OutputImageVec::new_builder().extend(images).build())
+        .build()
+}
+
+fn molecule_bytes(bytes: &[u8]) -> Bytes {
+    Bytes::new_builder()
+        .set(bytes.iter().copied().map(Byte::new).collect())
+        .build()
+}
+
+pub fn frame_diagnostic_error(code: u64) -> Vec<u8> {
+    frame(
+        Channel::Diagnostic,
+        format!("{{\"error\":{code}}}").as_bytes(),
+    )
+}
+
+// `output` cut into `Channel::Chunk` frames of at most `chunk_size` payload bytes, an
+// empty output still takes one chunk
+pub fn frame_chunks(output: &[u8], chunk_size: usize) -> Vec<Vec<u8>> {
+    let chunk_size = chunk_size.max(1);
+    let count = output.len().div_ceil(chunk_size).max(1);
+    (0..count)
+        .map(|index| {
+            let start = index * chunk_size;
+            let end = output.len().min(start + chunk_size);
+            let mut payload = Vec::with_capacity(CHUNK_HEADER_SIZE + end - start);
+            payload.extend_from_slice(format!("{index:08x}{count:08x}").as_bytes());
+            payload.extend_from_slice(&output[start..end]);
+            frame(Channel::Chunk, &payload)
+        })
+        .collect()
+}
+
+// reassembles the output from a complete and ordered run of chunk frames
+pub fn unchunk(mut bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    let mut expected = 0;
+    loop {
+        let (channel, payload, rest) = unframe(bytes)?;
+        if channel != Channel::Chunk || payload.len() < CHUNK_HEADER_SIZE {
+            return None;
+        }
+        let header = core::str::from_utf8(&payload[..CHUNK_HEADER_SIZE]).ok()?;
+        let index = usize::from_str_radix(&header[..8], 16).ok()?;
+        let count = usize::from_str_radix(&header[8..], 16).ok()?;
+        if index != expected {
+            return None;
+        }
+        output.extend_from_slice(&payload[CHUNK_HEADER_SIZE..]);
+        expected += 1;
+        if expected == count {
+            return rest.is_empty().then_some(output);
+        }
+        bytes = rest;
+    }
+}
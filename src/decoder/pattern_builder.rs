@@ -0,0 +1,309 @@
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use molecule::prelude::{Builder, Byte, Entity};
+use serde_json::Value;
+
+use super::input::MOLECULE_TAG;
+use super::schema::{decode_schema_row, decode_trait_schema};
+use super::types::{Compression, Error, ImageType, Pattern, SchemaOptions, TraitSchema};
+use crate::generated::{Bytes, Cell, CellUnion, Json, Row, RowVec, Uint64};
+
+impl TraitSchema {
+    pub fn new(
+        name: &str,
+        type_: ImageType,
+        dob0_trait: &str,
+        pattern: Pattern,
+        args: Option<Value>,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            type_,
+            dob0_trait: dob0_trait.to_owned(),
+            dob0_traits: Vec::new(),
+            pattern,
+            compression: None,
+            args,
+            options: SchemaOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: SchemaOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    // the row as a pattern writes it, `decode_trait_schema` reads it back into `self`
+    pub fn encode(&self) -> Vec<Value> {
+        let type_ = match (&self.type_, &self.compression) {
+            (ImageType::ColorCode, _) => "color",
+            (ImageType::URI, _) => "uri",
+            (ImageType::RawImage, Some(Compression::Gzip)) => "image+gzip",
+            (ImageType::RawImage, None) => "image",
+            (ImageType::SVG, _) => "svg",
+            (ImageType::Text, _) => "text",
+        };
+        let pattern = match self.pattern {
+            Pattern::Options => "options",
+            Pattern::Range => "range",
+            Pattern::Raw => "raw",
+            Pattern::Template => "template",
+            Pattern::WeightedRandom => "weighted",
+            Pattern::Mod => "mod",
+            Pattern::Bucket => "bucket",
+            Pattern::Expr => "expr",
+        };
+        let mut values = vec![
+            Value::String(self.name.clone()),
+            Value::String(type_.to_owned()),
+            match self.dob0_traits.as_slice() {
+                [] => Value::String(self.dob0_trait.clone()),
+                dob0_traits => dob0_traits.iter().cloned().map(Value::String).collect(),
+            },
+            Value::String(pattern.to_owned()),
+        ];
+        if let Some(args) = &self.args {
+            values.push(args.clone());
+        }
+        let options = (!self.options.is_empty())
+            .then(|| serde_json::to_value(&self.options).ok())
+            .flatten();
+        if let Some(options) = options {
+            if self.args.is_none() {
+                values.push(Value::Null);
+            }
+            values.push(options);
+        }
+        values
+    }
+
+    pub fn to_value(&self) -> Value {
+        Value::Array(self.encode())
+    }
+
+    // a single row, directives and macros span the whole pattern and are left to
+    // `decode_trait_schema`'s callers
+    pub fn from_value(value: &Value) -> Result<Self, Error> {
+        let row = value.as_array().ok_or(Error::ParseInvalidTraitsBase)?;
+        decode_schema_row(row)
+    }
+}
+
+// the rows of a pattern one call at a time, for tools deploying clusters:
+//
+//   PatternBuilder::layer("0")
+//       .color("Name")
+//       .option("Ethan", "#FF0000")
+//       .fallback("#FFFFFF")
+//       .group("1")
+//       .uri("Age")
+//       .range(0, 50, "btcfs://...")
+//
+// a type call starts a row of the current group and the calls after it fill its args,
+// nothing is checked until `build` runs the rows through the decoder's own schema parsing
+#[derive(Default)]
+pub struct PatternBuilder {
+    name: String,
+    rows: Vec<TraitSchema>,
+    error: Option<Error>,
+}
+
+impl PatternBuilder {
+    pub fn layer(name: &str) -> Self {
+        Self::default().group(name)
+    }
+
+    // rows from here on render into the image group `name`
+    pub fn group(mut self, name: &str) -> Self {
+        name.clone_into(&mut self.name);
+        self
+    }
+
+    pub fn color(self, dob0_trait: &str) -> Self {
+        self.row(ImageType::ColorCode, dob0_trait)
+    }
+
+    pub fn uri(self, dob0_trait: &str) -> Self {
+        self.row(ImageType::URI, dob0_trait)
+    }
+
+    pub fn image(self, dob0_trait: &str) -> Self {
+        self.row(ImageType::RawImage, dob0_trait)
+    }
+
+    pub fn svg(self, dob0_trait: &str) -> Self {
+        self.row(ImageType::SVG, dob0_trait)
+    }
+
+    pub fn text(self, dob0_trait: &str) -> Self {
+        self.row(ImageType::Text, dob0_trait)
+    }
+
+    // a row built by hand, for patterns the fluent calls don't cover
+    pub fn push(mut self, schema: TraitSchema) -> Self {
+        self.rows.push(schema);
+        self
+    }
+
+    // the layer takes the DOB/0 value itself, which is also what a row starts out as
+    pub fn raw(self) -> Self {
+        self.with_row(|row| {
+            row.pattern = Pattern::Raw;
+            Ok(())
+        })
+    }
+
+    pub fn option(self, dob0_value: impl Into<Value>, dob1_value: &str) -> Self {
+        self.entry(Pattern::Options, vec![dob0_value.into(), dob1_value.into()])
+    }
+
+    pub fn range(self, start: i64, end: i64, dob1_value: &str) -> Self {
+        let range = Value::from(vec![start, end]);
+        self.entry(Pattern::Range, vec![range, dob1_value.into()])
+    }
+
+    pub fn weighted(self, weight: u64, dob1_value: &str) -> Self {
+        self.entry(
+            Pattern::WeightedRandom,
+            vec![weight.into(), dob1_value.into()],
+        )
+    }
+
+    // the `*` entry matching any DOB/0 value of an options or range row
+    pub fn fallback(self, dob1_value: &str) -> Self {
+        let pattern = match self.rows.last() {
+            Some(row) if row.pattern == Pattern::Range => Pattern::Range,
+            _ => Pattern::Options,
+        };
+        self.entry(pattern, vec![Value::from(vec!["*"]), dob1_value.into()])
+    }
+
+    pub fn template(self, template: &str) -> Self {
+        self.with_row(|row| {
+            row.pattern = Pattern::Template;
+            row.args = Some(template.into());
+            Ok(())
+        })
+    }
+
+    // raw image values are gzip streams inflated by the decoder, see `Compression`
+    pub fn gzip(self) -> Self {
+        self.with_row(|row| match row.type_ {
+            ImageType::RawImage => {
+                row.compression = Some(Compression::Gzip);
+                Ok(())
+            }
+            _ => Err(Error::SchemaTypeMismatch),
+        })
+    }
+
+    pub fn options(self, options: SchemaOptions) -> Self {
+        self.with_row(|row| {
+            row.options = options;
+            Ok(())
+        })
+    }
+
+    // the rows as the decoder parses them
+    pub fn build(self) -> Result<Vec<TraitSchema>, Error> {
+        decode_trait_schema(self.encode()?)
+    }
+
+    // the pattern as the json array a cluster stores
+    pub fn to_json(self) -> Result<Value, Error> {
+        let rows = self.encode()?;
+        decode_trait_schema(rows.clone())?;
+        Ok(rows.into_iter().map(Value::Array).collect())
+    }
+
+    // the pattern as the molecule `RowVec` a cluster stores, with the leading
+    // `MOLECULE_TAG` that tells it apart from json
+    pub fn to_molecule(self) -> Result<Vec<u8>, Error> {
+        let rows = self.encode()?;
+        decode_trait_schema(rows.clone())?;
+        let mut bytes = vec![MOLECULE_TAG];
+        bytes.extend_from_slice(encode_molecule_rows(&rows).as_slice());
+        Ok(bytes)
+    }
+
+    fn encode(self) -> Result<Vec<Vec<Value>>, Error> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        Ok(self.rows.iter().map(TraitSchema::encode).collect())
+    }
+
+    fn row(mut self, type_: ImageType, dob0_trait: &str) -> Self {
+        let row = TraitSchema::new(&self.name, type_, dob0_trait, Pattern::Raw, None);
+        self.rows.push(row);
+        self
+    }
+
+    // the first failing call is the one `build` reports
+    fn with_row<F>(mut self, update: F) -> Self
+    where
+        F: FnOnce(&mut TraitSchema) -> Result<(), Error>,
+    {
+        let result = match self.rows.last_mut() {
+            Some(row) => update(row),
+            None => Err(Error::SchemaInsufficientElements),
+        };
+        if let (Err(err), None) = (result, &self.error) {
+            self.error = Some(err);
+        }
+        self
+    }
+
+    // appends an args entry, the first one decides the row's pattern
+    fn entry(self, pattern: Pattern, entry: Vec<Value>) -> Self {
+        self.with_row(|row| match &mut row.args {
+            None => {
+                row.pattern = pattern;
+                row.args = Some(Value::Array(vec![entry.into()]));
+                Ok(())
+            }
+            Some(Value::Array(entries)) if row.pattern == pattern => {
+                entries.push(entry.into());
+                Ok(())
+            }
+            _ => Err(Error::SchemaPatternMismatch),
+        })
+    }
+}
+
+// rows in the molecule encoding `input::decode_traits_pool` reads, text and unsigned
+// numbers get their own cells and anything else is kept as json
+pub fn encode_molecule_rows(rows: &[Vec<Value>]) -> RowVec {
+    let bytes = |bytes: &[u8]| {
+        Bytes::new_builder()
+            .set(bytes.iter().copied().map(Byte::new).collect())
+            .build()
+    };
+    let rows = rows.iter().map(|row| {
+        let cells = row.iter().map(|cell| {
+            let cell = match cell {
+                Value::String(text) => CellUnion::from(bytes(text.as_bytes())),
+                Value::Number(number) if number.is_u64() => {
+                    let number = number.as_u64().unwrap_or_default();
+                    CellUnion::from(
+                        Uint64::new_builder()
+                            .set(number.to_le_bytes().map(Byte::new))
+                            .build(),
+                    )
+                }
+                json => CellUnion::from(
+                    Json::new_builder()
+                        .set(json.to_string().bytes().map(Byte::new).collect())
+                        .build(),
+                ),
+            };
+            Cell::new_builder().set(cell).build()
+        });
+        Row::new_builder().extend(cells).build()
+    });
+    RowVec::new_builder().extend(rows).build()
+}
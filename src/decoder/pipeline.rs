@@ -0,0 +1,1275 @@
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String, vec, vec::Vec};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blake2b_ref::Blake2bBuilder;
+use core::cell::{Cell, RefCell};
+use molecule::prelude::{Builder, Entity, Reader};
+
+use super::args::parse_parameters;
+use super::loader::CellReference;
+use super::output::{
+    encode_dob1_output, encode_output, frame_chunks, frame_diagnostic_error, frame_dob1_prelude,
+    frame_json, Channel, CHUNK_HEADER_SIZE, DEFAULT_CHUNK_SIZE, FRAME_HEADER_SIZE,
+};
+use super::rng::digest;
+use super::types::{
+    BatchOutput, Config, DOB0Output, DOB1Output, DisplayTrait, Error, Image, ImageFormat,
+    ImageMetadata, ImageReport, LayerGroup, LayerSource, OutputMode, OutputProtocol, Overflow,
+    Parameters, RenderReport, ReportMode, APNG_MIME, CACHE_REFERENCE_TYPE, COMMITMENT_ENCODING,
+    ERROR_TYPE, INTEGRITY_FAILED_TYPE, PNG_TYPE, SVG_TYPE, TRUNCATED_TYPE, URI_LIST_TYPE,
+};
+use super::validate::dobs_validate_pattern;
+use super::{
+    build_item_vec, cached_fingerprint, compose_svg, dobs_display_traits, dobs_output_traits,
+    dobs_resolve_layer_groups, json, layer_sources, translate_trait, truncate_layers, uri_list,
+};
+use crate::generated::{ImageVecReader, ItemVec, ItemVecVec};
+use crate::host::Host;
+use crate::log::{self, Diagnostic, Log};
+use crate::stats::{self, Counter, Counters, Stats};
+use crate::{log_debug, log_warn};
+
+// ceiling of combine and write syscalls per run unless the config sets `syscall_budget`
+pub const DEFAULT_SYSCALL_BUDGET: u64 = 1024;
+
+// bytes offered to the combiner on the first call unless the config sets
+// `combine_buffer_size`, images up to this size take a single call
+pub const DEFAULT_COMBINE_BUFFER_SIZE: usize = 64 * 1024;
+
+// leads the optional mime header of a composed image, see `split_mime_header`
+pub const MIME_HEADER_MARK: u8 = 0;
+
+// the combiner's answer when fetched bytes miss the digest of an `Integrity` item
+pub const COMBINE_INTEGRITY_MISMATCH: u64 = 2;
+// when an asset could not be fetched, e.g. a dead uri or an unreachable gateway
+pub const COMBINE_FETCH_FAILED: u64 = 3;
+// when fetched or raw bytes are no image the combiner decodes
+pub const COMBINE_UNDECODABLE_ASSET: u64 = 4;
+// when the pattern holds an item the combiner doesn't know, e.g. of a newer decoder
+pub const COMBINE_UNSUPPORTED_ITEM: u64 = 5;
+
+// the combiner's answers naming why it failed, any other one is taken for a host without
+// a combiner on the first call and fails as `Error::CombineSyscallFailed` after it
+const COMBINE_ERRORS: [(u64, Error); 4] = [
+    (COMBINE_INTEGRITY_MISMATCH, Error::ComposeIntegrityMismatch),
+    (COMBINE_FETCH_FAILED, Error::ComposeFetchFailed),
+    (COMBINE_UNDECODABLE_ASSET, Error::ComposeUndecodableAsset),
+    (COMBINE_UNSUPPORTED_ITEM, Error::ComposeUnsupportedItem),
+];
+
+fn combine_error(code: u64) -> Option<Error> {
+    COMBINE_ERRORS
+        .iter()
+        .find_map(|&(answer, err)| (answer == code).then_some(err))
+}
+
+fn combine_code(err: Error) -> Option<u64> {
+    COMBINE_ERRORS
+        .iter()
+        .find_map(|&(answer, named)| (named as u64 == err as u64).then_some(answer))
+}
+
+// one decoding run against a host, everything it combines or writes spends the same
+// syscall budget and counts towards the same output limit, a detached run has no host and
+// only serves callers decoding without one
+pub struct Run<'a> {
+    host: Option<RefCell<&'a mut dyn Host>>,
+    issued: Cell<u64>,
+    limit: Cell<u64>,
+    written: Cell<u64>,
+    max_output: Cell<Option<u64>>,
+    stats: Counters,
+}
+
+impl<'a> Run<'a> {
+    pub fn new(host: &'a mut dyn Host) -> Self {
+        Run {
+            host: Some(RefCell::new(host)),
+            ..Run::detached()
+        }
+    }
+
+    pub fn detached() -> Self {
+        Run {
+            host: None,
+            issued: Cell::new(0),
+            limit: Cell::new(DEFAULT_SYSCALL_BUDGET),
+            written: Cell::new(0),
+            max_output: Cell::new(None),
+            stats: Counters::default(),
+        }
+    }
+
+    // the limits of the parsed config, which the arguments before it ran without
+    fn configure(&self, config: &Config) {
+        let limit = config.syscall_budget.unwrap_or(DEFAULT_SYSCALL_BUDGET);
+        self.count(Counter::SyscallBudget, limit);
+        self.limit.set(limit);
+        self.max_output.set(config.max_output_bytes);
+    }
+
+    fn spend(&self) -> Result<(), Error> {
+        let issued = self.issued.get().saturating_add(1);
+        if issued > self.limit.get() {
+            return Err(Error::SyscallBudgetExceeded);
+        }
+        self.issued.set(issued);
+        Ok(())
+    }
+
+    // the host's combiner, a detached run has none and fails the probe
+    fn combine(&self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+        match &self.host {
+            Some(host) => host.borrow_mut().combine(buffer, buffer_size, pattern),
+            None => 1,
+        }
+    }
+
+    fn combines_batches(&self) -> bool {
+        self.host
+            .as_ref()
+            .is_some_and(|host| host.borrow().combines_batches())
+    }
+
+    fn combine_batch(&self, buffer: &mut [u8], buffer_size: &mut u64, patterns: &[u8]) -> u64 {
+        match &self.host {
+            Some(host) => host
+                .borrow_mut()
+                .combine_batch(buffer, buffer_size, patterns),
+            None => 1,
+        }
+    }
+
+    pub(crate) fn reads_cells(&self) -> bool {
+        self.host
+            .as_ref()
+            .is_some_and(|host| host.borrow().reads_cells())
+    }
+
+    pub(crate) fn load_cell(&self, cell: &CellReference) -> Option<Vec<u8>> {
+        self.host
+            .as_ref()
+            .and_then(|host| host.borrow_mut().load_cell(cell))
+    }
+
+    pub(crate) fn count(&self, counter: Counter, value: u64) {
+        self.stats.add(counter, value);
+    }
+
+    fn heap_peak(&self) -> Option<usize> {
+        self.host
+            .as_ref()
+            .and_then(|host| host.borrow().heap_peak())
+    }
+
+    fn sample_heap(&self) {
+        if let Some(peak) = self.heap_peak() {
+            self.stats.record_heap(peak);
+        }
+    }
+
+    fn stats(&self) -> Stats {
+        self.stats.snapshot()
+    }
+
+    fn written(&self) -> u64 {
+        self.written.get()
+    }
+
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        self.reserve(bytes)?;
+        self.write_unbudgeted(bytes);
+        Ok(())
+    }
+
+    // spends a write syscall on `bytes` and counts them towards the output limit
+    fn reserve(&self, bytes: &[u8]) -> Result<(), Error> {
+        let total = self.written.get().saturating_add(bytes.len() as u64);
+        if self.max_output.get().is_some_and(|max| total > max) {
+            return Err(Error::OutputTooLarge);
+        }
+        self.written.set(total);
+        self.spend()?;
+        self.count(Counter::WriteSyscalls, 1);
+        self.count(Counter::OutputBytes, bytes.len() as u64);
+        Ok(())
+    }
+
+    // for the last words of a run, which are never held back by its limits
+    fn write_unbudgeted(&self, bytes: &[u8]) {
+        if let Some(host) = &self.host {
+            host.borrow_mut().write(bytes);
+        }
+    }
+}
+
+impl Log for Run<'_> {
+    // a record past the budget or the output limit is dropped, logging never fails a run
+    fn write_log(&self, frame: &[u8]) {
+        let Some(host) = &self.host else {
+            return;
+        };
+        if self.reserve(frame).is_ok() {
+            host.borrow_mut().write_log(frame);
+        }
+    }
+}
+
+// the combiner and sink of `dobs_run` as a host
+struct Callbacks<C, W> {
+    combiner: C,
+    sink: W,
+}
+
+impl<C, W> Host for Callbacks<C, W>
+where
+    C: FnMut(&mut [u8], &mut u64, &[u8]) -> u64,
+    W: FnMut(&[u8]),
+{
+    fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+        (self.combiner)(buffer, buffer_size, pattern)
+    }
+
+    fn write(&mut self, output: &[u8]) -> u64 {
+        (self.sink)(output);
+        0
+    }
+
+    // `sink` only ever receives the output
+    fn write_log(&mut self, _: &[u8]) -> u64 {
+        0
+    }
+}
+
+// values keyed by the ItemVec they belong to, digests may collide so each value keeps its
+// pattern to compare against
+struct PatternMap<T>(BTreeMap<u64, (ItemVec, T)>);
+
+impl<T> Default for PatternMap<T> {
+    fn default() -> Self {
+        PatternMap(BTreeMap::new())
+    }
+}
+
+impl<T> PatternMap<T> {
+    fn get(&self, pattern: &ItemVec) -> Option<&T> {
+        self.0
+            .get(&digest(pattern.as_slice()))
+            .filter(|(key, _)| key.as_slice() == pattern.as_slice())
+            .map(|(_, value)| value)
+    }
+
+    // the first value of a digest is kept, a colliding pattern just goes without
+    fn insert(&mut self, pattern: &ItemVec, value: T) {
+        self.0
+            .entry(digest(pattern.as_slice()))
+            .or_insert_with(|| (pattern.clone(), value));
+    }
+}
+
+// composed images of the whole run, a pattern shared by several groups or tokens, such
+// as a common background, is only combined once
+type CombineCache = PatternMap<Vec<u8>>;
+
+// name and type of the first image of an output composed from each pattern
+type References = PatternMap<(String, String)>;
+
+// the whole decoding run of the binary, from argv to the written output
+//
+// `combiner` mirrors the image combining syscall: it is first probed with an empty buffer
+// to learn the image size through `buffer_size`, then called again to fill the buffer,
+// `sink` receives every null-terminated chunk of output
+pub fn dobs_run<C, W>(args: Vec<&[u8]>, combiner: C, sink: W) -> u64
+where
+    C: FnMut(&mut [u8], &mut u64, &[u8]) -> u64,
+    W: FnMut(&[u8]),
+{
+    dobs_run_host(args, &mut Callbacks { combiner, sink })
+}
+
+// `dobs_run` with the syscalls of `host`
+pub fn dobs_run_host<H: Host>(args: Vec<&[u8]>, host: &mut H) -> u64 {
+    let run = Run::new(host);
+    let code = run_args(args, &run);
+    if let Some(peak) = run.heap_peak() {
+        log_debug!(&run, "heap peak {peak} bytes");
+    }
+    code
+}
+
+fn run_args(args: Vec<&[u8]>, run: &Run) -> u64 {
+    // the pattern alone asks for a validation report instead of images
+    if let [images_base] = args.as_slice() {
+        let report = dobs_validate_pattern(images_base, run);
+        let Ok(mut output) = json::to_vec(&report) else {
+            return Error::SerializeFailed as u64;
+        };
+        output.push(0);
+        run.write_unbudgeted(&output);
+        return match report.error {
+            Some(code) => code,
+            None if !report.valid => Error::ValidatePatternFailed as u64,
+            None => 0,
+        };
+    }
+    let dob_params = match parse_parameters(args, run) {
+        Ok(value) => value,
+        Err(err) => {
+            log::diagnose(run, &Diagnostic::new(err));
+            return err as u64;
+        }
+    };
+    run.sample_heap();
+    run.configure(&dob_params.config);
+    let framed = dob_params.config.output != OutputProtocol::Blob;
+    match dobs_render(dob_params, run) {
+        Ok(()) => 0,
+        Err(err) => {
+            log::diagnose(run, &Diagnostic::new(err));
+            // the error frame itself is never held back by the syscall budget
+            let code = err as u64;
+            if framed {
+                run.write_unbudgeted(&frame_diagnostic_error(code));
+            }
+            code
+        }
+    }
+}
+
+// the decoded DOB/1 output without serializing or writing anything, `combiner` is the
+// same image combining callback `dobs_run` takes
+pub fn dobs_decode<C>(args: Vec<&[u8]>, combiner: C) -> Result<DOB1Output, Error>
+where
+    C: FnMut(&mut [u8], &mut u64, &[u8]) -> u64,
+{
+    let sink = |_: &[u8]| {};
+    dobs_decode_host(args, &mut Callbacks { combiner, sink })
+}
+
+// `dobs_decode` with the syscalls of `host`, which is never written to
+pub fn dobs_decode_host<H: Host>(args: Vec<&[u8]>, host: &mut H) -> Result<DOB1Output, Error> {
+    let run = Run::new(host);
+    let mut dob_params = parse_parameters(args, &run)?;
+    // a batch has no single output to return
+    if dob_params.tokens.is_some() {
+        return Err(Error::ParseInvalidDOB0Output);
+    }
+    run.sample_heap();
+    run.configure(&dob_params.config);
+    let mut cache = CombineCache::default();
+    decode_output(&mut dob_params, &run, &mut cache)
+}
+
+struct Resolved {
+    layer_groups: Vec<Result<LayerGroup, (String, Error)>>,
+    layers: Option<Vec<LayerGroup>>,
+    display: Option<Vec<DisplayTrait>>,
+    // see `SchemaOptions.as_trait`
+    synthesized: Vec<DOB0Output>,
+}
+
+// the traits the output echoes, followed by the ones synthesized from layer selections
+fn output_traits(
+    dob_params: &Parameters,
+    dob0_output: Vec<DOB0Output>,
+    synthesized: Vec<DOB0Output>,
+) -> Vec<DOB0Output> {
+    let translations = dob_params
+        .locale
+        .as_ref()
+        .and_then(|locale| dob_params.directives.i18n.get(locale));
+    let mut traits = dobs_output_traits(&dob_params.directives.traits, translations, dob0_output);
+    traits.extend(synthesized.into_iter().map(|mut output| {
+        if let Some(translation) = translations.and_then(|traits| traits.get(&output.name)) {
+            translate_trait(translation, &mut output);
+        }
+        output
+    }));
+    traits
+}
+
+fn resolve(dob_params: &Parameters, run: &Run) -> Result<Resolved, Error> {
+    let layer_groups = dobs_resolve_layer_groups(dob_params, run);
+    if !dob_params.config.recover {
+        if let Some((_, err)) = layer_groups.iter().find_map(|group| group.as_ref().err()) {
+            return Err(*err);
+        }
+    }
+    let mode = dob_params.config.mode();
+    let display = display_traits(dob_params);
+    let synthesized = layer_groups
+        .iter()
+        .filter_map(|group| group.as_ref().ok())
+        .flat_map(|group| group.traits.iter().cloned())
+        .collect();
+    if mode != OutputMode::Traits {
+        return Ok(Resolved {
+            layer_groups,
+            layers: None,
+            display,
+            synthesized,
+        });
+    }
+    let layers = layer_groups
+        .into_iter()
+        .filter_map(|group| match group {
+            Ok(group) => Some(group),
+            Err((name, _)) => {
+                log_warn!(run, "group {name}: skipped in traits only output");
+                None
+            }
+        })
+        .collect();
+    Ok(Resolved {
+        layer_groups: vec![],
+        layers: Some(layers),
+        display,
+        synthesized,
+    })
+}
+
+fn display_traits(dob_params: &Parameters) -> Option<Vec<DisplayTrait>> {
+    dob_params
+        .config
+        .locale
+        .as_ref()
+        .filter(|_| dob_params.config.mode() != OutputMode::Images)
+        .map(|locale| dobs_display_traits(dob_params, locale))
+}
+
+// the output of the resolved groups and their images, see `decode_output`
+fn dob1_output(
+    dob_params: &mut Parameters,
+    resolved: Resolved,
+    images: Vec<Image>,
+    report: Option<RenderReport>,
+) -> DOB1Output {
+    DOB1Output {
+        traits: (dob_params.config.mode() != OutputMode::Images).then(|| {
+            let dob0_output = core::mem::take(&mut dob_params.dob0_output);
+            output_traits(dob_params, dob0_output, resolved.synthesized)
+        }),
+        images,
+        layers: resolved.layers,
+        display: resolved.display,
+        context: dob_params.config.context.clone(),
+        stats: None,
+        report,
+    }
+}
+
+// whether decoding has any image to compose, patterns without image rows and the traits
+// mode have none
+fn composes_images(dob_params: &Parameters) -> bool {
+    !dob_params.images_base.is_empty() && dob_params.config.mode() != OutputMode::Traits
+}
+
+// `decode_output` of a run composing no image, built from the resolved groups alone
+// without rendering any of them
+fn traits_output(dob_params: &mut Parameters, run: &Run) -> Result<DOB1Output, Error> {
+    let resolved = resolve(dob_params, run)?;
+    let report = dob_params.report.map(|_| {
+        layer_report(
+            dob_params,
+            &resolved.layer_groups,
+            resolved.layers.as_deref(),
+        )
+    });
+    Ok(dob1_output(dob_params, resolved, vec![], report))
+}
+
+fn render(
+    dob_params: &Parameters,
+    group: Result<LayerGroup, (String, Error)>,
+    run: &Run,
+    cache: &mut CombineCache,
+    references: &mut References,
+) -> Result<Image, Error> {
+    let (name, image) = match group {
+        Ok(group) => (
+            group.name.clone(),
+            compose_image(dob_params, group, run, cache, references),
+        ),
+        Err((name, err)) => (name, Err(err)),
+    };
+    recovered(dob_params, name, image, run)
+}
+
+fn recovered(
+    dob_params: &Parameters,
+    name: String,
+    image: Result<Image, Error>,
+    run: &Run,
+) -> Result<Image, Error> {
+    run.sample_heap();
+    let limit = dob_params.config.max_image_bytes;
+    let image = image.and_then(|image| match limit {
+        // also for images composed without the combiner
+        Some(limit) if content_bytes(&image) as u64 > limit => Err(Error::OutputTooLarge),
+        _ => Ok(image),
+    });
+    match image {
+        Err(Error::ComposeIntegrityMismatch) => {
+            log_warn!(run, "group {name}: assets failed their integrity check");
+            let mut image = error_image(name, Error::ComposeIntegrityMismatch);
+            image.type_ = INTEGRITY_FAILED_TYPE.to_owned();
+            Ok(image)
+        }
+        Err(err) if dob_params.config.recover && !matches!(err, Error::SyscallBudgetExceeded) => {
+            log_warn!(run, "group {name}: failed with error {}", err as u64);
+            Ok(error_image(name, err))
+        }
+        image => image,
+    }
+}
+
+// renders the groups in order, every image is handed to `emit` as soon as it is ready
+fn render_groups<E>(
+    dob_params: &Parameters,
+    layer_groups: Vec<Result<LayerGroup, (String, Error)>>,
+    run: &Run,
+    cache: &mut CombineCache,
+    mut emit: E,
+) -> Result<(), Error>
+where
+    E: FnMut(Image) -> Result<(), Error>,
+{
+    let mut references = References::default();
+    if !dob_params.config.batch {
+        for group in layer_groups {
+            emit(render(dob_params, group, run, cache, &mut references)?)?;
+        }
+        return Ok(());
+    }
+    let compositions = layer_groups
+        .into_iter()
+        .map(|group| {
+            group.and_then(|group| {
+                let name = group.name.clone();
+                prepare_image(dob_params, group, run).map_err(|err| (name, err))
+            })
+        })
+        .collect::<Vec<_>>();
+    // the batch fills the cache, images it leaves out are combined one by one
+    let mut pending = PatternMap::default();
+    for composition in &compositions {
+        if let Ok(Composition::Combine { pattern, .. }) = composition {
+            if cache.get(pattern).is_none() {
+                pending.insert(pattern, ());
+            }
+        }
+    }
+    let patterns = pending
+        .0
+        .into_values()
+        .map(|(pattern, _)| pattern)
+        .collect::<Vec<_>>();
+    let capacity = combine_buffer_size(&dob_params.config);
+    let limit = dob_params.config.max_image_bytes;
+    if let Some(images) = batch_combine(patterns.clone(), capacity, limit, run)? {
+        for (pattern, image) in patterns.iter().zip(images) {
+            cache.insert(pattern, image);
+        }
+    }
+    for composition in compositions {
+        let (name, image) = match composition {
+            Ok(Composition::Ready(image)) => (image.name.clone(), Ok(image)),
+            Ok(Composition::Combine {
+                group,
+                sources,
+                preview,
+                pattern,
+            }) => {
+                let name = group.name.clone();
+                let image = combined_image(
+                    dob_params,
+                    group,
+                    sources,
+                    preview,
+                    pattern,
+                    &mut references,
+                    |group, pattern| combine(dob_params, group, pattern, run, cache),
+                );
+                (name, image)
+            }
+            Err((name, err)) => (name, Err(err)),
+        };
+        emit(recovered(dob_params, name, image, run)?)?;
+    }
+    Ok(())
+}
+
+// `dob_params` is left without its DOB/0 output, which moves into the decoded one
+fn decode_output(
+    dob_params: &mut Parameters,
+    run: &Run,
+    cache: &mut CombineCache,
+) -> Result<DOB1Output, Error> {
+    if !composes_images(dob_params) {
+        return traits_output(dob_params, run);
+    }
+    let mut resolved = resolve(dob_params, run)?;
+    let mut report = dob_params.report.map(|_| {
+        layer_report(
+            dob_params,
+            &resolved.layer_groups,
+            resolved.layers.as_deref(),
+        )
+    });
+    let mut images = vec![];
+    let layer_groups = core::mem::take(&mut resolved.layer_groups);
+    render_groups(dob_params, layer_groups, run, cache, |image| {
+        images.push(image);
+        Ok(())
+    })?;
+    if let Some(report) = report.as_mut() {
+        report.images = images.iter().map(image_report).collect();
+    }
+    Ok(dob1_output(dob_params, resolved, images, report))
+}
+
+// the layer counts of a `RenderReport`, which is left without images
+fn layer_report(
+    dob_params: &Parameters,
+    layer_groups: &[Result<LayerGroup, (String, Error)>],
+    layers: Option<&[LayerGroup]>,
+) -> RenderReport {
+    let background = dob_params.cluster.background.is_some() as usize;
+    let groups = layer_groups
+        .iter()
+        .filter_map(|group| group.as_ref().ok())
+        .chain(layers.into_iter().flatten());
+    let mut report = RenderReport::default();
+    for group in groups {
+        report.layers_matched += group.layers.len().saturating_sub(background) as u64;
+        report.layers_skipped += group.skipped as u64;
+    }
+    report
+}
+
+fn image_report(image: &Image) -> ImageReport {
+    let bytes = match image.error {
+        Some(_) => 0,
+        None => content_bytes(image),
+    };
+    ImageReport {
+        name: image.name.clone(),
+        type_: image.type_.clone(),
+        bytes: bytes as u64,
+        error: image.error,
+    }
+}
+
+// the size of the image itself, which base64 contents are decoded to
+fn content_bytes(image: &Image) -> usize {
+    let content = image.content.as_bytes();
+    match image.type_.ends_with(";base64") {
+        true => {
+            let padding = content
+                .iter()
+                .rev()
+                .take_while(|&&byte| byte == b'=')
+                .count();
+            (content.len() / 4 * 3).saturating_sub(padding)
+        }
+        false => content.len(),
+    }
+}
+
+// every token decoded in turn against the pattern parsed once, all of them spending the
+// same syscall budget and sharing the composed images
+fn decode_batch(
+    mut dob_params: Parameters,
+    tokens: Vec<(String, Vec<DOB0Output>)>,
+    run: &Run,
+    cache: &mut CombineCache,
+) -> Result<Vec<BatchOutput>, Error> {
+    tokens
+        .into_iter()
+        .map(|(token_id, dob0_output)| {
+            dob_params.dob0_output = dob0_output;
+            let output = decode_output(&mut dob_params, run, cache)?;
+            Ok(BatchOutput { token_id, output })
+        })
+        .collect()
+}
+
+fn dobs_render(mut dob_params: Parameters, run: &Run) -> Result<(), Error> {
+    let mut cache = CombineCache::default();
+    let max_output = dob_params.config.max_output_bytes;
+    let truncate = max_output.filter(|_| dob_params.config.overflow == Overflow::Truncate);
+
+    // in framed mode everything but the images goes out before the first composition
+    if dob_params.config.output == OutputProtocol::Framed {
+        let resolved = resolve(&dob_params, run)?;
+        let traits = (dob_params.config.mode() != OutputMode::Images).then(|| {
+            let dob0_output = dob_params.dob0_output.clone();
+            output_traits(&dob_params, dob0_output, resolved.synthesized)
+        });
+        frame_dob1_prelude(
+            traits.as_ref(),
+            resolved.display.as_ref(),
+            resolved.layers.as_ref(),
+            dob_params.config.context.as_ref(),
+        )?
+        .iter()
+        .try_for_each(|frame| run.write(frame))?;
+        let mut report = dob_params.report.map(|_| {
+            layer_report(
+                &dob_params,
+                &resolved.layer_groups,
+                resolved.layers.as_deref(),
+            )
+        });
+        render_groups(
+            &dob_params,
+            resolved.layer_groups,
+            run,
+            &mut cache,
+            |image| {
+                let mut frame = frame_json(Channel::Image, &image)?;
+                let image = match truncate {
+                    Some(max) if run.written().saturating_add(frame.len() as u64) > max => {
+                        let image = truncated_image(image.name);
+                        frame = frame_json(Channel::Image, &image)?;
+                        image
+                    }
+                    _ => image,
+                };
+                if let Some(report) = report.as_mut() {
+                    report.images.push(image_report(&image));
+                }
+                run.write(&frame)
+            },
+        )?;
+        if stats::ENABLED {
+            let stats = serde_json::json!({ "stats": run.stats() });
+            let frame = frame_json(Channel::Diagnostic, &stats)?;
+            // the stats give way to a truncating limit like the images do
+            if truncate.is_none_or(|max| run.written().saturating_add(frame.len() as u64) <= max) {
+                run.write(&frame)?;
+            }
+        }
+        if let Some(report) = report {
+            let report = serde_json::json!({ "report": report });
+            run.write(&frame_json(Channel::Diagnostic, &report)?)?;
+        }
+        return Ok(());
+    }
+
+    let chunk_size = (dob_params.config.output == OutputProtocol::Chunked)
+        .then(|| dob_params.config.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE));
+    // the bytes an output of `len` takes once written, with its frames or terminator
+    let written_len = |len: usize| {
+        let len = match chunk_size {
+            Some(chunk_size) => {
+                let count = len.div_ceil(chunk_size.max(1)).max(1);
+                len + count * (FRAME_HEADER_SIZE + CHUNK_HEADER_SIZE + 1)
+            }
+            None => len + 1,
+        };
+        len as u64
+    };
+    let encoding = dob_params.config.encoding;
+    let report_only = dob_params.report == Some(ReportMode::Only);
+    let mut output = match dob_params.tokens.take() {
+        // a batch writes the array of outputs, without stats as they cover the whole run
+        Some(tokens) => {
+            let mut outputs = decode_batch(dob_params, tokens, run, &mut cache)?;
+            if report_only {
+                let reports = outputs
+                    .iter()
+                    .map(|batch| {
+                        serde_json::json!({
+                            "token_id": batch.token_id,
+                            "report": batch.output.report,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                encode_output(&reports, encoding)?
+            } else {
+                let encode = |outputs: &Vec<BatchOutput>| encode_output(outputs, encoding);
+                match truncate {
+                    Some(max) => {
+                        fit_output(&mut outputs, encode, |len| written_len(len) > max, run)?
+                    }
+                    None => encode(&outputs)?,
+                }
+            }
+        }
+        None if report_only => {
+            let dob1_output = decode_output(&mut dob_params, run, &mut cache)?;
+            let report = serde_json::json!({ "report": dob1_output.report });
+            encode_output(&report, encoding)?
+        }
+        None => {
+            let mut dob1_output = decode_output(&mut dob_params, run, &mut cache)?;
+            let mut output = encode_dob1_output(&dob1_output, encoding)?;
+            if stats::ENABLED {
+                // `output_bytes` reports the size of the output without the stats themselves
+                run.count(
+                    Counter::OutputBytes,
+                    (output.len() as u64).saturating_add(1),
+                );
+                dob1_output.stats = Some(run.stats());
+                output = encode_dob1_output(&dob1_output, encoding)?;
+            }
+            match truncate {
+                Some(max) if written_len(output.len()) > max => {
+                    let encode = |output: &DOB1Output| encode_dob1_output(output, encoding);
+                    fit_output(&mut dob1_output, encode, |len| written_len(len) > max, run)?
+                }
+                _ => output,
+            }
+        }
+    };
+    if let Some(chunk_size) = chunk_size {
+        return frame_chunks(&output, chunk_size)
+            .iter()
+            .try_for_each(|chunk| run.write(chunk));
+    }
+    output.push(0);
+    run.write(&output)
+}
+
+// outputs whose images `Overflow::Truncate` may drop
+trait Images {
+    fn images(&mut self) -> Vec<&mut Image>;
+}
+
+impl Images for DOB1Output {
+    fn images(&mut self) -> Vec<&mut Image> {
+        self.images.iter_mut().collect()
+    }
+}
+
+impl Images for Vec<BatchOutput> {
+    fn images(&mut self) -> Vec<&mut Image> {
+        self.iter_mut()
+            .flat_map(|batch| batch.output.images.iter_mut())
+            .collect()
+    }
+}
+
+// replaces images of `output` from the last one on by markers until its encoding is no
+// longer `too_large`, which fails once nothing is left to replace
+fn fit_output<T, E, F>(output: &mut T, encode: E, too_large: F, run: &Run) -> Result<Vec<u8>, Error>
+where
+    T: Images,
+    E: Fn(&T) -> Result<Vec<u8>, Error>,
+    F: Fn(usize) -> bool,
+{
+    let mut encoded = encode(output)?;
+    while too_large(encoded.len()) {
+        let mut images = output.images();
+        let Some(image) = images
+            .iter_mut()
+            .rev()
+            .find(|image| image.type_ != TRUNCATED_TYPE)
+        else {
+            return Err(Error::OutputTooLarge);
+        };
+        let name = core::mem::take(&mut image.name);
+        log_warn!(run, "image {name}: truncated from the output");
+        **image = truncated_image(name);
+        encoded = encode(output)?;
+    }
+    Ok(encoded)
+}
+
+fn truncated_image(name: String) -> Image {
+    let mut image = error_image(name, Error::OutputTooLarge);
+    image.type_ = TRUNCATED_TYPE.to_owned();
+    image
+}
+
+// the content names the error, for readers not knowing the codes
+fn error_image(name: String, err: Error) -> Image {
+    Image {
+        name,
+        type_: ERROR_TYPE.to_owned(),
+        content: err.name().to_owned(),
+        sources: vec![],
+        preview: false,
+        metadata: ImageMetadata::default(),
+        alt: None,
+        error: Some(err as u64),
+        combine_error: combine_code(err),
+        items: None,
+        ref_: None,
+    }
+}
+
+#[cfg(feature = "compositor")]
+fn compose_fallback(group: &LayerGroup, config: &Config) -> Result<Vec<u8>, Error> {
+    super::compositor::compose_png(&group.layers, config.thumbnail)
+}
+
+#[cfg(not(feature = "compositor"))]
+fn compose_fallback(_: &LayerGroup, _: &Config) -> Result<Vec<u8>, Error> {
+    Err(Error::ComposeImageFailed)
+}
+
+enum Composition {
+    // needs no combiner at all
+    Ready(Image),
+    Combine {
+        group: LayerGroup,
+        sources: Vec<LayerSource>,
+        preview: bool,
+        pattern: ItemVec,
+    },
+}
+
+fn compose_image(
+    dob_params: &Parameters,
+    group: LayerGroup,
+    run: &Run,
+    cache: &mut CombineCache,
+    references: &mut References,
+) -> Result<Image, Error> {
+    match prepare_image(dob_params, group, run)? {
+        Composition::Ready(image) => Ok(image),
+        Composition::Combine {
+            group,
+            sources,
+            preview,
+            pattern,
+        } => combined_image(
+            dob_params,
+            group,
+            sources,
+            preview,
+            pattern,
+            references,
+            |group, pattern| combine(dob_params, group, pattern, run, cache),
+        ),
+    }
+}
+
+// the image composed from `pattern`, or with `Config.dedupe` a reference to the earlier
+// image of the output composed from the same one
+fn combined_image<F>(
+    dob_params: &Parameters,
+    group: LayerGroup,
+    sources: Vec<LayerSource>,
+    preview: bool,
+    pattern: ItemVec,
+    references: &mut References,
+    combine: F,
+) -> Result<Image, Error>
+where
+    F: FnOnce(&LayerGroup, &ItemVec) -> Result<Vec<u8>, Error>,
+{
+    let dedupe = dob_params.config.dedupe;
+    if let Some((name, type_)) = references.get(&pattern).filter(|_| dedupe) {
+        return Ok(Image {
+            name: group.name,
+            type_: type_.clone(),
+            content: String::new(),
+            sources,
+            preview,
+            metadata: group.metadata,
+            alt: group.alt,
+            error: None,
+            combine_error: None,
+            items: audit_items(dob_params, &pattern),
+            ref_: Some(name.clone()),
+        });
+    }
+    let buffer = combine(&group, &pattern)?;
+    let image = finish_image(dob_params, group, sources, preview, &pattern, buffer)?;
+    if dedupe {
+        references.insert(&pattern, (image.name.clone(), image.type_.clone()));
+    }
+    Ok(image)
+}
+
+fn prepare_image(
+    dob_params: &Parameters,
+    mut group: LayerGroup,
+    run: &Run,
+) -> Result<Composition, Error> {
+    let preview = dob_params
+        .config
+        .preview
+        .as_ref()
+        .is_some_and(|preview| truncate_layers(&mut group.layers, preview));
+    let sources = layer_sources(&group.layers);
+    let composed = match group.format {
+        ImageFormat::Png => None,
+        ImageFormat::Svg => Some((SVG_TYPE, compose_svg(&group))),
+        ImageFormat::Urls => Some((URI_LIST_TYPE, uri_list(&group))),
+    };
+    let cached = || {
+        dob_params
+            .config
+            .cache
+            .as_ref()
+            .and_then(|cache| cached_fingerprint(cache, &group.layers))
+            .map(|fingerprint| (CACHE_REFERENCE_TYPE, fingerprint.clone()))
+    };
+    // images the decoder composes itself have the type of their format
+    let declared = dob_params.directives.mime.get(&group.name);
+    if let (Some((type_, _)), Some(mime)) = (&composed, declared) {
+        if mime != type_ {
+            return Err(Error::ComposeImageFailed);
+        }
+    }
+    if let Some((type_, content)) = composed.or_else(cached) {
+        return Ok(Composition::Ready(Image {
+            name: group.name,
+            type_: type_.to_owned(),
+            content,
+            sources,
+            preview,
+            metadata: group.metadata,
+            alt: group.alt,
+            error: None,
+            combine_error: None,
+            items: None,
+            ref_: None,
+        }));
+    }
+    let pattern = build_item_vec(&group, &dob_params.config, run)?;
+    Ok(Composition::Combine {
+        group,
+        sources,
+        preview,
+        pattern,
+    })
+}
+
+fn combine(
+    dob_params: &Parameters,
+    group: &LayerGroup,
+    pattern: &ItemVec,
+    run: &Run,
+    cache: &mut CombineCache,
+) -> Result<Vec<u8>, Error> {
+    if let Some(buffer) = cache.get(pattern) {
+        return Ok(buffer.clone());
+    }
+    let capacity = combine_buffer_size(&dob_params.config);
+    let limit = dob_params.config.max_image_bytes;
+    let buffer = match call_combiner(capacity, limit, run, |buffer, buffer_size| {
+        run.combine(buffer, buffer_size, pattern.as_slice())
+    }) {
+        Ok(Some(buffer)) => buffer,
+        // a host without the combiner already fails the first call
+        Ok(None) => compose_fallback(group, &dob_params.config)?,
+        // an item the host doesn't know may still be one the compositor draws, if it can't
+        // either the combiner's answer stands
+        Err(Error::ComposeUnsupportedItem) if cfg!(feature = "compositor") => {
+            compose_fallback(group, &dob_params.config)
+                .map_err(|_| Error::ComposeUnsupportedItem)?
+        }
+        Err(err) => return Err(err),
+    };
+    cache.insert(pattern, buffer.clone());
+    Ok(buffer)
+}
+
+fn combine_buffer_size(config: &Config) -> usize {
+    config
+        .combine_buffer_size
+        .unwrap_or(DEFAULT_COMBINE_BUFFER_SIZE)
+}
+
+// the combiner reports the full image size and only writes the image if it fits the given
+// buffer, so a buffer of `capacity` bytes takes a single call for any image up to that size
+// and larger ones are retried once with an exact buffer, None if the first call failed
+//
+// sizes past `limit` fail before their buffer is allocated, see `Config.max_image_bytes`
+fn call_combiner<F>(
+    capacity: usize,
+    limit: Option<u64>,
+    run: &Run,
+    mut call: F,
+) -> Result<Option<Vec<u8>>, Error>
+where
+    F: FnMut(&mut [u8], &mut u64) -> u64,
+{
+    let limit = limit.map_or(usize::MAX, |limit| {
+        usize::try_from(limit).unwrap_or(usize::MAX)
+    });
+    let mut buffer = vec![];
+    let mut buffer_size;
+    let mut size = capacity.min(limit);
+    for attempt in 0..2 {
+        buffer.clear();
+        buffer
+            .try_reserve_exact(size)
+            .map_err(|_| Error::ComposeOutOfMemory)?;
+        buffer.resize(size, 0);
+        run.spend()?;
+        run.count(Counter::CombineSyscalls, 1);
+        // the size goes in as the room the host may fill and comes back as the full length
+        buffer_size = size as u64;
+        match call(&mut buffer, &mut buffer_size) {
+            0 => {}
+            code => match combine_error(code) {
+                Some(err) => return Err(err),
+                None if attempt == 0 => return Ok(None),
+                // the combiner was there for the probe, so a failed refill is its own error
+                None => {
+                    log_warn!(run, "combine syscall failed with {code} after its probe");
+                    return Err(Error::CombineSyscallFailed);
+                }
+            },
+        }
+        let required = usize::try_from(buffer_size).map_err(|_| Error::ArithmeticOverflow)?;
+        if required <= size {
+            buffer.truncate(required);
+            return Ok(Some(buffer));
+        }
+        if required > limit {
+            return Err(Error::OutputTooLarge);
+        }
+        size = required;
+    }
+    // the combiner must not claim more bytes than it was given room for
+    Err(Error::ComposeImageFailed)
+}
+
+// the composed images of all patterns from a single probe and fill, None if the host
+// has no batched combiner so every image falls back to its own combine calls
+fn batch_combine(
+    patterns: Vec<ItemVec>,
+    capacity: usize,
+    limit: Option<u64>,
+    run: &Run,
+) -> Result<Option<Vec<Vec<u8>>>, Error> {
+    if !run.combines_batches() || patterns.is_empty() {
+        return Ok(None);
+    }
+    let count = patterns.len();
+    // every image may take up to the limit of one
+    let limit = limit.map(|limit| limit.saturating_mul(count as u64));
+    let patterns = ItemVecVec::new_builder().set(patterns).build();
+    let buffer = match call_combiner(capacity, limit, run, |buffer, buffer_size| {
+        run.combine_batch(buffer, buffer_size, patterns.as_slice())
+    }) {
+        Err(err) if combine_code(err).is_some() => {
+            // the images one by one tell which of them failed
+            log_warn!(
+                run,
+                "batched combine failed with error {}, composing images one by one",
+                err as u64
+            );
+            return Ok(None);
+        }
+        buffer => buffer?,
+    };
+    let Some(buffer) = buffer else {
+        log_warn!(
+            run,
+            "batched combine unsupported, composing images one by one"
+        );
+        return Ok(None);
+    };
+    let images = ImageVecReader::from_slice(&buffer).map_err(|_| Error::ComposeImageFailed)?;
+    if images.len() != count {
+        return Err(Error::ComposeImageFailed);
+    }
+    Ok(Some(
+        images
+            .iter()
+            .map(|image| image.raw_data().to_vec())
+            .collect(),
+    ))
+}
+
+fn finish_image(
+    dob_params: &Parameters,
+    group: LayerGroup,
+    sources: Vec<LayerSource>,
+    preview: bool,
+    pattern: &ItemVec,
+    buffer: Vec<u8>,
+) -> Result<Image, Error> {
+    // unless the combiner names another format, animated groups come back as an APNG
+    let animated = group.layers.iter().any(|layer| layer.animation.is_some());
+    let declared = dob_params.directives.mime.get(&group.name);
+    let default = match declared.or(dob_params.cluster.mime.as_ref()) {
+        Some(mime) => Some(mime.as_str()),
+        None => animated.then_some(APNG_MIME),
+    };
+    let (type_, content) = split_mime_header(&buffer, default)?;
+    // the combiner must compose the type the pattern declares
+    if declared.is_some_and(|mime| type_.strip_suffix(";base64") != Some(mime)) {
+        return Err(Error::ComposeImageFailed);
+    }
+    let (type_, content) = match (dob_params.commit, type_.strip_suffix(";base64")) {
+        (true, Some(mime)) => (
+            format!("{mime};{COMMITMENT_ENCODING}"),
+            commitment(content, pattern),
+        ),
+        _ => (type_, STANDARD.encode(content)),
+    };
+    Ok(Image {
+        name: group.name,
+        type_,
+        content,
+        sources,
+        preview,
+        metadata: group.metadata,
+        alt: group.alt,
+        error: None,
+        combine_error: None,
+        items: audit_items(dob_params, pattern),
+        ref_: None,
+    })
+}
+
+// a commitment is only checked against the layers it was composed from
+fn audit_items(dob_params: &Parameters, pattern: &ItemVec) -> Option<String> {
+    (dob_params.config.audit || dob_params.commit).then(|| hex::encode(pattern.as_slice()))
+}
+
+fn commitment(image: &[u8], pattern: &ItemVec) -> String {
+    let mut hash = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32)
+        .personal(b"ckb-default-hash")
+        .build();
+    blake2b.update(&(image.len() as u64).to_le_bytes());
+    blake2b.update(image);
+    blake2b.update(pattern.as_slice());
+    blake2b.finalize(&mut hash);
+    hex::encode(hash)
+}
+
+// a combiner composing anything but the cluster's `mime`, png by default, prefixes the
+// image with `\0<mime>\0`, no image format starts with a nul byte so the buffers of older
+// hosts are taken as they are
+fn split_mime_header<'a>(
+    buffer: &'a [u8],
+    default: Option<&str>,
+) -> Result<(String, &'a [u8]), Error> {
+    let [MIME_HEADER_MARK, header @ ..] = buffer else {
+        let type_ = match default {
+            Some(mime) => format!("{mime};base64"),
+            None => PNG_TYPE.to_owned(),
+        };
+        return Ok((type_, buffer));
+    };
+    let end = header
+        .iter()
+        .position(|&byte| byte == MIME_HEADER_MARK)
+        .ok_or(Error::ComposeImageFailed)?;
+    let mime = core::str::from_utf8(&header[..end]).map_err(|_| Error::OutputNotUtf8)?;
+    if !is_mime_type(mime) {
+        return Err(Error::ComposeImageFailed);
+    }
+    Ok((format!("{mime};base64"), &header[end + 1..]))
+}
+
+// `type/subtype` in printable ascii without parameters, those are for the decoder to add
+pub(crate) fn is_mime_type(mime: &str) -> bool {
+    mime.split_once('/').is_some_and(|(kind, subtype)| {
+        !kind.is_empty()
+            && !subtype.is_empty()
+            && mime
+                .bytes()
+                .all(|byte| byte.is_ascii_graphic() && byte != b';')
+    })
+}
@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+
+use super::types::ParsedTrait;
+
+// xoshiro256** seeded through splitmix64
+//
+// every step is defined on explicit little-endian u64 words, so a seed yields the same
+// sequence on every architecture, unlike `rand::rngs::SmallRng` whose algorithm depends
+// on the platform and the crate version
+#[derive(Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+fn splitmix64(value: &mut u64) -> u64 {
+    *value = value.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+fn fold(mut hash: u64, bytes: &[u8]) -> u64 {
+    for chunk in bytes.chunks(8) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        hash ^= u64::from_le_bytes(word);
+        hash = splitmix64(&mut hash);
+    }
+    hash ^= bytes.len() as u64;
+    splitmix64(&mut hash)
+}
+
+// the same 64 bit hash seeds take, for keying anything by its bytes, not collision resistant
+pub fn digest(bytes: &[u8]) -> u64 {
+    fold(0, bytes)
+}
+
+impl Rng {
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let mut hash = fold(0, seed);
+        let state = [
+            splitmix64(&mut hash),
+            splitmix64(&mut hash),
+            splitmix64(&mut hash),
+            splitmix64(&mut hash),
+        ];
+        Rng { state }
+    }
+
+    // hex strings, with or without `0x`, seed with their decoded bytes
+    pub fn from_trait(value: &ParsedTrait) -> Self {
+        match value {
+            ParsedTrait::String(value) => {
+                let hex = value.strip_prefix("0x").unwrap_or(value);
+                match hex::decode(hex) {
+                    Ok(bytes) => Self::from_seed(&bytes),
+                    Err(_) => Self::from_seed(value.as_bytes()),
+                }
+            }
+            // the 8 bytes of the signed or unsigned 64 bit number the trait was decoded from
+            ParsedTrait::Number(value)
+                if i64::try_from(*value).is_ok() || u64::try_from(*value).is_ok() =>
+            {
+                Self::from_seed(&(*value as u64).to_le_bytes())
+            }
+            // wider ones seed with all 16 bytes, so they don't collide with their low 64 bits
+            ParsedTrait::Number(value) => Self::from_seed(&value.to_le_bytes()),
+            ParsedTrait::Float(value) => Self::from_seed(&value.to_bits().to_le_bytes()),
+        }
+    }
+
+    // an independent stream per consumer, e.g. keyed by the image group name, so that
+    // one pattern drawing more numbers never shifts the sequence of another
+    pub fn fork(&self, label: &[u8]) -> Self {
+        let mut seed = self
+            .state
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        seed.extend_from_slice(label);
+        Self::from_seed(&seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+        result
+    }
+
+    // uniform in `0..bound` by multiply-and-shift, `bound` must not be zero
+    pub fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
@@ -0,0 +1,547 @@
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec, vec::Vec};
+use serde_json::Value;
+
+use super::matcher::{
+    float_range_bounds, glob_prefix, json_integer, range_bounds, split_divisor, split_expression,
+    split_trait_index, MATCH_OPERATORS,
+};
+use super::pipeline::Run;
+use super::types::{
+    Canvas, Compression, Directives, Error, ImageType, Pattern, SchemaOptions, TraitSchema,
+};
+use super::{
+    is_color_code, is_color_value, json, ASSET_REFERENCE, CANVAS_TYPE, IMAGE_MIME_TYPES, MIME_TYPE,
+    RESERVED_SELECTORS, SCHEMA_VERSION, SCHEMA_VERSION_HEADER, STATIC_TRAIT,
+};
+use crate::log::{self, Diagnostic, Log};
+
+// directive rows are `[name, value]` pairs, they never collide with schema rows which
+// carry at least four elements
+pub(crate) fn decode_pattern_directives(
+    mut traits_pool: Vec<Vec<Value>>,
+) -> Result<(Directives, Vec<Vec<Value>>), Error> {
+    let mut directives = Directives {
+        version: take_schema_version(&mut traits_pool)?,
+        ..Default::default()
+    };
+    let mut schemas = Vec::with_capacity(traits_pool.len());
+    for row in traits_pool {
+        if row.len() == 3 && row[1] == CANVAS_TYPE {
+            let name = row[0].as_str().ok_or(Error::SchemaInvalidName)?;
+            let canvas: Canvas =
+                serde_json::from_value(row[2].clone()).map_err(|_| Error::SchemaInvalidArgs)?;
+            let valid = canvas.width > 0
+                && canvas.height > 0
+                && canvas.background.as_deref().is_none_or(is_color_value);
+            if !valid || directives.canvas.contains_key(name) {
+                return Err(Error::SchemaInvalidArgs);
+            }
+            directives.canvas.insert(name.to_owned(), canvas);
+            continue;
+        }
+        if row.len() == 3 && row[1] == MIME_TYPE {
+            let name = row[0].as_str().ok_or(Error::SchemaInvalidName)?;
+            let mime = row[2]
+                .as_str()
+                .filter(|mime| IMAGE_MIME_TYPES.contains(mime))
+                .ok_or(Error::SchemaInvalidArgs)?;
+            if directives.mime.contains_key(name) {
+                return Err(Error::SchemaInvalidArgs);
+            }
+            directives.mime.insert(name.to_owned(), mime.to_owned());
+            continue;
+        }
+        if row.len() != 2 {
+            schemas.push(row);
+            continue;
+        }
+        match row[0].as_str() {
+            Some("i18n") => {
+                directives.i18n = serde_json::from_value(row[1].clone())
+                    .map_err(|_| Error::SchemaInvalidDirective)?;
+            }
+            Some("macros") => {
+                let macros: BTreeMap<String, Vec<Value>> =
+                    serde_json::from_value(row[1].clone())
+                        .map_err(|_| Error::SchemaInvalidDirective)?;
+                let valid = |template: &Vec<Value>| {
+                    (2..=4).contains(&template.len())
+                        && template[0].is_string()
+                        && template[1].is_string()
+                };
+                if !macros.values().all(valid) {
+                    return Err(Error::SchemaInvalidDirective);
+                }
+                directives.macros = macros;
+            }
+            Some("inherit") => {
+                let hash = row[1]
+                    .as_str()
+                    .and_then(|hash| hash.strip_prefix("0x"))
+                    .and_then(|hash| hex::decode(hash).ok())
+                    .and_then(|hash| <[u8; 32]>::try_from(hash).ok())
+                    .ok_or(Error::SchemaInvalidDirective)?;
+                directives.inherit = Some(hash);
+            }
+            Some("seed") => {
+                let seed = row[1].as_str().ok_or(Error::SchemaInvalidDirective)?;
+                directives.seed = Some(seed.to_owned());
+            }
+            Some("mode") => {
+                directives.mode = serde_json::from_value(row[1].clone())
+                    .map_err(|_| Error::SchemaInvalidDirective)?;
+            }
+            Some("traits") => {
+                directives.traits = serde_json::from_value(row[1].clone())
+                    .map_err(|_| Error::SchemaInvalidDirective)?;
+            }
+            Some("rewrite") => {
+                let rewrite: BTreeMap<String, String> = serde_json::from_value(row[1].clone())
+                    .map_err(|_| Error::SchemaInvalidDirective)?;
+                if rewrite.keys().any(String::is_empty) {
+                    return Err(Error::SchemaInvalidDirective);
+                }
+                directives.rewrite = rewrite;
+            }
+            Some("defs") => {
+                let defs: BTreeMap<String, Value> = serde_json::from_value(row[1].clone())
+                    .map_err(|_| Error::SchemaInvalidDirective)?;
+                let valid = |(name, asset): (&String, &Value)| {
+                    !name.is_empty() && !name.starts_with(ASSET_REFERENCE) && !asset.is_null()
+                };
+                if !defs.iter().all(valid) {
+                    return Err(Error::SchemaInvalidDirective);
+                }
+                directives.defs = Some(defs);
+            }
+            Some("commit") => {
+                directives.commit = row[1].as_bool().ok_or(Error::SchemaInvalidDirective)?;
+            }
+            _ => return Err(Error::SchemaInvalidDirective),
+        }
+    }
+    Ok((directives, schemas))
+}
+
+// a `[name, "$macro", dob0_trait]` row instantiates the `[type, pattern, args, options]`
+// template declared under that name by the macros directive
+pub(crate) fn expand_schema_macros(
+    directives: &Directives,
+    traits_pool: Vec<Vec<Value>>,
+) -> Result<Vec<Vec<Value>>, Error> {
+    traits_pool
+        .into_iter()
+        .map(|row| {
+            let Some(name) = row.get(1).and_then(Value::as_str) else {
+                return Ok(row);
+            };
+            let Some(name) = name.strip_prefix('$') else {
+                return Ok(row);
+            };
+            if row.len() != 3 {
+                return Err(Error::SchemaInsufficientElements);
+            }
+            let template = directives
+                .macros
+                .get(name)
+                .ok_or(Error::SchemaUndefinedMacro)?;
+            let mut schema = vec![row[0].clone(), template[0].clone(), row[2].clone()];
+            schema.extend(template[1..].iter().cloned());
+            Ok(schema)
+        })
+        .collect()
+}
+
+// replaces the `@name` values of the args by the assets the defs directive declares, only
+// in patterns having one so older patterns keep values starting with `@`, which `@@` writes
+// in patterns that do
+//
+// values are the second element of an args entry, a bucket value, the object form's values
+// and a static raw row's args, stacks and variants resolve element by element
+pub(crate) fn expand_asset_references(
+    directives: &Directives,
+    mut traits_pool: Vec<Vec<Value>>,
+) -> Result<Vec<Vec<Value>>, Error> {
+    let Some(defs) = &directives.defs else {
+        return Ok(traits_pool);
+    };
+    for row in &mut traits_pool {
+        let static_raw = row.get(2).and_then(Value::as_str) == Some(STATIC_TRAIT)
+            && row.get(3).and_then(Value::as_str) == Some("raw");
+        match row.get_mut(4) {
+            Some(args @ Value::String(_)) if static_raw => resolve_asset(args, defs)?,
+            Some(Value::Array(entries)) => {
+                for entry in entries {
+                    match entry {
+                        Value::Array(entry) => {
+                            if let Some(value) = entry.get_mut(1) {
+                                resolve_asset(value, defs)?;
+                            }
+                        }
+                        value => resolve_asset(value, defs)?,
+                    }
+                }
+            }
+            Some(Value::Object(entries)) => {
+                for value in entries.values_mut() {
+                    resolve_asset(value, defs)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(traits_pool)
+}
+
+fn resolve_asset(value: &mut Value, defs: &BTreeMap<String, Value>) -> Result<(), Error> {
+    match value {
+        Value::String(text) => {
+            let Some(name) = text.strip_prefix(ASSET_REFERENCE) else {
+                return Ok(());
+            };
+            *value = if name.starts_with(ASSET_REFERENCE) {
+                Value::String(name.to_owned())
+            } else {
+                defs.get(name).cloned().ok_or(Error::SchemaUndefinedAsset)?
+            };
+        }
+        Value::Array(values) => {
+            for value in values {
+                resolve_asset(value, defs)?;
+            }
+        }
+        Value::Object(variants) => {
+            for value in variants.values_mut() {
+                resolve_asset(value, defs)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// the inverse of `decode_trait_schema`, decoding the rows again gives back `traits_base`
+pub fn encode_trait_schema(traits_base: Vec<TraitSchema>) -> Vec<Vec<Value>> {
+    traits_base.iter().map(TraitSchema::encode).collect()
+}
+
+// takes the version header off the pattern, a later version may add columns and patterns
+// this decoder would misread or drop, so it refuses the whole pattern instead, a header
+// anywhere but the first row is an unknown directive
+pub(crate) fn take_schema_version(traits_pool: &mut Vec<Vec<Value>>) -> Result<Option<u64>, Error> {
+    let version = match traits_pool.first().map(Vec::as_slice) {
+        Some([Value::String(header), version]) if header == SCHEMA_VERSION_HEADER => version
+            .as_u64()
+            .filter(|version| *version > 0)
+            .ok_or(Error::SchemaInvalidDirective)?,
+        _ => return Ok(None),
+    };
+    if version > SCHEMA_VERSION {
+        return Err(Error::SchemaUnsupportedVersion);
+    }
+    traits_pool.remove(0);
+    Ok(Some(version))
+}
+
+pub fn decode_trait_schema(traits_pool: Vec<Vec<Value>>) -> Result<Vec<TraitSchema>, Error> {
+    decode_schema_rows(traits_pool, &Run::detached())
+}
+
+// `decode_trait_schema` diagnosing the row that fails to `logger`
+pub(crate) fn decode_schema_rows(
+    mut traits_pool: Vec<Vec<Value>>,
+    logger: &dyn Log,
+) -> Result<Vec<TraitSchema>, Error> {
+    take_schema_version(&mut traits_pool)?;
+    let traits_base = traits_pool
+        .iter()
+        .enumerate()
+        .map(|(row, schema)| {
+            decode_schema_row(schema).inspect_err(|err| {
+                log::diagnose(
+                    logger,
+                    &Diagnostic {
+                        row: Some(row),
+                        trait_name: schema.get(2).and_then(Value::as_str),
+                        value: Some(Value::Array(schema.clone())),
+                        ..Diagnostic::new(*err)
+                    },
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(traits_base)
+}
+
+pub(crate) fn decode_schema_row(schema: &[Value]) -> Result<TraitSchema, Error> {
+    if schema.len() < 4 {
+        return Err(Error::SchemaInsufficientElements);
+    }
+    let name = schema[0].as_str().ok_or(Error::SchemaInvalidName)?;
+    // a compressed raw image is written `image+gzip`, other encodings or a build without
+    // the `gzip` feature can't inflate it
+    let (type_str, compression) = match schema[1].as_str() {
+        Some(type_str) => match type_str.split_once('+') {
+            Some(("image", "gzip")) if cfg!(feature = "gzip") => ("image", Some(Compression::Gzip)),
+            Some(("image", _)) => return Err(Error::SchemaUnsupportedEncoding),
+            Some(_) => return Err(Error::SchemaTypeMismatch),
+            None => (type_str, None),
+        },
+        None => return Err(Error::SchemaInvalidType),
+    };
+    let type_ = match type_str {
+        "color" => ImageType::ColorCode,
+        "uri" => ImageType::URI,
+        "image" => ImageType::RawImage,
+        "svg" => ImageType::SVG,
+        "text" => ImageType::Text,
+        _ => return Err(Error::SchemaTypeMismatch),
+    };
+    // a list of traits makes a multi-trait row, see `args_key_matches`
+    let mut dob0_traits = match &schema[2] {
+        Value::String(dob0_trait) => vec![dob0_trait.as_str()],
+        Value::Array(dob0_traits) if !dob0_traits.is_empty() => dob0_traits
+            .iter()
+            .map(Value::as_str)
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::SchemaInvalidTraitName)?,
+        _ => return Err(Error::SchemaInvalidTraitName),
+    };
+    let reserved =
+        |dob0_trait: &&str| !RESERVED_SELECTORS.contains(dob0_trait) && dob0_trait.starts_with('#');
+    // a multi-trait row reads one value of each trait
+    let many = |dob0_trait: &&str| *dob0_trait == STATIC_TRAIT || glob_prefix(dob0_trait).is_some();
+    if dob0_traits.iter().any(reserved) || (dob0_traits.len() > 1 && dob0_traits.iter().any(many)) {
+        return Err(Error::SchemaInvalidTraitName);
+    }
+    let dob0_trait = dob0_traits.join(",");
+    if dob0_traits.len() == 1 {
+        dob0_traits.clear();
+    }
+    let pattern_str = schema[3].as_str().ok_or(Error::SchemaInvalidPattern)?;
+    let pattern = match (pattern_str, &type_) {
+        ("options", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Options
+        }
+        ("range", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Range
+        }
+        ("raw", ImageType::RawImage | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Raw
+        }
+        ("raw", ImageType::ColorCode) if dob0_trait == STATIC_TRAIT => Pattern::Raw,
+        ("template", ImageType::URI | ImageType::Text) => Pattern::Template,
+        ("weighted", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::WeightedRandom
+        }
+        ("mod", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Mod
+        }
+        ("bucket", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Bucket
+        }
+        ("expr", ImageType::ColorCode | ImageType::URI | ImageType::SVG | ImageType::Text) => {
+            Pattern::Expr
+        }
+        _ => return Err(Error::SchemaPatternMismatch),
+    };
+    if !dob0_traits.is_empty() && !matches!(pattern, Pattern::Options | Pattern::Range) {
+        return Err(Error::SchemaPatternMismatch);
+    }
+    let args = schema
+        .get(4)
+        .filter(|args| !args.is_null())
+        .map(normalize_args)
+        .transpose()?;
+    if dob0_trait == STATIC_TRAIT && !matches!(pattern, Pattern::Raw | Pattern::Template) {
+        return Err(Error::SchemaPatternMismatch);
+    }
+    if (dob0_trait == STATIC_TRAIT || pattern == Pattern::Template)
+        && !args.as_ref().is_some_and(Value::is_string)
+    {
+        return Err(Error::SchemaInvalidArgs);
+    }
+    if matches!(pattern, Pattern::Mod | Pattern::Bucket) {
+        split_divisor(args.as_ref().ok_or(Error::SchemaInvalidArgs)?)?;
+    }
+    if pattern == Pattern::Expr {
+        split_expression(args.as_ref().ok_or(Error::SchemaInvalidArgs)?)?;
+    }
+    let options = match schema.get(5) {
+        Some(options) => decode_schema_options(options)?,
+        None => SchemaOptions::default(),
+    };
+    if options.text.is_some() && type_ != ImageType::Text {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if options.frames.is_some() && type_ != ImageType::URI {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if options.fill().is_some() && !matches!(type_, ImageType::URI | ImageType::RawImage) {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if options.loose && pattern != Pattern::Options {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if options.number.is_some() && (pattern != Pattern::Raw || dob0_trait == STATIC_TRAIT) {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    // the reserved selectors and the static trait have a single value, globs take all
+    if !options.occurrence.is_default()
+        && (!dob0_traits.is_empty()
+            || dob0_trait == STATIC_TRAIT
+            || dob0_trait.starts_with('#')
+            || glob_prefix(split_trait_index(&dob0_trait).0).is_some())
+    {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    Ok(TraitSchema {
+        name: name.to_owned(),
+        type_,
+        dob0_traits: dob0_traits.into_iter().map(ToOwned::to_owned).collect(),
+        dob0_trait,
+        pattern,
+        compression,
+        args,
+        options,
+    })
+}
+
+// args may also be written as an object, `{"Ethan": "#FF0000", "[0,50]": "btcfs://..."}`,
+// which becomes the pairs form: `*` and keys in brackets are parsed as json, keys json
+// reads as numbers match numeric traits and anything else is a string key
+//
+// json objects carry no order, so exact keys are tried first, then ranges, operator keys
+// like exclusions and the `*` catch-all last, ranges keep the order of their string keys,
+// `[10,20]` before `[5,15]`, so ranges of one object may not overlap
+fn normalize_args(args: &Value) -> Result<Value, Error> {
+    let Value::Object(args) = args else {
+        return Ok(args.clone());
+    };
+    let rank = |key: &Value| match key.as_array().map(Vec::as_slice) {
+        None => 0,
+        Some([Value::String(name)]) if name == "*" => 3,
+        Some([Value::String(name), _]) if MATCH_OPERATORS.contains(&name.as_str()) => 2,
+        Some(_) => 1,
+    };
+    let mut pairs = args
+        .iter()
+        .map(|(key, value)| {
+            let key = if key == "*" {
+                Value::Array(vec![Value::String(key.clone())])
+            } else if key.starts_with('[') {
+                json::from_slice(key.as_bytes()).map_err(|_| Error::SchemaInvalidArgsElement)?
+            } else if let Some(number) = numeric_key(key) {
+                // exact even when written as a range
+                return Ok((0, number, value.clone()));
+            } else {
+                Value::String(key.clone())
+            };
+            Ok((rank(&key), key, value.clone()))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let ranges = pairs
+        .iter()
+        .filter(|(rank, ..)| *rank == 1)
+        .map(|(_, key, _)| key)
+        .collect::<Vec<_>>();
+    for (index, range) in ranges.iter().enumerate() {
+        if ranges[..index]
+            .iter()
+            .any(|earlier| ranges_overlap(earlier, range))
+        {
+            return Err(Error::SchemaInvalidRange);
+        }
+    }
+    pairs.sort_by_key(|(rank, ..)| *rank);
+    Ok(Value::Array(
+        pairs
+            .into_iter()
+            .map(|(_, key, value)| Value::Array(vec![key, value]))
+            .collect(),
+    ))
+}
+
+fn ranges_overlap(range: &Value, other: &Value) -> bool {
+    let (Some(range), Some(other)) = (range.as_array(), other.as_array()) else {
+        return false;
+    };
+    if let (Ok((start, end)), Ok((other_start, other_end))) =
+        (range_bounds(range), range_bounds(other))
+    {
+        return start <= other_end && other_start <= end;
+    }
+    // float traits are rounded to match integer ranges, see `integer_value`
+    let bounds = |range: &[Value]| {
+        range_bounds(range)
+            .ok()
+            .map(|(start, end)| (start as f64 - 0.5, end as f64 + 0.5))
+            .or_else(|| float_range_bounds(range).ok())
+    };
+    match (bounds(range), bounds(other)) {
+        (Some((start, end)), Some((other_start, other_end))) => {
+            start <= other_end && other_start <= end
+        }
+        _ => false,
+    }
+}
+
+// `-5` or `1.5` as json reads them, integers past 64 bits would only survive as floats and
+// become a range of just themselves instead, bounded by decimal strings as in `range_bounds`
+fn numeric_key(key: &str) -> Option<Value> {
+    let number = json::from_slice::<Value>(key.as_bytes())
+        .ok()
+        .filter(Value::is_number)?;
+    match key.parse::<i128>() {
+        Ok(_) if json_integer(&number).is_none() => {
+            Some(Value::Array(vec![Value::String(key.to_owned()); 2]))
+        }
+        _ => Some(number),
+    }
+}
+
+fn decode_schema_options(options: &Value) -> Result<SchemaOptions, Error> {
+    let options: SchemaOptions =
+        serde_json::from_value(options.clone()).map_err(|_| Error::SchemaInvalidOptions)?;
+    let metadata = &options.metadata;
+    if let Some([width, height]) = metadata.aspect_ratio {
+        if width == 0 || height == 0 {
+            return Err(Error::SchemaInvalidOptions);
+        }
+    }
+    if let Some(transform) = &options.transform {
+        let scale = transform.scale * 1000.0;
+        if !(1.0..=u32::MAX as f64).contains(&scale) || !(0.0..=1.0).contains(&transform.opacity) {
+            return Err(Error::SchemaInvalidOptions);
+        }
+    }
+    if options.frames == Some(0) {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if options.exclusive.as_deref() == Some("") {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    // a layer either fills the canvas in one way or is placed on it
+    let fills = options.tile as usize + options.slice.is_some() as usize;
+    if fills + options.transform.is_some() as usize > 1 {
+        return Err(Error::SchemaInvalidOptions);
+    }
+    if let Some(style) = &options.text {
+        let fraction = |value: f64| (0.0..=1.0).contains(&value);
+        if !fraction(style.x) || !fraction(style.y) || !fraction(style.size) || style.size == 0.0 {
+            return Err(Error::SchemaInvalidOptions);
+        }
+        if !is_color_code(&style.color) {
+            return Err(Error::SchemaInvalidOptions);
+        }
+    }
+    if let Some(area) = &metadata.safe_area {
+        let within = |offset: f64, length: f64| {
+            (0.0..=1.0).contains(&offset) && (0.0..=1.0).contains(&length) && offset + length <= 1.0
+        };
+        if !within(area.x, area.width) || !within(area.y, area.height) {
+            return Err(Error::SchemaInvalidOptions);
+        }
+    }
+    Ok(options)
+}
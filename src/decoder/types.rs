@@ -1,8 +1,11 @@
-use alloc::{string::String, vec::Vec};
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec::Vec};
 use serde_json::Value;
 
+use crate::stats::Stats;
+
 #[repr(u64)]
 #[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy)]
 pub enum Error {
     ParseInvalidArgCount = 1,
     ParseInvalidDOB0Output,
@@ -23,12 +26,356 @@ pub enum Error {
     DecodeInvalidRawValue,
     DecodeBadUTF8Format,
     DecodeBadColorCodeFormat,
+
+    ParseInvalidConfig,
+    SchemaInvalidOptions,
+    SchemaInvalidDirective,
+    ComposeImageFailed,
+    ComposeOutOfMemory,
+    SchemaInvalidRange,
+    ArithmeticOverflow,
+    SyscallBudgetExceeded,
+    SchemaUndefinedMacro,
+    ParseParentPatternNotFound,
+    ParseInvalidParentPattern,
+    ComposeUnsupportedLayer,
+    ComposeInvalidImage,
+    DecodeMissingTrait,
+    ParseInvalidHexArg,
+    ValidatePatternFailed,
+    DecodeUnsupportedURIScheme,
+    DecodeCellDataNotFound,
+    DecodeFloatKeyMismatch,
+    DecodeCorruptCompressedImage,
+    ComposeIntegrityMismatch,
+    ParseInvalidChainedOutput,
+    DecodeChainedImageNotFound,
+    ParseInvalidClusterConfig,
+    OutputNotUtf8,
+    SerializeFailed,
+    CombineSyscallFailed,
+    HeapExhausted,
+    SchemaUnexpectedColumn,
+    SchemaDuplicateRow,
+    SchemaShadowedEntry,
+    ParseInvalidFlag,
+    OutputTooLarge,
+    ParseTraitPrecisionLoss,
+    SchemaUnsupportedVersion,
+    DecodeRawImageTooLarge,
+    DecodeUnknownRawImageFormat,
+    ComposeFetchFailed,
+    ComposeUndecodableAsset,
+    ComposeUnsupportedItem,
+    SchemaUndefinedAsset,
+    SchemaUnsupportedEncoding,
+}
+
+impl Error {
+    // codes are contiguous from 1, so a code indexes this table
+    const ALL: [Error; 59] = [
+        Error::ParseInvalidArgCount,
+        Error::ParseInvalidDOB0Output,
+        Error::ParseInvalidTraitsBase,
+        Error::SchemaInsufficientElements,
+        Error::SchemaInvalidName,
+        Error::SchemaInvalidTraitName,
+        Error::SchemaInvalidType,
+        Error::SchemaTypeMismatch,
+        Error::SchemaInvalidPattern,
+        Error::SchemaPatternMismatch,
+        Error::SchemaInvalidArgs,
+        Error::SchemaInvalidArgsElement,
+        Error::SchemaInvalidParsedTraitType,
+        Error::DecodeInvalidOptionArgs,
+        Error::DecodeInvalidRawValue,
+        Error::DecodeBadUTF8Format,
+        Error::DecodeBadColorCodeFormat,
+        Error::ParseInvalidConfig,
+        Error::SchemaInvalidOptions,
+        Error::SchemaInvalidDirective,
+        Error::ComposeImageFailed,
+        Error::ComposeOutOfMemory,
+        Error::SchemaInvalidRange,
+        Error::ArithmeticOverflow,
+        Error::SyscallBudgetExceeded,
+        Error::SchemaUndefinedMacro,
+        Error::ParseParentPatternNotFound,
+        Error::ParseInvalidParentPattern,
+        Error::ComposeUnsupportedLayer,
+        Error::ComposeInvalidImage,
+        Error::DecodeMissingTrait,
+        Error::ParseInvalidHexArg,
+        Error::ValidatePatternFailed,
+        Error::DecodeUnsupportedURIScheme,
+        Error::DecodeCellDataNotFound,
+        Error::DecodeFloatKeyMismatch,
+        Error::DecodeCorruptCompressedImage,
+        Error::ComposeIntegrityMismatch,
+        Error::ParseInvalidChainedOutput,
+        Error::DecodeChainedImageNotFound,
+        Error::ParseInvalidClusterConfig,
+        Error::OutputNotUtf8,
+        Error::SerializeFailed,
+        Error::CombineSyscallFailed,
+        Error::HeapExhausted,
+        Error::SchemaUnexpectedColumn,
+        Error::SchemaDuplicateRow,
+        Error::SchemaShadowedEntry,
+        Error::ParseInvalidFlag,
+        Error::OutputTooLarge,
+        Error::ParseTraitPrecisionLoss,
+        Error::SchemaUnsupportedVersion,
+        Error::DecodeRawImageTooLarge,
+        Error::DecodeUnknownRawImageFormat,
+        Error::ComposeFetchFailed,
+        Error::ComposeUndecodableAsset,
+        Error::ComposeUnsupportedItem,
+        Error::SchemaUndefinedAsset,
+        Error::SchemaUnsupportedEncoding,
+    ];
+
+    // the exit code of a run failing with this error
+    pub fn code(&self) -> u64 {
+        *self as u64
+    }
+
+    pub fn from_code(code: u64) -> Option<Self> {
+        let index = usize::try_from(code.checked_sub(1)?).ok()?;
+        Self::ALL.get(index).copied()
+    }
+
+    // the variant name, as written to diagnostics
+    pub fn name(&self) -> &'static str {
+        match self {
+            Error::ParseInvalidArgCount => "ParseInvalidArgCount",
+            Error::ParseInvalidDOB0Output => "ParseInvalidDOB0Output",
+            Error::ParseInvalidTraitsBase => "ParseInvalidTraitsBase",
+            Error::SchemaInsufficientElements => "SchemaInsufficientElements",
+            Error::SchemaInvalidName => "SchemaInvalidName",
+            Error::SchemaInvalidTraitName => "SchemaInvalidTraitName",
+            Error::SchemaInvalidType => "SchemaInvalidType",
+            Error::SchemaTypeMismatch => "SchemaTypeMismatch",
+            Error::SchemaInvalidPattern => "SchemaInvalidPattern",
+            Error::SchemaPatternMismatch => "SchemaPatternMismatch",
+            Error::SchemaInvalidArgs => "SchemaInvalidArgs",
+            Error::SchemaInvalidArgsElement => "SchemaInvalidArgsElement",
+            Error::SchemaInvalidParsedTraitType => "SchemaInvalidParsedTraitType",
+            Error::DecodeInvalidOptionArgs => "DecodeInvalidOptionArgs",
+            Error::DecodeInvalidRawValue => "DecodeInvalidRawValue",
+            Error::DecodeBadUTF8Format => "DecodeBadUTF8Format",
+            Error::DecodeBadColorCodeFormat => "DecodeBadColorCodeFormat",
+            Error::ParseInvalidConfig => "ParseInvalidConfig",
+            Error::SchemaInvalidOptions => "SchemaInvalidOptions",
+            Error::SchemaInvalidDirective => "SchemaInvalidDirective",
+            Error::ComposeImageFailed => "ComposeImageFailed",
+            Error::ComposeOutOfMemory => "ComposeOutOfMemory",
+            Error::SchemaInvalidRange => "SchemaInvalidRange",
+            Error::ArithmeticOverflow => "ArithmeticOverflow",
+            Error::SyscallBudgetExceeded => "SyscallBudgetExceeded",
+            Error::SchemaUndefinedMacro => "SchemaUndefinedMacro",
+            Error::ParseParentPatternNotFound => "ParseParentPatternNotFound",
+            Error::ParseInvalidParentPattern => "ParseInvalidParentPattern",
+            Error::ComposeUnsupportedLayer => "ComposeUnsupportedLayer",
+            Error::ComposeInvalidImage => "ComposeInvalidImage",
+            Error::DecodeMissingTrait => "DecodeMissingTrait",
+            Error::ParseInvalidHexArg => "ParseInvalidHexArg",
+            Error::ValidatePatternFailed => "ValidatePatternFailed",
+            Error::DecodeUnsupportedURIScheme => "DecodeUnsupportedURIScheme",
+            Error::DecodeCellDataNotFound => "DecodeCellDataNotFound",
+            Error::DecodeFloatKeyMismatch => "DecodeFloatKeyMismatch",
+            Error::DecodeCorruptCompressedImage => "DecodeCorruptCompressedImage",
+            Error::ComposeIntegrityMismatch => "ComposeIntegrityMismatch",
+            Error::ParseInvalidChainedOutput => "ParseInvalidChainedOutput",
+            Error::DecodeChainedImageNotFound => "DecodeChainedImageNotFound",
+            Error::ParseInvalidClusterConfig => "ParseInvalidClusterConfig",
+            Error::OutputNotUtf8 => "OutputNotUtf8",
+            Error::SerializeFailed => "SerializeFailed",
+            Error::CombineSyscallFailed => "CombineSyscallFailed",
+            Error::HeapExhausted => "HeapExhausted",
+            Error::SchemaUnexpectedColumn => "SchemaUnexpectedColumn",
+            Error::SchemaDuplicateRow => "SchemaDuplicateRow",
+            Error::SchemaShadowedEntry => "SchemaShadowedEntry",
+            Error::ParseInvalidFlag => "ParseInvalidFlag",
+            Error::OutputTooLarge => "OutputTooLarge",
+            Error::ParseTraitPrecisionLoss => "ParseTraitPrecisionLoss",
+            Error::SchemaUnsupportedVersion => "SchemaUnsupportedVersion",
+            Error::DecodeRawImageTooLarge => "DecodeRawImageTooLarge",
+            Error::DecodeUnknownRawImageFormat => "DecodeUnknownRawImageFormat",
+            Error::ComposeFetchFailed => "ComposeFetchFailed",
+            Error::ComposeUndecodableAsset => "ComposeUndecodableAsset",
+            Error::ComposeUnsupportedItem => "ComposeUnsupportedItem",
+            Error::SchemaUndefinedAsset => "SchemaUndefinedAsset",
+            Error::SchemaUnsupportedEncoding => "SchemaUnsupportedEncoding",
+        }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Error::ParseInvalidArgCount => "wrong number of arguments",
+            Error::ParseInvalidDOB0Output => "the DOB/0 output is malformed",
+            Error::ParseInvalidTraitsBase => "the pattern is malformed",
+            Error::SchemaInsufficientElements => "a pattern row has too few elements",
+            Error::SchemaInvalidName => "a pattern row has no valid image name",
+            Error::SchemaInvalidTraitName => "a pattern row has no valid trait name",
+            Error::SchemaInvalidType => "a pattern row has no valid image type",
+            Error::SchemaTypeMismatch => "unknown image type",
+            Error::SchemaInvalidPattern => "a pattern row has no valid pattern name",
+            Error::SchemaPatternMismatch => {
+                "the pattern does not apply to the row's image type or trait"
+            }
+            Error::SchemaInvalidArgs => "a pattern row has invalid args",
+            Error::SchemaInvalidArgsElement => "an args entry is invalid",
+            Error::SchemaInvalidParsedTraitType => "a DOB/0 trait has the wrong value type",
+            Error::DecodeInvalidOptionArgs => "a pattern row needs args to decode",
+            Error::DecodeInvalidRawValue => "a raw value is not text",
+            Error::DecodeBadUTF8Format => "a value is not valid utf-8",
+            Error::DecodeBadColorCodeFormat => "a color value is not a color code",
+            Error::ParseInvalidConfig => "the config is malformed or inconsistent",
+            Error::SchemaInvalidOptions => "a pattern row has invalid options",
+            Error::SchemaInvalidDirective => "a pattern directive is invalid",
+            Error::ComposeImageFailed => "the image combiner failed",
+            Error::ComposeOutOfMemory => "out of memory for the composed image",
+            Error::SchemaInvalidRange => "a range key is invalid",
+            Error::ArithmeticOverflow => "a number is out of range",
+            Error::SyscallBudgetExceeded => "the syscall budget is spent",
+            Error::SchemaUndefinedMacro => "a pattern row uses an undefined macro",
+            Error::ParseParentPatternNotFound => "the inherited pattern is not in the cell deps",
+            Error::ParseInvalidParentPattern => "the inherited pattern is malformed",
+            Error::ComposeUnsupportedLayer => "a layer cannot be composed by the decoder",
+            Error::ComposeInvalidImage => "a layer image cannot be read",
+            Error::DecodeMissingTrait => "a trait the pattern needs is missing",
+            Error::ParseInvalidHexArg => "an argument is not valid hex",
+            Error::ValidatePatternFailed => "the pattern failed validation",
+            Error::DecodeUnsupportedURIScheme => "a uri has an unsupported scheme",
+            Error::DecodeCellDataNotFound => "a referenced cell is not in the cell deps",
+            Error::DecodeFloatKeyMismatch => "a float key only matches float traits",
+            Error::DecodeCorruptCompressedImage => "a compressed image is corrupt",
+            Error::ComposeIntegrityMismatch => "an asset failed its integrity check",
+            Error::ParseInvalidChainedOutput => "the chained DOB/1 output is malformed",
+            Error::DecodeChainedImageNotFound => "a chained image is missing or not embeddable",
+            Error::ParseInvalidClusterConfig => "the cluster config is malformed",
+            Error::OutputNotUtf8 => "the combiner answered with text that is not utf-8",
+            Error::SerializeFailed => "the output could not be serialized",
+            Error::CombineSyscallFailed => "the combine syscall failed after its probe",
+            Error::HeapExhausted => "the decoder ran out of heap",
+            Error::SchemaUnexpectedColumn => "a pattern row has columns past its options",
+            Error::SchemaDuplicateRow => "a group has two rows of the same trait",
+            Error::SchemaShadowedEntry => "an args entry follows a catch-all one",
+            Error::ParseInvalidFlag => "an argument starting with `--` is no known flag",
+            Error::OutputTooLarge => "an image or the whole output exceeds its configured size",
+            Error::ParseTraitPrecisionLoss => {
+                "a trait number exceeds 128 bits or lost precision in json"
+            }
+            Error::SchemaUnsupportedVersion => "the pattern is of a newer version than the decoder",
+            Error::DecodeRawImageTooLarge => "a raw image exceeds the size a layer may have",
+            Error::DecodeUnknownRawImageFormat => "a raw image is no png, jpeg or webp",
+            Error::ComposeFetchFailed => "the combiner could not fetch an asset",
+            Error::ComposeUndecodableAsset => "the combiner could not decode an asset",
+            Error::ComposeUnsupportedItem => "the combiner does not know an item of the pattern",
+            Error::SchemaUndefinedAsset => "an args entry references an undefined asset",
+            Error::SchemaUnsupportedEncoding => {
+                "a raw image row names an encoding this build lacks"
+            }
+        })
+    }
 }
 
+// serde_json errors only reach `?` on the output side, inputs map theirs to the argument
+// they were read from
+impl From<serde_json::Error> for Error {
+    fn from(_: serde_json::Error) -> Self {
+        Error::SerializeFailed
+    }
+}
+
+// molecule is only verified for DOB/0 outputs unless the caller maps the error itself
+impl From<molecule::error::VerificationError> for Error {
+    fn from(_: molecule::error::VerificationError) -> Self {
+        Error::ParseInvalidDOB0Output
+    }
+}
+
+#[cfg_attr(test, derive(Debug))]
 #[derive(serde::Deserialize, serde::Serialize, Clone)]
+#[serde(try_from = "Value")]
 pub enum ParsedTrait {
     String(String),
-    Number(u64),
+    // wide enough for both unsigned and signed 64 bit DOB/0 numbers
+    Number(i128),
+    Float(f64),
+}
+
+// besides `{"String": ..}` and its siblings, the forms other DOB/0 renders use: bare
+// values and `{"value": ..}`, other fields of the object are ignored
+//
+// json numbers past 64 bits only survive parsing as floats, numbers up to 128 bits are
+// given as decimal strings instead, `{"Number": "340282366920938463463374607431768211455"}`
+impl TryFrom<Value> for ParsedTrait {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Object(mut object) = value else {
+            return match value {
+                Value::String(value) => Ok(ParsedTrait::String(value)),
+                Value::Number(ref number) if number.is_f64() => match number.as_f64() {
+                    // an integer too wide for 64 bits, not a float anyone wrote
+                    Some(value) if value.is_finite() && !fits_64_bits(value) => {
+                        Err(Error::ParseTraitPrecisionLoss)
+                    }
+                    value => value
+                        .map(ParsedTrait::Float)
+                        .ok_or(Error::ParseInvalidDOB0Output),
+                },
+                value => trait_number(&value).map(ParsedTrait::Number),
+            };
+        };
+        if let Some(value) = object.remove("String") {
+            return match value {
+                Value::String(value) => Ok(ParsedTrait::String(value)),
+                _ => Err(Error::ParseInvalidDOB0Output),
+            };
+        }
+        if let Some(value) = object.remove("Number") {
+            return trait_number(&value).map(ParsedTrait::Number);
+        }
+        if let Some(value) = object.remove("Float") {
+            return value
+                .as_f64()
+                .map(ParsedTrait::Float)
+                .ok_or(Error::ParseInvalidDOB0Output);
+        }
+        match object.remove("value") {
+            Some(value @ (Value::String(_) | Value::Number(_))) => Self::try_from(value),
+            _ => Err(Error::ParseInvalidDOB0Output),
+        }
+    }
+}
+
+// floats out of this range are all integers
+fn fits_64_bits(value: f64) -> bool {
+    (i64::MIN as f64..u64::MAX as f64).contains(&value)
+}
+
+fn trait_number(value: &Value) -> Result<i128, Error> {
+    match value {
+        Value::String(digits) => {
+            let unsigned = digits.strip_prefix('-').unwrap_or(digits);
+            if unsigned.is_empty() || !unsigned.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(Error::ParseInvalidDOB0Output);
+            }
+            digits.parse().map_err(|_| Error::ParseTraitPrecisionLoss)
+        }
+        Value::Number(number) => match (number.as_i64(), number.as_u64(), number.as_f64()) {
+            (Some(value), _, _) => Ok(value.into()),
+            (_, Some(value), _) => Ok(value.into()),
+            (_, _, Some(value)) if !fits_64_bits(value) => Err(Error::ParseTraitPrecisionLoss),
+            _ => Err(Error::ParseInvalidDOB0Output),
+        },
+        _ => Err(Error::ParseInvalidDOB0Output),
+    }
 }
 
 impl ParsedTrait {
@@ -40,7 +387,7 @@ impl ParsedTrait {
         }
     }
 
-    pub fn get_number(&self) -> Result<u64, Error> {
+    pub fn get_number(&self) -> Result<i128, Error> {
         if let ParsedTrait::Number(value) = self {
             Ok(*value)
         } else {
@@ -49,54 +396,1141 @@ impl ParsedTrait {
     }
 }
 
-#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone)]
 pub struct DOB0Output {
     pub name: String,
     pub traits: Vec<ParsedTrait>,
 }
 
+// the same as deserializing it, but a trait that fails keeps its own error
+impl TryFrom<Value> for DOB0Output {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Object(mut object) = value else {
+            return Err(Error::ParseInvalidDOB0Output);
+        };
+        let (Some(Value::String(name)), Some(Value::Array(traits))) =
+            (object.remove("name"), object.remove("traits"))
+        else {
+            return Err(Error::ParseInvalidDOB0Output);
+        };
+        let traits = traits
+            .into_iter()
+            .map(ParsedTrait::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(DOB0Output { name, traits })
+    }
+}
+
 #[cfg_attr(test, derive(serde::Deserialize))]
 pub struct Parameters {
     pub dob0_output: Vec<DOB0Output>,
     pub images_base: Vec<TraitSchema>,
+    pub directives: Directives,
+    pub config: Config,
+    // the tokens of a batch run, `dob0_output` is left empty then
+    #[cfg_attr(test, serde(default))]
+    pub tokens: Option<Vec<(String, Vec<DOB0Output>)>>,
+    // images of the DOB1Output passed as the optional fourth argument
+    #[cfg_attr(test, serde(default))]
+    pub chained: Vec<ChainedImage>,
+    #[cfg_attr(test, serde(default))]
+    pub cluster: Cluster,
+    // set by a `--report` or `--report-only` argument
+    #[cfg_attr(test, serde(default))]
+    pub report: Option<ReportMode>,
+    // set by a `--locale=<tag>` argument, the output traits are translated into it by the
+    // pattern's i18n directive, unlike `Config.locale` which adds `DOB1Output.display`
+    #[cfg_attr(test, serde(default))]
+    pub locale: Option<String>,
+    // set by a `--commit` argument or the pattern's commit directive, each image the
+    // combiner composes is written as its `COMMITMENT_ENCODING` instead of base64
+    #[cfg_attr(test, serde(default))]
+    pub commit: bool,
+}
+
+#[cfg_attr(test, derive(serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReportMode {
+    // the output carries a `RenderReport` next to everything else
+    Append,
+    // the `RenderReport` is all that is written
+    Only,
+}
+
+// cluster wide settings of the optional fifth argument, merged into every image group
+#[derive(serde::Deserialize, Default)]
+pub struct Cluster {
+    // size every image is composed at unless the config asks for a `thumbnail`
+    #[serde(default)]
+    pub canvas: Option<Size>,
+    // color code of a layer beneath all others of every group
+    #[serde(default)]
+    pub background: Option<String>,
+    // type of the images a combiner returns without a mime header, png if unset
+    #[serde(default)]
+    pub mime: Option<String>,
+    // scheme => prefix replacing `<scheme>://` in URI layers, e.g. "ar" => "https://arweave.net/"
+    #[serde(default)]
+    pub uri_aliases: BTreeMap<String, String>,
+}
+
+// an image of a prior decoder's output a `dob1:<name>` raw image value stands for
+#[derive(serde::Deserialize)]
+pub struct ChainedImage {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub content: String,
+    // see `Image.ref_`
+    #[serde(default, rename = "ref")]
+    pub ref_: Option<String>,
+}
+
+// the part of a prior DOB1Output that chaining reads
+#[derive(serde::Deserialize)]
+pub struct ChainedOutput {
+    #[serde(default)]
+    pub images: Vec<ChainedImage>,
+}
+
+// pattern-wide settings declared by `[name, value]` rows of the images_base
+#[derive(serde::Deserialize, Default)]
+pub struct Directives {
+    // locale => DOB/0 trait name => translation
+    #[serde(default)]
+    pub i18n: BTreeMap<String, BTreeMap<String, TraitTranslation>>,
+    // schema row templates `[type, pattern, args, options]` keyed by macro name
+    #[serde(default)]
+    pub macros: BTreeMap<String, Vec<Value>>,
+    // data hash of the cell dep holding the parent cluster's pattern
+    #[serde(default)]
+    pub inherit: Option<[u8; 32]>,
+    // DOB/0 trait name seeding `decoder::dobs_rng`
+    #[serde(default)]
+    pub seed: Option<String>,
+    // what the output carries unless the config picks a mode itself
+    #[serde(default)]
+    pub mode: Option<OutputMode>,
+    // the DOB/0 traits the output echoes, layers still match all of them by their names
+    #[serde(default)]
+    pub traits: TraitsFilter,
+    // image name => canvas of its `[name, "canvas", {...}]` row
+    #[serde(default)]
+    pub canvas: BTreeMap<String, Canvas>,
+    // image name => type of its `[name, "mime", type]` row, see `pipeline::finish_image`
+    #[serde(default)]
+    pub mime: BTreeMap<String, String>,
+    // composed images are written as commitments, see `Parameters.commit`
+    #[serde(default)]
+    pub commit: bool,
+    // of the pattern's `["dob1", version]` header, see `decoder::take_schema_version`
+    #[serde(default)]
+    pub version: Option<u64>,
+    // the gateways the pattern suggests, see `Config.rewrite`
+    #[serde(default)]
+    pub rewrite: BTreeMap<String, String>,
+    // asset name => the args value `@name` stands for, see `decoder::expand_asset_references`
+    #[serde(default)]
+    pub defs: Option<BTreeMap<String, Value>>,
+}
+
+// output dimension and background color a combiner composes one image group at
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, Clone)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub background: Option<String>,
+}
+
+#[derive(serde::Deserialize, Default)]
+pub struct TraitsFilter {
+    // only these DOB/0 traits are echoed, every one unless set
+    #[serde(default)]
+    pub only: Option<Vec<String>>,
+    #[serde(default)]
+    pub hide: Vec<String>,
+    // DOB/0 trait name => name in the output
+    #[serde(default)]
+    pub rename: BTreeMap<String, String>,
+}
+
+impl TraitsFilter {
+    // the name `name` is echoed under, None if it is dropped
+    pub fn output_name<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        let shown = self
+            .only
+            .as_ref()
+            .is_none_or(|only| only.iter().any(|only| only == name));
+        if !shown || self.hide.iter().any(|hide| hide == name) {
+            return None;
+        }
+        Some(self.rename.get(name).map_or(name, String::as_str))
+    }
+}
+
+#[derive(serde::Deserialize, Default)]
+pub struct TraitTranslation {
+    #[serde(default)]
+    pub name: Option<String>,
+    // keyed by the string or decimal number value of the trait
+    #[serde(default)]
+    pub values: BTreeMap<String, String>,
+}
+
+#[derive(serde::Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub output: OutputProtocol,
+    // what the output carries, the pattern's `mode` directive or `full` if unset
+    #[serde(default)]
+    pub mode: Option<OutputMode>,
+    // how blob and chunked output is encoded, framed output is always json
+    #[serde(default)]
+    pub encoding: OutputEncoding,
+    #[serde(default)]
+    pub cache: Option<RenderCache>,
+    #[serde(default)]
+    pub preview: Option<Preview>,
+    // forwarded to the combiner as a leading `OutputSize` item
+    #[serde(default)]
+    pub thumbnail: Option<Size>,
+    // picks the themed variant of args values, e.g. "dark" or "light"
+    #[serde(default)]
+    pub theme: Option<String>,
+    // prefer the animated variants of args values over the static ones
+    #[serde(default)]
+    pub animated: bool,
+    // emits `DOB1Output.display` translated by the pattern's i18n directive
+    #[serde(default)]
+    pub locale: Option<String>,
+    // opaque caller data copied verbatim into `DOB1Output.context`
+    #[serde(default)]
+    pub context: Option<Value>,
+    // let numeric DOB/0 values match string keys of the same digits and vice versa
+    #[serde(default)]
+    pub coerce: bool,
+    // also drop zero width characters when comparing DOB/0 strings against option keys
+    #[serde(default)]
+    pub strip_zero_width: bool,
+    // echo unknown schema option keys under `extensions` of the image group
+    #[serde(default)]
+    pub extensions: bool,
+    // echo the ItemVec of every composed image in `Image.items` to re-run the composition
+    #[serde(default)]
+    pub audit: bool,
+    // ceiling of combine and write syscalls, `pipeline::DEFAULT_SYSCALL_BUDGET` if unset
+    #[serde(default)]
+    pub syscall_budget: Option<u64>,
+    // a failed image group yields an `ERROR_TYPE` image instead of aborting the run
+    #[serde(default, alias = "lenient")]
+    pub recover: bool,
+    // compose every image in one pair of batched combine calls, only for hosts providing
+    // that syscall as an unknown one aborts the whole VM
+    #[serde(default)]
+    pub batch: bool,
+    // payload bytes of each chunk in chunked output, `output::DEFAULT_CHUNK_SIZE` if unset
+    #[serde(default)]
+    pub chunk_size: Option<usize>,
+    // bytes offered on the first combine call, 0 probes the size before every image,
+    // `pipeline::DEFAULT_COMBINE_BUFFER_SIZE` if unset
+    #[serde(default)]
+    pub combine_buffer_size: Option<usize>,
+    // schemes URI layers may use, any if unset, e.g. `validate::URI_SCHEMES`
+    #[serde(default)]
+    pub uri_schemes: Option<Vec<String>>,
+    // URI prefix => replacement, applied to the URIs of the items handed to the combiner
+    // once matching is done, `{"btcfs://": "https://ordinals.example/content/"}`, merged
+    // over the pattern's rewrite directive and the longest matching prefix wins
+    #[serde(default)]
+    pub rewrite: BTreeMap<String, String>,
+    // reject patterns with rows past their options column, rows of a group repeating a
+    // trait or args entries after a `["*"]` one, which validation reports as errors
+    #[serde(default)]
+    pub strict: bool,
+    // an image composed from the same ItemVec as an earlier one of the output names it in
+    // `Image.ref_` instead of repeating its content, not for molecule encoding
+    #[serde(default)]
+    pub dedupe: bool,
+    // ceiling of the decoded bytes of one image, checked against the size the combiner
+    // reports before any buffer of it is allocated
+    #[serde(default)]
+    pub max_image_bytes: Option<u64>,
+    // ceiling of the bytes a run writes, frames and terminators included
+    #[serde(default)]
+    pub max_output_bytes: Option<u64>,
+    #[serde(default)]
+    pub overflow: Overflow,
 }
 
+// what a run does once its output would exceed `Config.max_output_bytes`
+#[derive(serde::Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Overflow {
+    #[default]
+    Fail,
+    // images from the last one on give way to `TRUNCATED_TYPE` markers until the rest fits
+    Truncate,
+}
+
+#[derive(serde::Deserialize, Clone, Copy)]
+pub struct Size {
+    pub width: u32,
+    pub height: u32,
+}
+
+// compose only `layers` layers of each group for a quick low-fidelity render
+#[derive(serde::Deserialize)]
+pub struct Preview {
+    pub layers: usize,
+    #[serde(default)]
+    pub keep: PreviewKeep,
+}
+
+#[derive(serde::Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewKeep {
+    // the last (topmost) layers
+    #[default]
+    Top,
+    // the first (base) layers
+    Base,
+}
+
+// fingerprints of what the host has already rendered, `composed` is keyed by the
+// comma-joined fingerprints of a group's layers in stacking order
+#[derive(serde::Deserialize, Default)]
+pub struct RenderCache {
+    #[serde(default)]
+    pub layers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub composed: BTreeMap<String, String>,
+}
+
+#[derive(serde::Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputProtocol {
+    // a single null-terminated DOB1Output json
+    #[default]
+    Blob,
+    // traits and each image written as separate tagged frames, see `decoder::output`
+    Framed,
+    // the blob json split across `Channel::Chunk` frames of at most `Config.chunk_size`
+    // bytes, see `output::frame_chunks`
+    Chunked,
+}
+
+#[derive(serde::Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputMode {
+    #[default]
+    Full,
+    // traits and resolved layers, the image combiner is never called
+    Traits,
+    // images alone, without the traits and their display translation
+    Images,
+}
+
+// binary encodings are written hex encoded, the debug syscall only takes text
+#[derive(serde::Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputEncoding {
+    #[default]
+    Json,
+    // the `Output` table of `dob1_syscall.mol`, only traits and images
+    Molecule,
+    // the json data model in rfc 8949 cbor
+    Cbor,
+    // json that stays byte for byte the same for the same output across decoder versions,
+    // for hashing it: keys sorted, minimal escaping and floats as strings, see
+    // `json::to_canonical_vec`, not for framed output
+    Canonical,
+}
+
+impl Config {
+    pub fn mode(&self) -> OutputMode {
+        self.mode.unwrap_or_default()
+    }
+}
+
+// `Image.type_` of a group served from the host cache, `content` is the composed fingerprint
+pub const CACHE_REFERENCE_TYPE: &str = "cache-reference";
+
+// `Image.type_` of a group that failed to resolve or compose in recover mode, `error` holds the
+// code and `content` the name of the error
+pub const ERROR_TYPE: &str = "error";
+
+// `Image.type_` of an image dropped by `Overflow::Truncate`, `error` holds
+// `Error::OutputTooLarge`
+pub const TRUNCATED_TYPE: &str = "truncated";
+
+// `Image.type_` of a group whose fetched assets failed their sha256 check, in recover mode
+// or not, `error` holds `Error::ComposeIntegrityMismatch`
+pub const INTEGRITY_FAILED_TYPE: &str = "integrity-failed";
+
+// suffix of the `Image.type_` of a composed image written as a commitment: `content` is
+// the hex blake2b-256 hash, personalized as CKB's `ckb-default-hash`, of the image length
+// as a little-endian u64, the image bytes and the ItemVec in `items` it was composed from
+pub const COMMITMENT_ENCODING: &str = "commitment";
+
 #[derive(serde::Serialize)]
 pub struct Image {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: String,
     pub content: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<LayerSource>,
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    pub preview: bool,
+    #[serde(flatten)]
+    pub metadata: ImageMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<u64>,
+    // what the combiner answered when `error` is one it names, see `COMBINE_FETCH_FAILED`
+    // and its siblings in `pipeline`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combine_error: Option<u64>,
+    // hex of the molecule ItemVec handed to the combiner, see `Config.audit`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<String>,
+    // name of the earlier image holding the content, which is left empty, see `Config.dedupe`
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_: Option<String>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Clone)]
+pub struct LayerSource {
+    pub uri: String,
+    pub hash: String,
 }
 
 #[derive(serde::Serialize)]
 pub struct DOB1Output {
-    pub traits: Vec<DOB0Output>,
+    // left out in `OutputMode::Images`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traits: Option<Vec<DOB0Output>>,
     pub images: Vec<Image>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layers: Option<Vec<LayerGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<Vec<DisplayTrait>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Value>,
+    // execution counters, only filled in by `trace` builds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<RenderReport>,
+}
+
+// a summary of the run for monitoring it without decoding any image, see `ReportMode`
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Default)]
+pub struct RenderReport {
+    pub images: Vec<ImageReport>,
+    // layers the pattern rows resolved to, without the cluster background
+    pub layers_matched: u64,
+    // rows of the resolved groups that added no layer
+    pub layers_skipped: u64,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct ImageReport {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    // of the decoded content for base64 images, zero for failed ones
+    pub bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<u64>,
+}
+
+// an element of the array a batch run writes instead of a single `DOB1Output`
+#[derive(serde::Serialize)]
+pub struct BatchOutput {
+    pub token_id: String,
+    #[serde(flatten)]
+    pub output: DOB1Output,
+}
+
+// see `coverage::analyze_pattern`
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct CoverageReport {
+    // the code the pattern failed to parse with, no rows are analyzed then
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<u64>,
+    pub rows: Vec<RowCoverage>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct RowCoverage {
+    pub group: String,
+    // position among the schema rows, directive rows not counted
+    pub row: usize,
+    pub dob0_trait: String,
+    // merged `[start, end]` integers the reachable args entries match, entries matching by
+    // anything but an integer or an integer range are left out
+    pub covered: Vec<[i128; 2]>,
+    // integers between the covered ranges, or below the divisor of a mod row, that match
+    // no entry, none if a `["*"]` entry catches them
+    pub gaps: Vec<[i128; 2]>,
+    // some value matches no entry, the group then ends at this row and renders without
+    // the layers of the rows after it
+    pub open: bool,
+    pub unreachable: Vec<ShadowedEntry>,
+}
+
+// an args entry no value reaches since the entry `by` always matches first, both are
+// positions within the row's args
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(serde::Serialize)]
+pub struct ShadowedEntry {
+    pub index: usize,
+    pub by: usize,
+}
+
+// what validation-only runs write instead of a `DOB1Output`
+#[derive(serde::Serialize)]
+pub struct ValidationReport {
+    // no issue of `Severity::Error`
+    pub valid: bool,
+    // the code the pattern failed to parse with, no issues are checked then
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<u64>,
+    pub issues: Vec<ValidationIssue>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    pub group: String,
+    // position among the schema rows, directive rows not counted
+    pub row: usize,
+    pub message: String,
+    // the error a strict decoding run fails with on this issue, see `Config.strict`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<u64>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    // the pattern cannot render as intended
+    Error,
+    // legal but most likely a mistake, e.g. an entry shadowed by an earlier one
+    Warning,
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(serde::Serialize)]
+pub struct DisplayTrait {
+    pub name: String,
+    pub value: String,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct Layer {
+    #[serde(rename = "type")]
+    pub type_: ImageType,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform: Option<Transform>,
+    // font and placement of a text layer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<TextStyle>,
+    // frames of an animated uri layer, `value` is the first of them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill: Option<Fill>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub duration: u32,
+    pub frames: Vec<String>,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct LayerGroup {
+    pub name: String,
+    pub layers: Vec<Layer>,
+    #[serde(flatten)]
+    pub metadata: ImageMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "ImageFormat::is_default")]
+    pub format: ImageFormat,
+    // traits synthesized by the `as_trait` rows of the group
+    #[serde(skip)]
+    pub traits: Vec<DOB0Output>,
+    // forwarded to the combiner as a leading `Canvas` item
+    #[serde(skip)]
+    pub canvas: Option<Canvas>,
+    // rows that added no layer, see `RenderReport`
+    #[serde(skip)]
+    pub skipped: usize,
+}
+
+// see `decoder::pattern_stats`
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize)]
+pub struct GroupStats {
+    pub name: String,
+    // distinct layer stacks the group can render, saturated at u64::MAX
+    pub combinations: u64,
+    // a raw row takes its value verbatim from the trait, so the space is open ended
+    pub unbounded: bool,
+    pub fallbacks: Vec<FallbackBranch>,
 }
 
-#[cfg_attr(test, derive(serde::Serialize, Clone, Debug))]
-#[derive(serde::Deserialize, PartialEq, Eq)]
+// a `["*"]` args entry catching every value the entries before it missed
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(serde::Serialize)]
+pub struct FallbackBranch {
+    // position of the schema row within its group
+    pub row: usize,
+    pub dob0_trait: String,
+    // position of the entry within the row's args
+    pub index: usize,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq)]
 pub enum ImageType {
+    #[serde(rename = "color")]
     ColorCode,
+    #[serde(rename = "uri")]
     URI,
+    #[serde(rename = "image")]
     RawImage,
+    // an svg fragment the decoder splices into the composed svg itself
+    #[serde(rename = "svg")]
+    SVG,
+    // a line of text stamped onto the image, styled by the row's `text` option
+    #[serde(rename = "text")]
+    Text,
 }
 
-#[cfg_attr(test, derive(serde::Serialize, Clone, PartialEq, Debug))]
-#[derive(serde::Deserialize)]
+// the decoder inflates the embedded bytes before building the `RawImage` item
+//
+// gzip only for now, zstd needs a no_std decoder this crate doesn't have yet and is left
+// to a change of its own, `image+zstd` rows fail like any other unknown encoding until then
+#[cfg_attr(test, derive(serde::Serialize, Debug))]
+#[derive(serde::Deserialize, Clone, PartialEq)]
+pub enum Compression {
+    Gzip,
+}
+
+#[cfg_attr(test, derive(serde::Serialize, Debug))]
+#[derive(serde::Deserialize, Clone, PartialEq)]
 pub enum Pattern {
     Options,
     Range,
     Raw,
+    // args is a string whose `{TraitName}` placeholders take the DOB/0 values
+    Template,
+    // args are `[weight, value]` entries drawn from by the DOB/0 value as the seed
+    WeightedRandom,
+    // args are a divisor followed by `[remainder, value]` entries matched like options
+    // against the DOB/0 number modulo the divisor
+    Mod,
+    // args are a bucket size followed by values, the DOB/0 number divided by the size
+    // picks the value, numbers past the last bucket match nothing
+    Bucket,
+    // args are an arithmetic expression over DOB/0 number traits followed by entries
+    // matched like range ones against its value, see `expr::Expr`, the row's own trait
+    // only decides whether the row is missing
+    Expr,
 }
 
-#[cfg_attr(test, derive(serde::Serialize, Clone, PartialEq, Debug))]
-#[derive(serde::Deserialize)]
+#[cfg_attr(test, derive(serde::Serialize, Debug))]
+#[derive(serde::Deserialize, Clone, PartialEq)]
 pub struct TraitSchema {
     pub name: String,
     pub type_: ImageType,
+    // the traits of a multi-trait row joined by commas
     pub dob0_trait: String,
+    // every trait of a multi-trait row, empty if the row matches a single trait
+    #[serde(default)]
+    pub dob0_traits: Vec<String>,
     pub pattern: Pattern,
+    // how the values of an `image+<compression>` row are compressed
+    #[serde(default)]
+    pub compression: Option<Compression>,
+    // `[key, value]` entries kept in the order the pattern wrote them, the first match wins
     pub args: Option<Value>,
+    pub options: SchemaOptions,
+}
+
+// the optional sixth element of a schema row
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, PartialEq)]
+pub struct SchemaOptions {
+    #[serde(flatten)]
+    pub metadata: ImageMetadata,
+    // alt text template of the image group, `{TraitName}` is replaced by the DOB/0 value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub alt: Option<String>,
+    // byte order of `0x` hex string traits matched by a range pattern
+    #[serde(skip_serializing_if = "Endian::is_default")]
+    #[serde(default)]
+    pub endian: Endian,
+    // how the group is rendered, the first row declaring it wins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub format: Option<ImageFormat>,
+    // what the row does when its DOB/0 trait is absent
+    #[serde(skip_serializing_if = "MissingTrait::is_default")]
+    #[serde(default)]
+    pub missing: MissingTrait,
+    // stacking order of the row's layer within its group, lower is further back, rows of
+    // equal `z` keep their schema order and rows without one sit at 0
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub z: Option<i32>,
+    // places the row's layer instead of stretching it over the whole canvas
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub transform: Option<Transform>,
+    // font and placement of a `text` row's layer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub text: Option<TextStyle>,
+    // milliseconds each value of the row shows for, the values it picks play in turn as
+    // one animated layer instead of stacking, uri rows only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub frames: Option<u32>,
+    // name of a trait appended to the output traits, holding the values the row's args
+    // picked as the pattern spells them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub as_trait: Option<String>,
+    // string keys of an options row match regardless of case and of whitespace around
+    // the DOB/0 value and the key, "ethan " matches "Ethan"
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    #[serde(default)]
+    pub loose: bool,
+    // DOB/0 trait name => args keys, the row is skipped unless every trait matches one of
+    // its keys, `{"Rarity": ["Legendary", "Mythic"]}`, a missing trait matches none
+    #[serde(rename = "if", skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde(default)]
+    pub guard: BTreeMap<String, Vec<Value>>,
+    // how a raw row writes a Number trait, which it rejects without one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub number: Option<NumberFormat>,
+    // which of the DOB/0 entries sharing the row's trait name it reads, single trait rows only
+    #[serde(skip_serializing_if = "Occurrence::is_default")]
+    #[serde(default)]
+    pub occurrence: Occurrence,
+    // repeats the layer of a uri or image row at its own size across the canvas
+    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    #[serde(default)]
+    pub tile: bool,
+    // `[top, right, bottom, left]` pixel insets stretching the layer of a uri or image row
+    // as a nine-slice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub slice: Option<[u32; 4]>,
+    // rows of the group sharing this label form an else-if chain: the first of them adding
+    // a layer wins and the later ones are skipped, a row of the chain matching nothing or
+    // missing its trait falls through to the next instead of ending the group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub exclusive: Option<String>,
+    // keys unknown to this decoder, kept for ecosystem extensions, see `Config.extensions`
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+impl SchemaOptions {
+    pub fn is_empty(&self) -> bool {
+        self.metadata.is_empty()
+            && self.alt.is_none()
+            && self.endian.is_default()
+            && self.format.is_none()
+            && self.missing.is_default()
+            && self.z.is_none()
+            && self.transform.is_none()
+            && self.text.is_none()
+            && self.frames.is_none()
+            && self.as_trait.is_none()
+            && !self.loose
+            && self.guard.is_empty()
+            && self.number.is_none()
+            && self.occurrence.is_default()
+            && !self.tile
+            && self.slice.is_none()
+            && self.exclusive.is_none()
+            && self.extensions.is_empty()
+    }
+
+    // `decode_schema_options` rejects rows asking for both
+    pub fn fill(&self) -> Option<Fill> {
+        match (self.tile, self.slice) {
+            (true, _) => Some(Fill::Tile),
+            (false, insets) => insets.map(Fill::Slice),
+        }
+    }
+}
+
+// written as "truncate", "skip", "error" or "default:<value>"
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum MissingTrait {
+    // the group ends at this row, none of the later rows are rendered
+    #[default]
+    Truncate,
+    // only this row is left out
+    Skip,
+    // the group fails with `Error::DecodeMissingTrait`
+    Fail,
+    // the layer takes this value as is, no args are matched
+    Default(String),
+}
+
+impl MissingTrait {
+    pub fn is_default(&self) -> bool {
+        *self == MissingTrait::Truncate
+    }
+}
+
+impl TryFrom<String> for MissingTrait {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "truncate" => Ok(MissingTrait::Truncate),
+            "skip" => Ok(MissingTrait::Skip),
+            "error" => Ok(MissingTrait::Fail),
+            _ => value
+                .strip_prefix("default:")
+                .map(|value| MissingTrait::Default(value.into()))
+                .ok_or("unknown missing trait policy"),
+        }
+    }
+}
+
+impl From<MissingTrait> for String {
+    fn from(value: MissingTrait) -> Self {
+        match value {
+            MissingTrait::Truncate => "truncate".to_owned(),
+            MissingTrait::Skip => "skip".to_owned(),
+            MissingTrait::Fail => "error".to_owned(),
+            MissingTrait::Default(value) => alloc::format!("default:{value}"),
+        }
+    }
+}
+
+// written as "decimal", "hex", "pad:<width>" or "hex:<width>", widths pad with leading
+// zeros and hex digits are lowercase without a `0x`
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub struct NumberFormat {
+    pub hex: bool,
+    pub width: usize,
+}
+
+impl NumberFormat {
+    // negative numbers only have a decimal form
+    pub fn format(&self, number: i128) -> Result<String, Error> {
+        let width = self.width;
+        match self.hex {
+            true if number < 0 => Err(Error::DecodeInvalidRawValue),
+            true => Ok(alloc::format!("{number:0width$x}")),
+            false => Ok(alloc::format!("{number:0width$}")),
+        }
+    }
+}
+
+impl TryFrom<String> for NumberFormat {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (radix, width) = match value.split_once(':') {
+            Some((radix, width)) => (radix, width.parse().map_err(|_| "invalid width")?),
+            None => (value.as_str(), 0),
+        };
+        match radix {
+            "decimal" if width == 0 => Ok(NumberFormat { hex: false, width }),
+            "pad" => Ok(NumberFormat { hex: false, width }),
+            "hex" => Ok(NumberFormat { hex: true, width }),
+            _ => Err("unknown number format"),
+        }
+    }
+}
+
+impl From<NumberFormat> for String {
+    fn from(value: NumberFormat) -> Self {
+        match (value.hex, value.width) {
+            (false, 0) => "decimal".to_owned(),
+            (false, width) => alloc::format!("pad:{width}"),
+            (true, 0) => "hex".to_owned(),
+            (true, width) => alloc::format!("hex:{width}"),
+        }
+    }
+}
+
+// written as "first", "last", "all" or "nth:<index>"
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum Occurrence {
+    #[default]
+    First,
+    Last,
+    // counted from 0 among the entries of the name
+    Nth(usize),
+    // the row is matched once per entry, each match adding its own layers
+    All,
+}
+
+impl Occurrence {
+    pub fn is_default(&self) -> bool {
+        *self == Occurrence::First
+    }
+}
+
+impl TryFrom<String> for Occurrence {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "first" => Ok(Occurrence::First),
+            "last" => Ok(Occurrence::Last),
+            "all" => Ok(Occurrence::All),
+            _ => value
+                .strip_prefix("nth:")
+                .and_then(|index| index.parse().ok())
+                .map(Occurrence::Nth)
+                .ok_or("unknown occurrence"),
+        }
+    }
+}
+
+impl From<Occurrence> for String {
+    fn from(value: Occurrence) -> Self {
+        match value {
+            Occurrence::First => "first".to_owned(),
+            Occurrence::Last => "last".to_owned(),
+            Occurrence::Nth(index) => alloc::format!("nth:{index}"),
+            Occurrence::All => "all".to_owned(),
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    // composed by the image combiner into `PNG_TYPE`
+    #[default]
+    Png,
+    // layers stacked into an `SVG_TYPE` document by the decoder itself
+    Svg,
+    // nothing is composed, the URIs of the layers are listed as `URI_LIST_TYPE`
+    Urls,
+}
+
+impl ImageFormat {
+    pub fn is_default(&self) -> bool {
+        *self == ImageFormat::Png
+    }
+}
+
+pub const PNG_TYPE: &str = "image/png;base64";
+// what the combiner composes a group with animated layers into
+pub const APNG_MIME: &str = "image/apng";
+pub const SVG_TYPE: &str = "image/svg+xml";
+pub const URI_LIST_TYPE: &str = "text/uri-list";
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Endian {
+    #[default]
+    Big,
+    Little,
+}
+
+impl Endian {
+    pub fn is_default(&self) -> bool {
+        *self == Endian::Big
+    }
+}
+
+// display hints of an image group, any row of the group may declare them and the first
+// declaration wins
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Default, Clone, PartialEq)]
+pub struct ImageMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<[u32; 2]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safe_area: Option<SafeArea>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape: Option<Shape>,
+    // ask the combiner to bake `shape` into the render as a `Mask` item
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    pub mask: bool,
+    // unknown schema option keys of the group, only echoed with `Config.extensions`
+    #[serde(skip_deserializing, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, Value>,
+}
+
+impl ImageMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.aspect_ratio.is_none() && self.safe_area.is_none() && self.shape.is_none()
+    }
+
+    pub fn merge(&mut self, other: &ImageMetadata) {
+        if self.aspect_ratio.is_none() {
+            self.aspect_ratio = other.aspect_ratio;
+        }
+        if self.safe_area.is_none() {
+            self.safe_area.clone_from(&other.safe_area);
+        }
+        if self.shape.is_none() {
+            self.shape = other.shape;
+            self.mask = other.mask;
+        }
+        for (key, value) in &other.extensions {
+            self.extensions
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Shape {
+    Square,
+    Circle,
+    // corner radius in pixels
+    RoundedRect(u32),
+}
+
+// offsets in pixels of the combined image, scale and rotation about the layer's center,
+// `rotation` in degrees clockwise and `opacity` from 0 to 1
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq)]
+pub struct Transform {
+    #[serde(default)]
+    pub x: i32,
+    #[serde(default)]
+    pub y: i32,
+    #[serde(default = "Transform::unit")]
+    pub scale: f64,
+    #[serde(default)]
+    pub rotation: i32,
+    #[serde(default = "Transform::unit")]
+    pub opacity: f64,
+}
+
+impl Transform {
+    fn unit() -> f64 {
+        1.0
+    }
+}
+
+// how an image layer smaller than the canvas fills it instead of being stretched over it
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Fill {
+    Tile,
+    // nine-slice insets in pixels, top, right, bottom and left
+    Slice([u32; 4]),
+}
+
+// `x` and `y` place the center of the text and `size` is its font size, all fractions of
+// the canvas width and height
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq)]
+pub struct TextStyle {
+    #[serde(default = "TextStyle::center")]
+    pub x: f64,
+    #[serde(default = "TextStyle::center")]
+    pub y: f64,
+    #[serde(default = "TextStyle::font_size")]
+    pub size: f64,
+    #[serde(default = "TextStyle::color")]
+    pub color: String,
+}
+
+impl TextStyle {
+    fn center() -> f64 {
+        0.5
+    }
+
+    fn font_size() -> f64 {
+        0.1
+    }
+
+    fn color() -> String {
+        "#000000".to_owned()
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            x: Self::center(),
+            y: Self::center(),
+            size: Self::font_size(),
+            color: Self::color(),
+        }
+    }
+}
+
+// the `color` value `linear:<stops>[,<angle>deg]` or `radial:<stops>`, see `parse_gradient`
+#[cfg_attr(test, derive(PartialEq, Debug))]
+pub struct Gradient {
+    pub kind: GradientKind,
+    // clockwise from the top, always 0 for radial gradients
+    pub angle: u32,
+    // at least two color codes
+    pub stops: Vec<String>,
+}
+
+#[cfg_attr(test, derive(PartialEq, Debug))]
+#[derive(Clone, Copy)]
+pub enum GradientKind {
+    Linear,
+    Radial,
+}
+
+// rectangle in fractions of the image width and height
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq)]
+pub struct SafeArea {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
 }
@@ -0,0 +1,281 @@
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use serde_json::Value;
+
+use super::args::{decode_hex_arg, dobs_parse_images_base};
+use super::matcher::{json_integer, range_bounds};
+use super::pipeline::Run;
+use super::types::{
+    Error, ImageType, Pattern, Severity, TraitSchema, ValidationIssue, ValidationReport,
+};
+use super::{
+    decode_raw_image, has_uri_scheme, is_color_value, is_raw_image, raw_image_mime, CHAINED_PREFIX,
+    STATIC_TRAIT,
+};
+
+// schemes a pattern may point its URI layers at unless the config sets `uri_schemes`,
+// anything else is unlikely to resolve
+pub const URI_SCHEMES: [&str; 5] = ["btcfs", "ckbfs", "ipfs", "https", "data"];
+
+// checks a pattern before it goes on-chain, the same parsing a strict decoding run does
+// followed by semantic checks of the values the pattern itself spells out, values coming
+// from DOB/0 traits can only be checked when decoding
+pub fn dobs_validate_pattern(images_base: &[u8], run: &Run) -> ValidationReport {
+    let parsed =
+        decode_hex_arg(images_base).and_then(|value| dobs_parse_images_base(&value, true, run));
+    let images_base = match parsed {
+        Ok((_, images_base)) => images_base,
+        Err(err) => {
+            return ValidationReport {
+                valid: false,
+                error: Some(err as u64),
+                issues: Vec::new(),
+            }
+        }
+    };
+    let mut issues = Vec::new();
+    check_group_names(&images_base, &mut issues);
+    let duplicates = duplicate_rows(&images_base);
+    for (row, schema) in images_base.iter().enumerate() {
+        let mut issue = |severity, message: String, error: Option<Error>| {
+            issues.push(ValidationIssue {
+                severity,
+                group: schema.name.clone(),
+                row,
+                message,
+                code: error.map(|err| err as u64),
+            })
+        };
+        if let Some(earlier) = duplicates
+            .iter()
+            .find_map(|&(duplicate, earlier)| (duplicate == row).then_some(earlier))
+        {
+            issue(
+                Severity::Error,
+                format!(
+                    "duplicate row of trait {}, see row {earlier}",
+                    schema.dob0_trait
+                ),
+                Some(Error::SchemaDuplicateRow),
+            );
+        }
+        for value in dob1_values(schema) {
+            match schema.type_ {
+                ImageType::ColorCode if !is_color_value(value) => {
+                    issue(Severity::Error, format!("invalid color code {value}"), None)
+                }
+                ImageType::URI if !has_uri_scheme(value, &URI_SCHEMES) => issue(
+                    Severity::Error,
+                    format!("unsupported uri scheme in {value}"),
+                    None,
+                ),
+                ImageType::RawImage
+                    if !is_raw_image(value) && !value.starts_with(CHAINED_PREFIX) =>
+                {
+                    issue(
+                        Severity::Error,
+                        "raw image is neither base64 nor hex".to_owned(),
+                        None,
+                    )
+                }
+                ImageType::RawImage
+                    if schema.compression.is_none()
+                        && decode_raw_image(value)
+                            .is_some_and(|image| raw_image_mime(&image).is_none()) =>
+                {
+                    issue(
+                        Severity::Error,
+                        "raw image is no png, jpeg or webp".to_owned(),
+                        Some(Error::DecodeUnknownRawImageFormat),
+                    )
+                }
+                _ => {}
+            }
+        }
+        for (message, error) in shadowed_entries(schema) {
+            let severity = match error {
+                Some(_) => Severity::Error,
+                None => Severity::Warning,
+            };
+            issue(severity, message, error);
+        }
+    }
+    ValidationReport {
+        valid: !issues.iter().any(|issue| issue.severity == Severity::Error),
+        error: None,
+        issues,
+    }
+}
+
+// rows of a group must be adjacent, a name showing up again later yields a second image
+// of the same name
+fn check_group_names(images_base: &[TraitSchema], issues: &mut Vec<ValidationIssue>) {
+    let mut closed: Vec<&str> = Vec::new();
+    let mut current = None;
+    for (row, schema) in images_base.iter().enumerate() {
+        if current == Some(schema.name.as_str()) {
+            continue;
+        }
+        if closed.contains(&schema.name.as_str()) {
+            issues.push(ValidationIssue {
+                severity: Severity::Error,
+                group: schema.name.clone(),
+                row,
+                message: "duplicate group name".to_owned(),
+                code: None,
+            });
+        }
+        closed.extend(current);
+        current = Some(schema.name.as_str());
+    }
+}
+
+// the error of the first row a strict decoding run rejects, see `Config.strict`
+pub(crate) fn check_strict(images_base: &[TraitSchema]) -> Result<(), Error> {
+    if !duplicate_rows(images_base).is_empty() {
+        return Err(Error::SchemaDuplicateRow);
+    }
+    match images_base
+        .iter()
+        .flat_map(shadowed_entries)
+        .find_map(|(_, error)| error)
+    {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+// `(row, earlier)` for every row repeating the trait of an earlier row of its group, rows
+// of the static trait may repeat as they take no value from the DOB/0 output, and so may
+// the alternatives of an exclusive chain
+fn duplicate_rows(images_base: &[TraitSchema]) -> Vec<(usize, usize)> {
+    let mut duplicates = Vec::new();
+    for (row, schema) in images_base.iter().enumerate() {
+        if schema.dob0_trait == STATIC_TRAIT {
+            continue;
+        }
+        let chain = schema.options.exclusive.as_ref();
+        let earlier = images_base[..row].iter().position(|earlier| {
+            earlier.name == schema.name
+                && earlier.dob0_trait == schema.dob0_trait
+                && (chain.is_none() || earlier.options.exclusive.as_ref() != chain)
+        });
+        if let Some(earlier) = earlier {
+            duplicates.push((row, earlier));
+        }
+    }
+    duplicates
+}
+
+// every layer value the row may render, themed variants included
+fn dob1_values(schema: &TraitSchema) -> Vec<&str> {
+    let mut values = Vec::new();
+    match (&schema.pattern, &schema.args) {
+        (Pattern::Raw, Some(Value::String(value))) if schema.dob0_trait == STATIC_TRAIT => {
+            values.push(value.as_str())
+        }
+        // only the text before the first placeholder is known
+        (Pattern::Template, Some(Value::String(template))) => {
+            if let Some((head, _)) = template.split_once('{') {
+                if head.contains(':') {
+                    values.push(head);
+                }
+            } else {
+                values.push(template.as_str());
+            }
+        }
+        (Pattern::Bucket, Some(Value::Array(entries))) => {
+            for entry in entries.iter().skip(1) {
+                push_dob1_value(&mut values, entry);
+            }
+        }
+        (_, Some(Value::Array(entries))) => {
+            for entry in entries.iter().filter_map(Value::as_array) {
+                if let Some(value) = entry.get(1) {
+                    push_dob1_value(&mut values, value);
+                }
+            }
+        }
+        _ => {}
+    }
+    values
+}
+
+fn push_dob1_value<'a>(values: &mut Vec<&'a str>, dob1_value: &'a Value) {
+    match dob1_value {
+        Value::String(value) => values.push(value.as_str()),
+        Value::Object(variants) => values.extend(variants.values().filter_map(Value::as_str)),
+        Value::Array(stack) => stack
+            .iter()
+            .for_each(|dob1_value| push_dob1_value(values, dob1_value)),
+        _ => {}
+    }
+}
+
+// entries no DOB/0 value can ever reach because an earlier one always matches first, those
+// after a `["*"]` entry come with the error strict mode rejects them with
+fn shadowed_entries(schema: &TraitSchema) -> Vec<(String, Option<Error>)> {
+    let mut messages = Vec::new();
+    let Some(Value::Array(entries)) = &schema.args else {
+        return messages;
+    };
+    if !matches!(schema.pattern, Pattern::Options | Pattern::Range)
+        || !schema.dob0_traits.is_empty()
+    {
+        return messages;
+    }
+    let keys = entries
+        .iter()
+        .map(|entry| entry.as_array().and_then(|entry| entry.first()))
+        .collect::<Vec<_>>();
+    for (index, key) in keys.iter().enumerate() {
+        let Some(key) = key else {
+            continue;
+        };
+        if let Some(earlier) = shadowing_entry(&keys, index) {
+            let error = keys[earlier]
+                .is_some_and(is_wildcard)
+                .then_some(Error::SchemaShadowedEntry);
+            messages.push((
+                format!("args entry {index} is shadowed by entry {earlier}"),
+                error,
+            ));
+        } else if let Some((key_start, key_end)) = range_of(key) {
+            let overlapping = keys[..index].iter().position(|earlier| {
+                earlier
+                    .and_then(range_of)
+                    .is_some_and(|(start, end)| start <= key_end && key_start <= end)
+            });
+            if let Some(earlier) = overlapping {
+                messages.push((format!("args entry {index} overlaps entry {earlier}"), None));
+            }
+        }
+    }
+    messages
+}
+
+// the earlier entry matching every value the key at `index` matches, if any
+pub(crate) fn shadowing_entry(keys: &[Option<&Value>], index: usize) -> Option<usize> {
+    let key = keys[index]?;
+    keys[..index].iter().position(|earlier| match earlier {
+        Some(earlier) if is_wildcard(earlier) => true,
+        Some(earlier) => match (range_of(earlier), range_of(key)) {
+            (Some((start, end)), Some((key_start, key_end))) => {
+                start <= key_start && key_end <= end
+            }
+            _ => *earlier == key,
+        },
+        None => false,
+    })
+}
+
+pub(crate) fn is_wildcard(key: &Value) -> bool {
+    matches!(key.as_array().map(Vec::as_slice), Some([Value::String(name)]) if name == "*")
+}
+
+pub(crate) fn range_of(key: &Value) -> Option<(i128, i128)> {
+    match key {
+        Value::Number(_) => json_integer(key).map(|value| (value, value)),
+        Value::Array(range) => range_bounds(range).ok(),
+        _ => None,
+    }
+}
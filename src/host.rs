@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+
+use crate::decoder::loader::CellReference;
+use crate::decoder::types::Error;
+
+// what the decoder needs from the VM running it, the binary implements it on top of the
+// syscalls of `SyscallTable` and the simulator with std stand-ins, see `dobs_run_host`
+pub trait Host {
+    // the image combining syscall, probed with an empty buffer first, see `dobs_run`
+    fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64;
+    fn write(&mut self, output: &[u8]) -> u64;
+
+    // log records and diagnostics, which go out with the output unless the host keeps them
+    // apart
+    fn write_log(&mut self, frame: &[u8]) -> u64 {
+        self.write(frame)
+    }
+
+    // whether `combine_batch` is there at all, hosts without it combine image by image
+    fn combines_batches(&self) -> bool {
+        false
+    }
+
+    // the batched combining syscall, it takes an `ItemVecVec` and answers with an `ImageVec`
+    // holding one image per pattern, probed like `combine`
+    fn combine_batch(
+        &mut self,
+        _buffer: &mut [u8],
+        _buffer_size: &mut u64,
+        _patterns: &[u8],
+    ) -> u64 {
+        1
+    }
+
+    // whether `load_cell` reads cell deps at all, `ckbfs://` images are left to the combiner
+    // of hosts without it
+    fn reads_cells(&self) -> bool {
+        false
+    }
+
+    // the data of a cell dep, for `ckbfs://` images and the parent pattern of an inherit
+    // directive
+    fn load_cell(&mut self, _cell: &CellReference) -> Option<Vec<u8>> {
+        None
+    }
+
+    // the most bytes its allocator has had out at once, for the `peak_heap` stat and the
+    // heap peak a run logs at the debug level
+    fn heap_peak(&self) -> Option<usize> {
+        None
+    }
+}
+
+// numbers of the syscalls the binary issues besides the CKB-VM ones
+#[cfg_attr(test, derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SyscallTable {
+    pub combine: u64,
+    // see `Host::combine_batch`, None for hosts without it, as a VM aborts on any syscall
+    // its host doesn't know
+    pub combine_batch: Option<u64>,
+    pub write: u64,
+}
+
+impl SyscallTable {
+    // the DOB server this decoder was written against
+    pub const DOB_SERVER: SyscallTable = SyscallTable {
+        combine: 2077,
+        combine_batch: None,
+        write: 2177,
+    };
+
+    // the number proposed for `combine_batch`, no DOB server answers it yet, so only hosts
+    // that do are opted in by `--syscall-combine-batch=2078`
+    pub const PROPOSED_COMBINE_BATCH: u64 = 2078;
+
+    // takes every `--syscall-<name>=<number>` flag out of `args`, so hosts numbering their
+    // syscalls differently can run the same binary
+    pub fn take_overrides(mut self, args: &mut Vec<&[u8]>) -> Result<Self, Error> {
+        let mut result = Ok(());
+        args.retain(|arg| {
+            let Some(flag) = arg.strip_prefix(b"--syscall-") else {
+                return true;
+            };
+            let parsed = flag
+                .iter()
+                .position(|&byte| byte == b'=')
+                .and_then(|split| {
+                    let number = core::str::from_utf8(&flag[split + 1..]).ok()?;
+                    Some((&flag[..split], number.parse::<u64>().ok()?))
+                });
+            match parsed {
+                Some((b"combine", number)) => self.combine = number,
+                Some((b"combine-batch", number)) => self.combine_batch = Some(number),
+                Some((b"write", number)) => self.write = number,
+                _ => result = Err(Error::ParseInvalidFlag),
+            }
+            false
+        });
+        result.map(|_| self)
+    }
+}
+
+impl Default for SyscallTable {
+    fn default() -> Self {
+        Self::DOB_SERVER
+    }
+}
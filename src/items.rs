@@ -0,0 +1,98 @@
+use alloc::string::String;
+use molecule::prelude::{Builder, Byte, Entity};
+
+use crate::generated::{Bytes, Color, Item, ItemUnion, OutputSize, RawImage, Uint32, URI};
+
+// shorthands for the molecule items of a combiner pattern, so hosts and tests don't map
+// bytes into builders by hand:
+//
+//   let pattern = ItemVec::new_builder()
+//       .push(Item::color("#FF0000"))
+//       .push(Item::uri("btcfs://...i0"))
+//       .build();
+//   let color = Color::try_from(items.get(0).unwrap())?.text();
+
+macro_rules! from_bytes {
+    ($itemty: ident, $value: ty) => {
+        impl From<$value> for $itemty {
+            fn from(value: $value) -> Self {
+                let value: &[u8] = value.as_ref();
+                $itemty::new_builder()
+                    .set(value.iter().copied().map(Byte::new).collect())
+                    .build()
+            }
+        }
+    };
+}
+
+from_bytes!(Color, &str);
+from_bytes!(URI, &str);
+from_bytes!(RawImage, &str);
+from_bytes!(RawImage, &[u8]);
+from_bytes!(Bytes, &str);
+from_bytes!(Bytes, &[u8]);
+
+impl From<u32> for Uint32 {
+    fn from(value: u32) -> Self {
+        Uint32::new_builder()
+            .set(value.to_le_bytes().map(Byte::new))
+            .build()
+    }
+}
+
+impl Item {
+    pub fn color(value: &str) -> Self {
+        Item::new_builder().set(Color::from(value)).build()
+    }
+
+    pub fn uri(value: &str) -> Self {
+        Item::new_builder().set(URI::from(value)).build()
+    }
+
+    // the image bytes as the combiner reads them, the decoder itself sends base64 text
+    pub fn raw(bytes: &[u8]) -> Self {
+        Item::new_builder().set(RawImage::from(bytes)).build()
+    }
+
+    pub fn output_size(width: u32, height: u32) -> Self {
+        let size = OutputSize::new_builder()
+            .width(width.into())
+            .height(height.into())
+            .build();
+        Item::new_builder().set(size).build()
+    }
+}
+
+// the item's own molecule value, or the item back if it holds another one
+macro_rules! try_from_item {
+    ($itemty: ident) => {
+        impl TryFrom<Item> for $itemty {
+            type Error = Item;
+
+            fn try_from(item: Item) -> Result<Self, Self::Error> {
+                match item.to_enum() {
+                    ItemUnion::$itemty(value) => Ok(value),
+                    _ => Err(item),
+                }
+            }
+        }
+    };
+}
+
+try_from_item!(URI);
+try_from_item!(Color);
+try_from_item!(RawImage);
+try_from_item!(OutputSize);
+
+impl Color {
+    // None unless the code is utf-8
+    pub fn text(&self) -> Option<String> {
+        String::from_utf8(self.raw_data().to_vec()).ok()
+    }
+}
+
+impl URI {
+    pub fn text(&self) -> Option<String> {
+        String::from_utf8(self.raw_data().to_vec()).ok()
+    }
+}
@@ -1,8 +1,14 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 extern crate alloc;
 pub mod decoder;
 pub mod generated;
+pub mod host;
+pub mod items;
+pub mod log;
+#[cfg(feature = "std")]
+pub mod simulator;
+pub mod stats;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,146 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Arguments;
+use serde_json::Value;
+
+use crate::decoder::output::{frame_json, Channel};
+use crate::decoder::types::Error;
+
+// levels are enabled at compile time, each `log-*` feature implies the less verbose ones,
+// without any of them every log macro folds away to nothing
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(test, derive(Debug))]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+pub const MAX_LEVEL: u8 = if cfg!(feature = "log-debug") {
+    Level::Debug as u8
+} else if cfg!(feature = "log-info") {
+    Level::Info as u8
+} else if cfg!(feature = "log-warn") {
+    Level::Warn as u8
+} else if cfg!(feature = "log-error") {
+    Level::Error as u8
+} else {
+    0
+};
+
+// `diagnostics` builds report every error with the schema row it came from before the
+// run returns its code, other builds only return the code
+pub const DIAGNOSTICS: bool = cfg!(feature = "diagnostics");
+
+#[derive(serde::Serialize)]
+struct Record<'a> {
+    level: &'a str,
+    message: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct Diagnostic<'a> {
+    pub error: u64,
+    pub name: &'static str,
+    // index into the pattern rows once directives are taken out
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    #[serde(rename = "trait", skip_serializing_if = "Option::is_none")]
+    pub trait_name: Option<&'a str>,
+    // the schema row or the DOB/0 value that failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+impl Diagnostic<'_> {
+    pub fn new(error: Error) -> Self {
+        Diagnostic {
+            error: error.code(),
+            name: error.name(),
+            row: None,
+            trait_name: None,
+            value: None,
+        }
+    }
+}
+
+// where records and diagnostics go, a run writes them through its host within the same
+// syscall budget and output limit as the output itself, see `Run`
+pub trait Log {
+    fn write_log(&self, frame: &[u8]);
+}
+
+// a diagnostic frame carrying {"level": ..., "message": ...}
+pub fn record(level: Level, args: Arguments) -> Result<Vec<u8>, Error> {
+    let record = Record {
+        level: level.as_str(),
+        message: format!("{args}"),
+    };
+    frame_json(Channel::Diagnostic, &record)
+}
+
+pub fn log(logger: &dyn Log, level: Level, args: Arguments) {
+    // a record that cannot be serialized is dropped, logging never fails a run
+    if let Ok(record) = record(level, args) {
+        logger.write_log(&record);
+    }
+}
+
+// a diagnostic frame of `diagnostic`, written like the log records
+pub fn diagnose(logger: &dyn Log, diagnostic: &Diagnostic) {
+    if !DIAGNOSTICS {
+        return;
+    }
+    if let Ok(frame) = frame_json(Channel::Diagnostic, diagnostic) {
+        logger.write_log(&frame);
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($logger: expr, $level: expr, $($arg: tt)+) => {
+        if ($level as u8) <= $crate::log::MAX_LEVEL {
+            $crate::log::log($logger, $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($logger: expr, $($arg: tt)+) => {
+        $crate::log!($logger, $crate::log::Level::Error, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($logger: expr, $($arg: tt)+) => {
+        $crate::log!($logger, $crate::log::Level::Warn, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($logger: expr, $($arg: tt)+) => {
+        $crate::log!($logger, $crate::log::Level::Info, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($logger: expr, $($arg: tt)+) => {
+        $crate::log!($logger, $crate::log::Level::Debug, $($arg)+)
+    };
+}
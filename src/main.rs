@@ -2,29 +2,145 @@
 #![no_std]
 
 extern crate alloc;
-use alloc::{borrow::ToOwned, format, vec, vec::Vec};
-use base64::{engine::general_purpose::STANDARD, Engine};
+use alloc::vec::Vec;
+use core::alloc::{GlobalAlloc, Layout};
 use core::ffi::CStr;
-use molecule::prelude::Entity;
-use spore_dob_1::decoder::{
-    dobs_parse_parameters, dobs_parse_syscall_parameters,
-    types::{DOB1Output, Image},
+use core::fmt::Write;
+use core::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use spore_dob_1::decoder::{loader::CellReference, pipeline::dobs_run_host, types::Error};
+use spore_dob_1::host::{Host, SyscallTable};
+
+// 2M unless a `heap-*` feature picks another profile, `SPORE_DOB_1_HEAP_SIZE` sets any
+// size in bytes at build time
+const HEAPS_SIZE: usize = match option_env!("SPORE_DOB_1_HEAP_SIZE") {
+    Some(size) => parse_number(size) as usize,
+    None if cfg!(feature = "heap-8m") => 1024 * 1024 * 8,
+    None if cfg!(feature = "heap-512k") => 1024 * 512,
+    None => 1024 * 1024 * 2,
+};
+
+// the DOB server's numbers unless `SPORE_DOB_1_*_SYSCALL` set others at build time,
+// `--syscall-<name>=<number>` arguments override either at run time
+const SYSCALLS: SyscallTable = SyscallTable {
+    combine: match option_env!("SPORE_DOB_1_COMBINE_SYSCALL") {
+        Some(number) => parse_number(number),
+        None => SyscallTable::DOB_SERVER.combine,
+    },
+    combine_batch: match option_env!("SPORE_DOB_1_COMBINE_BATCH_SYSCALL") {
+        Some(number) => Some(parse_number(number)),
+        None => SyscallTable::DOB_SERVER.combine_batch,
+    },
+    write: match option_env!("SPORE_DOB_1_WRITE_SYSCALL") {
+        Some(number) => parse_number(number),
+        None => SyscallTable::DOB_SERVER.write,
+    },
 };
 
-const HEAPS_SIZE: usize = 1024 * 1024 * 2; // 2M
+static COMBINE_SYSCALL: AtomicU64 = AtomicU64::new(SYSCALLS.combine);
+// zero while the host has no batched combine, see `SyscallTable.combine_batch`
+static COMBINE_BATCH_SYSCALL: AtomicU64 = AtomicU64::new(match SYSCALLS.combine_batch {
+    Some(number) => number,
+    None => 0,
+});
+static WRITE_SYSCALL: AtomicU64 = AtomicU64::new(SYSCALLS.write);
+
+const fn parse_number(number: &str) -> u64 {
+    let digits = number.as_bytes();
+    let mut number = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        assert!(
+            digits[i].is_ascii_digit(),
+            "a SPORE_DOB_1_* build variable is not a number"
+        );
+        number = number * 10 + (digits[i] - b'0') as u64;
+        i += 1;
+    }
+    number
+}
 
 static mut HEAPS: [u8; HEAPS_SIZE] = [0; HEAPS_SIZE];
 #[global_allocator]
-static ALLOC: linked_list_allocator::LockedHeap = linked_list_allocator::LockedHeap::empty();
+static ALLOC: Heap = Heap {
+    heap: linked_list_allocator::LockedHeap::empty(),
+    peak: AtomicUsize::new(0),
+    exhausted: AtomicBool::new(false),
+};
+
+// the allocator with its high-water mark, see `Host::heap_peak`
+struct Heap {
+    heap: linked_list_allocator::LockedHeap,
+    peak: AtomicUsize,
+    // whether the last allocation failed, `alloc_error_handler` is still unstable so the
+    // panic it ends in tells running out of heap apart by this
+    exhausted: AtomicBool,
+}
+
+unsafe impl GlobalAlloc for Heap {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.heap.alloc(layout);
+        // fallible reservations fail here too without a panic, the next allocation that
+        // succeeds clears the flag again
+        self.exhausted.store(ptr.is_null(), Ordering::Relaxed);
+        if !ptr.is_null() {
+            self.peak
+                .fetch_max(self.heap.lock().used(), Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.heap.dealloc(ptr, layout)
+    }
+}
+
+// a panic message cut to what fits, with room left for the null terminator
+struct StackMessage {
+    buffer: [u8; 256],
+    len: usize,
+}
+
+impl Default for StackMessage {
+    fn default() -> Self {
+        StackMessage {
+            buffer: [0; 256],
+            len: 0,
+        }
+    }
+}
+
+impl StackMessage {
+    fn terminated(&mut self) -> &[u8] {
+        self.buffer[self.len] = 0;
+        &self.buffer[..=self.len]
+    }
+}
+
+impl Write for StackMessage {
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        let room = self.buffer.len() - 1 - self.len;
+        let len = text.len().min(room);
+        self.buffer[self.len..self.len + len].copy_from_slice(&text.as_bytes()[..len]);
+        self.len += len;
+        Ok(())
+    }
+}
 
 #[panic_handler]
 fn panic_handler(panic_info: &core::panic::PanicInfo) -> ! {
+    if ALLOC.exhausted.load(Ordering::Relaxed) {
+        syscall_exit(Error::HeapExhausted as u64)
+    }
+    // written whatever the log level, on the stack as the heap may be what's broken
+    let mut message = StackMessage::default();
+    let _ = write!(message, "{panic_info}");
+    syscall_write(message.terminated());
     // If the main thread panics it will terminate all your threads and end your program with code 101.
     // See: https://github.com/rust-lang/rust/blob/master/library/core/src/macros/panic.md
-    syscall_write(&format!("{panic_info:?}").as_bytes().to_vec());
     syscall_exit(101)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn syscall(mut a0: u64, a1: u64, a2: u64, a3: u64, a4: u64, a5: u64, a6: u64, a7: u64) -> u64 {
     unsafe {
         core::arch::asm!(
@@ -44,26 +160,207 @@ fn syscall(mut a0: u64, a1: u64, a2: u64, a3: u64, a4: u64, a5: u64, a6: u64, a7
 
 fn syscall_exit(code: u64) -> ! {
     syscall(code, 0, 0, 0, 0, 0, 0, 93);
-    loop {}
+    loop {
+        core::hint::spin_loop();
+    }
 }
 
-fn syscall_write(buf: &Vec<u8>) -> u64 {
-    syscall(buf.as_ptr() as *const u8 as u64, 0, 0, 0, 0, 0, 0, 2177)
+fn syscall_write(buf: &[u8]) -> u64 {
+    let number = WRITE_SYSCALL.load(Ordering::Relaxed);
+    syscall(buf.as_ptr() as u64, 0, 0, 0, 0, 0, 0, number)
+}
+
+fn syscall_combine_image(buf: &mut [u8], buf_size: &mut u64, molecule_bytes: &[u8]) -> u64 {
+    syscall(
+        buf.as_mut_ptr() as u64,
+        buf_size as *mut u64 as u64,
+        molecule_bytes.as_ptr() as u64,
+        molecule_bytes.len() as u64,
+        0,
+        0,
+        0,
+        COMBINE_SYSCALL.load(Ordering::Relaxed),
+    )
 }
 
-fn syscall_combine_image(buf: &mut Vec<u8>, buf_size: &mut u64, molecule_bytes: &[u8]) -> u64 {
+fn syscall_combine_images(buf: &mut [u8], buf_size: &mut u64, molecule_bytes: &[u8]) -> u64 {
     syscall(
-        buf.as_mut_ptr() as *mut u8 as u64,
+        buf.as_mut_ptr() as u64,
         buf_size as *mut u64 as u64,
-        molecule_bytes.as_ptr() as *const u8 as u64,
+        molecule_bytes.as_ptr() as u64,
         molecule_bytes.len() as u64,
         0,
         0,
         0,
-        2077,
+        COMBINE_BATCH_SYSCALL.load(Ordering::Relaxed),
+    )
+}
+
+// the syscalls of the DOB server, or of whatever host `SYSCALLS` was pointed at
+struct Vm;
+
+impl Host for Vm {
+    fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+        syscall_combine_image(buffer, buffer_size, pattern)
+    }
+
+    fn write(&mut self, output: &[u8]) -> u64 {
+        syscall_write(output)
+    }
+
+    fn combines_batches(&self) -> bool {
+        COMBINE_BATCH_SYSCALL.load(Ordering::Relaxed) != 0
+    }
+
+    fn combine_batch(&mut self, buffer: &mut [u8], buffer_size: &mut u64, patterns: &[u8]) -> u64 {
+        syscall_combine_images(buffer, buffer_size, patterns)
+    }
+
+    fn reads_cells(&self) -> bool {
+        true
+    }
+
+    fn load_cell(&mut self, cell: &CellReference) -> Option<Vec<u8>> {
+        match cell {
+            CellReference::DataHash(hash) => load_cell_dep_data(hash),
+            CellReference::OutPoint(tx_hash, index) => load_out_point_data(tx_hash, *index),
+        }
+    }
+
+    fn heap_peak(&self) -> Option<usize> {
+        Some(ALLOC.peak.load(Ordering::Relaxed))
+    }
+}
+
+const SOURCE_CELL_DEP: u64 = 3;
+const CELL_FIELD_DATA_HASH: u64 = 1;
+const INDEX_OUT_OF_BOUND: u64 = 1;
+// a cell dep without data has no data hash either
+const ITEM_MISSING: u64 = 2;
+
+fn syscall_load_cell_by_field(buf: &mut [u8], index: u64, source: u64, field: u64) -> u64 {
+    let mut len = buf.len() as u64;
+    syscall(
+        buf.as_mut_ptr() as u64,
+        &mut len as *mut u64 as u64,
+        0,
+        index,
+        source,
+        field,
+        0,
+        2081,
     )
 }
 
+fn syscall_load_cell_data(buf: &mut Vec<u8>, index: u64, source: u64) -> u64 {
+    let mut len = buf.len() as u64;
+    let call = |buf: &mut Vec<u8>, len: &mut u64| {
+        syscall(
+            buf.as_mut_ptr() as u64,
+            len as *mut u64 as u64,
+            0,
+            index,
+            source,
+            0,
+            0,
+            2092,
+        )
+    };
+    let code = call(buf, &mut len); // determine real data size
+    if code != 0 {
+        return code;
+    }
+    buf.resize(len as usize, 0);
+    call(buf, &mut len) // fill buffer
+}
+
+fn syscall_load_transaction(buf: &mut Vec<u8>) -> u64 {
+    let mut len = buf.len() as u64;
+    let call = |buf: &mut Vec<u8>, len: &mut u64| {
+        syscall(
+            buf.as_mut_ptr() as u64,
+            len as *mut u64 as u64,
+            0,
+            0,
+            0,
+            0,
+            0,
+            2051,
+        )
+    };
+    let code = call(buf, &mut len); // determine real transaction size
+    if code != 0 {
+        return code;
+    }
+    buf.resize(len as usize, 0);
+    call(buf, &mut len) // fill buffer
+}
+
+// field `index` of a molecule table
+fn table_field(table: &[u8], index: usize) -> Option<&[u8]> {
+    let offset = |index: usize| {
+        let bytes = table.get(4 + index * 4..8 + index * 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?) as usize)
+    };
+    let fields = offset(0)?.checked_sub(4)? / 4;
+    let end = if index + 1 < fields {
+        offset(index + 1)?
+    } else {
+        table.len()
+    };
+    table.get(offset(index)?..end)
+}
+
+// out point (32 bytes tx hash and 4 bytes index) followed by the dep type
+const CELL_DEP_SIZE: usize = 37;
+
+// loads the cell dep whose out point is `tx_hash` and `index`, the position of each
+// cell dep is read from the `raw.cell_deps` of the running transaction
+fn load_out_point_data(tx_hash: &[u8; 32], index: u32) -> Option<Vec<u8>> {
+    let mut transaction = Vec::new();
+    if syscall_load_transaction(&mut transaction) != 0 {
+        return None;
+    }
+    let raw = table_field(&transaction, 0)?;
+    let cell_deps = table_field(raw, 1)?;
+    let position = cell_deps
+        .get(4..)?
+        .chunks_exact(CELL_DEP_SIZE)
+        .position(|cell_dep| {
+            &cell_dep[..32] == tx_hash && cell_dep[32..36] == index.to_le_bytes()
+        })?;
+    let mut data = Vec::new();
+    (syscall_load_cell_data(&mut data, position as u64, SOURCE_CELL_DEP) == 0).then_some(data)
+}
+
+// scans the cell deps for the one whose data hash is `hash`
+fn load_cell_dep_data(hash: &[u8; 32]) -> Option<Vec<u8>> {
+    let mut index = 0;
+    loop {
+        let mut data_hash = [0u8; 32];
+        match syscall_load_cell_by_field(
+            &mut data_hash,
+            index,
+            SOURCE_CELL_DEP,
+            CELL_FIELD_DATA_HASH,
+        ) {
+            0 if &data_hash == hash => {
+                let mut data = Vec::new();
+                return (syscall_load_cell_data(&mut data, index, SOURCE_CELL_DEP) == 0)
+                    .then_some(data);
+            }
+            0 | ITEM_MISSING => index += 1,
+            INDEX_OUT_OF_BOUND => return None,
+            // any other failure ends the search rather than skipping the index
+            _ => return None,
+        }
+    }
+}
+
+/// # Safety
+///
+/// The entry point the VM jumps to, with argc and argv on the stack as CKB lays them out,
+/// nothing else may call it.
 #[no_mangle]
 pub unsafe extern "C" fn _start() {
     core::arch::asm!(
@@ -77,9 +374,12 @@ pub unsafe extern "C" fn _start() {
 }
 
 #[no_mangle]
-unsafe extern "C" fn main(argc: u64, argv: *const *const i8) -> u64 {
+unsafe extern "C" fn main(argc: u64, argv: *const *const core::ffi::c_char) -> u64 {
     unsafe {
-        ALLOC.lock().init(HEAPS.as_mut_ptr(), HEAPS_SIZE);
+        ALLOC
+            .heap
+            .lock()
+            .init(core::ptr::addr_of_mut!(HEAPS).cast::<u8>(), HEAPS_SIZE);
     }
 
     let mut args = Vec::new();
@@ -87,41 +387,14 @@ unsafe extern "C" fn main(argc: u64, argv: *const *const i8) -> u64 {
         let argn = unsafe { CStr::from_ptr(argv.add(i as usize).read()) };
         args.push(argn.to_bytes());
     }
-    let dob_params = match dobs_parse_parameters(args) {
-        Ok(value) => value,
-        Err(err) => return err as u64,
-    };
-    let patterns = match dobs_parse_syscall_parameters(&dob_params) {
-        Ok(value) => value,
+    let syscalls = match SYSCALLS.take_overrides(&mut args) {
+        Ok(syscalls) => syscalls,
         Err(err) => return err as u64,
     };
-    let images = patterns
-        .into_iter()
-        .map(|(name, pattern)| {
-            let mut buffer = vec![];
-            let mut buffer_size = 0u64;
-            syscall_combine_image(&mut buffer, &mut buffer_size, pattern.as_slice()); // determine real buffer size
-            buffer.resize(buffer_size as usize, 0);
-            syscall_combine_image(&mut buffer, &mut buffer_size, pattern.as_slice()); // fill buffer
-            Image {
-                name,
-                type_: "image/png;base64".to_owned(),
-                content: STANDARD.encode(buffer),
-            }
-        })
-        .collect::<Vec<_>>();
-
-    let dob1_output = DOB1Output {
-        traits: dob_params.dob0_output,
-        images,
-    };
-    let mut output = serde_json::to_string(&dob1_output)
-        .expect("Failed to serialize output")
-        .as_bytes()
-        .to_vec();
-    output.push(0);
-    syscall_write(&output);
-    0
+    COMBINE_SYSCALL.store(syscalls.combine, Ordering::Relaxed);
+    COMBINE_BATCH_SYSCALL.store(syscalls.combine_batch.unwrap_or(0), Ordering::Relaxed);
+    WRITE_SYSCALL.store(syscalls.write, Ordering::Relaxed);
+    dobs_run_host(args, &mut Vm)
 }
 
 // #[no_mangle]
@@ -134,21 +407,11 @@ unsafe extern "C" fn main(argc: u64, argv: *const *const i8) -> u64 {
 
 //     let mut buffer = vec![0u8; 1024 * 1024];
 //     let mut buffer_size = 0u64;
-//     let color = generated::Color::new_builder()
-//         .set(b"#FF0000".map(molecule::prelude::Byte::new).to_vec())
-//         .build();
-//     let uri = generated::URI::new_builder()
-//         .set(
-//             b"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0"
-//                 .map(molecule::prelude::Byte::new)
-//                 .to_vec(),
-//         )
-//         .build();
-//     let color_item = generated::Item::new_builder().set(color).build();
-//     let uri_item = generated::Item::new_builder().set(uri).build();
 //     let pattern = generated::ItemVec::new_builder()
-//         .push(color_item)
-//         .push(uri_item)
+//         .push(generated::Item::color("#FF0000"))
+//         .push(generated::Item::uri(
+//             "btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0",
+//         ))
 //         .build();
 //     syscall_combine_image(&mut buffer, &mut buffer_size, pattern.as_slice());
 
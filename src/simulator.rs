@@ -0,0 +1,133 @@
+extern crate std;
+
+use alloc::{format, string::String, vec::Vec};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::decoder::types::{DOB1Output, ERROR_TYPE};
+use crate::host::Host;
+
+// std stand-ins for the syscalls of the binary, so pattern authors can run the whole
+// decoder on a desktop and look at the output before deploying anything
+//
+// the combiner answers every pattern with a transparent 1x1 PNG, together with the
+// `compositor` feature it declines instead and lets the decoder draw the layers itself
+
+pub const PLACEHOLDER_PNG: [u8; 68] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x60, 0x00, 0x02, 0x00,
+    0x00, 0x05, 0x00, 0x01, 0x7a, 0x5e, 0xab, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44,
+    0xae, 0x42, 0x60, 0x82,
+];
+
+pub fn syscall_exit(code: u64) -> ! {
+    std::process::exit(i32::try_from(code).unwrap_or(i32::MAX))
+}
+
+// every chunk goes to stdout on its own line, without the null terminator
+pub fn syscall_write(buf: &[u8]) -> u64 {
+    let buf = buf.strip_suffix(&[0]).unwrap_or(buf);
+    let mut stdout = std::io::stdout().lock();
+    match stdout.write_all(buf).and_then(|_| stdout.write_all(b"\n")) {
+        Ok(()) => 0,
+        Err(_) => 1,
+    }
+}
+
+pub fn syscall_write_log(record: &[u8]) {
+    let record = record.strip_suffix(&[0]).unwrap_or(record);
+    let mut stderr = std::io::stderr().lock();
+    let _ = stderr
+        .write_all(record)
+        .and_then(|_| stderr.write_all(b"\n"));
+}
+
+pub fn syscall_combine_image(buf: &mut [u8], buf_size: &mut u64, _molecule_bytes: &[u8]) -> u64 {
+    if cfg!(feature = "compositor") {
+        return 1;
+    }
+    *buf_size = PLACEHOLDER_PNG.len() as u64;
+    if let Some(buf) = buf.get_mut(..PLACEHOLDER_PNG.len()) {
+        buf.copy_from_slice(&PLACEHOLDER_PNG);
+    }
+    0
+}
+
+// the syscall stand-ins above as a `Host`
+pub struct Simulator;
+
+impl Host for Simulator {
+    fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+        syscall_combine_image(buffer, buffer_size, pattern)
+    }
+
+    fn write(&mut self, output: &[u8]) -> u64 {
+        syscall_write(output)
+    }
+
+    fn write_log(&mut self, frame: &[u8]) -> u64 {
+        syscall_write_log(frame);
+        0
+    }
+}
+
+// the traits as `traits.json` and every image as `<name>.<extension>` in `dir`, base64
+// contents are decoded and failed images are left out, the written paths are returned
+pub fn write_output(dir: &Path, output: &DOB1Output) -> std::io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+    if let Some(traits) = &output.traits {
+        let path = dir.join("traits.json");
+        std::fs::write(
+            &path,
+            serde_json::to_vec_pretty(traits).map_err(invalid_data)?,
+        )?;
+        paths.push(path);
+    }
+    for image in &output.images {
+        if image.type_ == ERROR_TYPE || image.error.is_some() {
+            continue;
+        }
+        let content = match image.type_.strip_suffix(";base64") {
+            Some(_) => STANDARD.decode(&image.content).map_err(invalid_data)?,
+            None => image.content.clone().into_bytes(),
+        };
+        let path = dir.join(format!(
+            "{}.{}",
+            file_name(&image.name),
+            extension(&image.type_)
+        ));
+        std::fs::write(&path, content)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+fn invalid_data<E>(_: E) -> std::io::Error {
+    std::io::ErrorKind::InvalidData.into()
+}
+
+// group names are free text, anything but a plain name character becomes `_`
+fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn extension(type_: &str) -> &str {
+    let mime = type_.split(';').next().unwrap_or_default();
+    match mime {
+        "image/svg+xml" => "svg",
+        "text/uri-list" => "txt",
+        "image/jpeg" => "jpg",
+        _ => mime
+            .strip_prefix("image/")
+            .filter(|subtype| subtype.bytes().all(|byte| byte.is_ascii_alphanumeric()))
+            .unwrap_or("bin"),
+    }
+}
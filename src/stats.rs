@@ -0,0 +1,62 @@
+use core::cell::Cell;
+
+// counters are only collected in builds with the `trace` feature, otherwise every call
+// is a no-op and `snapshot` stays all zero
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+#[derive(Clone, Copy)]
+pub enum Counter {
+    SchemasParsed,
+    LayersMatched,
+    CombineSyscalls,
+    OutputBytes,
+    WriteSyscalls,
+    SyscallBudget,
+}
+
+#[cfg_attr(test, derive(Debug))]
+#[derive(serde::Serialize, Default, Clone, Copy)]
+pub struct Stats {
+    pub schemas_parsed: u64,
+    pub layers_matched: u64,
+    pub combine_syscalls: u64,
+    pub write_syscalls: u64,
+    pub syscall_budget: u64,
+    pub peak_heap: u64,
+    pub output_bytes: u64,
+}
+
+// the counters of one run, see `Run`
+#[derive(Default)]
+pub struct Counters {
+    counts: [Cell<u64>; 6],
+    peak_heap: Cell<u64>,
+}
+
+impl Counters {
+    pub fn add(&self, counter: Counter, value: u64) {
+        if ENABLED {
+            let count = &self.counts[counter as usize];
+            count.set(count.get().saturating_add(value));
+        }
+    }
+
+    pub fn record_heap(&self, used: usize) {
+        if ENABLED {
+            self.peak_heap.set(self.peak_heap.get().max(used as u64));
+        }
+    }
+
+    pub fn snapshot(&self) -> Stats {
+        let counter = |counter: Counter| self.counts[counter as usize].get();
+        Stats {
+            schemas_parsed: counter(Counter::SchemasParsed),
+            layers_matched: counter(Counter::LayersMatched),
+            combine_syscalls: counter(Counter::CombineSyscalls),
+            write_syscalls: counter(Counter::WriteSyscalls),
+            syscall_budget: counter(Counter::SyscallBudget),
+            peak_heap: self.peak_heap.get(),
+            output_bytes: counter(Counter::OutputBytes),
+        }
+    }
+}
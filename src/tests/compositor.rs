@@ -0,0 +1,93 @@
+use alloc::{borrow::ToOwned, format, vec::Vec};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::decoder::{
+    compositor::{compose_png, decode_png},
+    types::{Error, ImageType, Layer, Size},
+};
+
+fn layer(type_: ImageType, value: &str) -> Layer {
+    Layer {
+        type_,
+        value: value.to_owned(),
+        hash: None,
+        transform: None,
+        style: None,
+        animation: None,
+        fill: None,
+    }
+}
+
+#[test]
+fn test_compose_colors_blend() {
+    let size = Some(Size {
+        width: 2,
+        height: 2,
+    });
+    let layers = [
+        layer(ImageType::ColorCode, "#0000FF"),
+        layer(ImageType::ColorCode, "#FF000080"),
+    ];
+    let png = compose_png(&layers, size).expect("compose");
+    let bitmap = decode_png(&png).expect("decode");
+    assert_eq!((bitmap.width, bitmap.height), (2, 2));
+    assert_eq!(&bitmap.pixels[..4], &[128, 0, 127, 255]);
+    assert_eq!(&bitmap.pixels[12..], &[128, 0, 127, 255]);
+}
+
+#[test]
+fn test_compose_data_uri_layers() {
+    let small = compose_png(
+        &[layer(ImageType::ColorCode, "#0F0")],
+        Some(Size {
+            width: 1,
+            height: 1,
+        }),
+    )
+    .unwrap();
+    let base = compose_png(
+        &[layer(ImageType::ColorCode, "#FFFFFF")],
+        Some(Size {
+            width: 2,
+            height: 1,
+        }),
+    )
+    .unwrap();
+    let layers = [
+        layer(ImageType::RawImage, &STANDARD.encode(base)),
+        layer(
+            ImageType::URI,
+            &format!("data:image/png;base64,{}", STANDARD.encode(small)),
+        ),
+    ];
+    let bitmap = decode_png(&compose_png(&layers, None).unwrap()).unwrap();
+    assert_eq!((bitmap.width, bitmap.height), (2, 1));
+    assert_eq!(bitmap.pixels, [0, 255, 0, 255, 255, 255, 255, 255]);
+
+    let layers = [layer(ImageType::URI, "btcfs://remotei0")];
+    assert!(matches!(
+        compose_png(&layers, None),
+        Err(Error::ComposeUnsupportedLayer)
+    ));
+}
+
+#[test]
+fn test_decode_palette_png() {
+    // 2x1, 1 bit palette indices [1, 0] where entry 1 is half transparent red
+    let raw = [0u8, 0b1000_0000];
+    let idat = miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6);
+    let mut png = Vec::from([0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+    let mut chunk = |kind: &[u8], data: &[u8]| {
+        png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        png.extend_from_slice(kind);
+        png.extend_from_slice(data);
+        png.extend_from_slice(&[0; 4]);
+    };
+    chunk(b"IHDR", &[0, 0, 0, 2, 0, 0, 0, 1, 1, 3, 0, 0, 0]);
+    chunk(b"PLTE", &[0, 0, 0, 255, 0, 0]);
+    chunk(b"tRNS", &[255, 128]);
+    chunk(b"IDAT", &idat);
+    chunk(b"IEND", &[]);
+    let bitmap = decode_png(&png).expect("decode");
+    assert_eq!(bitmap.pixels, [255, 0, 0, 128, 0, 0, 0, 255]);
+}
@@ -1,63 +1,82 @@
-use alloc::vec::Vec;
+use alloc::{borrow::ToOwned, collections::BTreeSet, format, vec::Vec};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use molecule::prelude::{Builder, Byte, Entity, Reader};
 use serde_json::Value;
 
 use crate::decoder::{
-    decode_trait_schema, dobs_parse_parameters, dobs_parse_syscall_parameters,
-    types::{ImageType, Pattern, TraitSchema},
+    args::{decode_hex_arg, parse_parameters},
+    build_item_vec, cached_fingerprint, compose_svg, decode_trait_schema, dobs_decode,
+    dobs_display_traits, dobs_parse_parameters, dobs_parse_syscall_parameters, dobs_resolve_layers,
+    dobs_rng, encode_trait_schema, hex_trait_as_number,
+    input::MOLECULE_TAG,
+    layer_sources,
+    loader::CellReference,
+    parse_gradient,
+    pattern_builder::{encode_molecule_rows, PatternBuilder},
+    pattern_stats,
+    pipeline::Run,
+    rng::Rng,
+    truncate_layers,
+    types::{
+        DisplayTrait, Endian, Error, Gradient, GradientKind, ImageMetadata, ImageType, OutputMode,
+        ParsedTrait, Pattern, Preview, PreviewKeep, SafeArea, SchemaOptions, Shape, TraitSchema,
+    },
+    MAX_RAW_IMAGE_BYTES,
 };
+use crate::generated::{self, ItemUnion};
+use crate::host::Host;
 
-impl TraitSchema {
-    pub fn new(
-        name: &str,
-        type_: ImageType,
-        dob0_trait: &str,
-        pattern: Pattern,
-        args: Option<Value>,
-    ) -> Self {
-        Self {
-            name: name.to_owned(),
-            type_,
-            dob0_trait: dob0_trait.to_owned(),
-            pattern,
-            args,
-        }
-    }
-
-    pub fn encode(&self) -> Vec<Value> {
-        let mut values = vec![
-            Value::String(self.name.clone()),
-            Value::String(match self.type_ {
-                ImageType::ColorCode => "color".to_owned(),
-                ImageType::URI => "uri".to_owned(),
-                ImageType::RawImage => "raw".to_owned(),
-            }),
-            Value::String(self.dob0_trait.clone()),
-            Value::String(match self.pattern {
-                Pattern::Options => "options".to_owned(),
-                Pattern::Range => "range".to_owned(),
-                Pattern::Raw => "raw".to_owned(),
-            }),
-        ];
-        if let Some(args) = &self.args {
-            values.push(args.clone());
-        }
-        values
-    }
-}
+// generated from `test_generate_basic_example` case
+pub(super) const DOB0_OUTPUT: &str = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Age\",\"traits\":[{\"Number\":23}]},{\"name\":\"Score\",\"traits\":[{\"Number\":136}]},{\"name\":\"DNA\",\"traits\":[{\"String\":\"0xaabbcc\"}]},{\"name\":\"URL\",\"traits\":[{\"String\":\"http://127.0.0.1:8090\"}]},{\"name\":\"Value\",\"traits\":[{\"Number\":13417386}]}]";
+pub(super) const IMAGES_BASE: &str = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Alice\",\"#0000FF\"],[\"Bob\",\"#00FF00\"],[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]],[\"0\",\"uri\",\"Age\",\"range\",[[[0,50],\"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0\"],[[51,100],\"btcfs://eb3910b3e32a5ed9460bd0d75168c01ba1b8f00cc0faf83e4d8b67b48ea79676i0\"],[[\"*\"],\"btcfs://11b6303eb7d887d7ade459ac27959754cd55f9f9e50345ced8e1e8f47f4581fai0\"]]],[\"0\",\"uri\",\"Score\",\"range\",[[[0,1000],\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\"],[[\"*\"],\"btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0\"]]],[\"1\",\"uri\",\"Value\",\"range\",[[[0,100000],\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\"],[[\"*\"],\"btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0\"]]]]";
 
 #[test]
 fn test_parse_syscall_parameters() {
-    // generated from `test_generate_basic_example` case
-    let dob0_output = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Age\",\"traits\":[{\"Number\":23}]},{\"name\":\"Score\",\"traits\":[{\"Number\":136}]},{\"name\":\"DNA\",\"traits\":[{\"String\":\"0xaabbcc\"}]},{\"name\":\"URL\",\"traits\":[{\"String\":\"http://127.0.0.1:8090\"}]},{\"name\":\"Value\",\"traits\":[{\"Number\":13417386}]}]";
-    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Alice\",\"#0000FF\"],[\"Bob\",\"#00FF00\"],[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]],[\"0\",\"uri\",\"Age\",\"range\",[[[0,50],\"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0\"],[[51,100],\"btcfs://eb3910b3e32a5ed9460bd0d75168c01ba1b8f00cc0faf83e4d8b67b48ea79676i0\"],[[\"*\"],\"btcfs://11b6303eb7d887d7ade459ac27959754cd55f9f9e50345ced8e1e8f47f4581fai0\"]]],[\"0\",\"uri\",\"Score\",\"range\",[[[0,1000],\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\"],[[\"*\"],\"btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0\"]]],[\"1\",\"uri\",\"Value\",\"range\",[[[0,100000],\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\"],[[\"*\"],\"btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0\"]]]]";
-
-    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
     let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
     let syscall_parameters =
         dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
     println!("{:?}", syscall_parameters);
 }
 
+#[test]
+fn test_traits_only_resolve_layers() {
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        IMAGES_BASE.as_bytes(),
+        "--traits-only".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert!(parameters.config.mode() == OutputMode::Traits);
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let layers = serde_json::to_string(&layer_groups).unwrap();
+    assert_eq!(
+        layers,
+        "[{\"name\":\"0\",\"layers\":[{\"type\":\"color\",\"value\":\"#FF0000\"},{\"type\":\"uri\",\"value\":\"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0\"},{\"type\":\"uri\",\"value\":\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\"}]},{\"name\":\"1\",\"layers\":[{\"type\":\"uri\",\"value\":\"btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0\"}]}]"
+    );
+
+    // the flag is the traits mode, over the pattern's directive but not against the config
+    let images_base = format!("[[\"mode\",\"images\"],{}", &IMAGES_BASE[1..]);
+    let mode = |config: &str| {
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+            "--traits-only".as_bytes(),
+        ];
+        dobs_parse_parameters(args).map(|parameters| parameters.config.mode())
+    };
+    assert!(matches!(mode("{}"), Ok(OutputMode::Traits)));
+    assert!(matches!(
+        mode("{\"mode\":\"traits\"}"),
+        Ok(OutputMode::Traits)
+    ));
+    assert!(matches!(
+        mode("{\"mode\":\"images\"}"),
+        Err(Error::ParseInvalidFlag)
+    ));
+}
+
 // use `test_generate_basic_example` test case in spore-dob-0 repo to generate the following test
 #[test]
 fn test_basic_trait_schema_encode_decode() {
@@ -97,3 +116,2270 @@ fn test_basic_trait_schema_encode_decode() {
     let decoded = decode_trait_schema(encoded).expect("decode");
     assert_eq!(traits, decoded);
 }
+
+#[test]
+fn test_layer_sources_with_content_hash() {
+    let dob0_output = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]}]";
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\",\"0xff\"]]],[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"btcfs://aai0\",\"0x1234\"]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let sources = layer_sources(&layer_groups[0].layers);
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].uri, "btcfs://aai0");
+    assert_eq!(sources[0].hash, "0x1234");
+}
+
+#[test]
+fn test_cached_fingerprint_requires_every_layer() {
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        IMAGES_BASE.as_bytes(),
+        "{\"cache\":{\"layers\":{\"#FF0000\":\"a\",\"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0\":\"b\",\"btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0\":\"c\"},\"composed\":{\"a,b,c\":\"abc\"}}}".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let cache = parameters.config.cache.as_ref().expect("cache");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(
+        cached_fingerprint(cache, &layer_groups[0].layers).map(String::as_str),
+        Some("abc")
+    );
+    assert_eq!(cached_fingerprint(cache, &layer_groups[1].layers), None);
+}
+
+#[test]
+fn test_preview_truncate_layers() {
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        IMAGES_BASE.as_bytes(),
+        "{\"preview\":{\"layers\":1}}".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let preview = parameters.config.preview.as_ref().expect("preview");
+    let mut layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert!(truncate_layers(&mut layer_groups[0].layers, preview));
+    assert_eq!(layer_groups[0].layers.len(), 1);
+    assert_eq!(
+        layer_groups[0].layers[0].value,
+        "btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0"
+    );
+    assert!(!truncate_layers(&mut layer_groups[1].layers, preview));
+
+    let preview = Preview {
+        layers: 1,
+        keep: PreviewKeep::Base,
+    };
+    let mut layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert!(truncate_layers(&mut layer_groups[0].layers, &preview));
+    assert_eq!(layer_groups[0].layers[0].value, "#FF0000");
+}
+
+#[test]
+fn test_thumbnail_size_leading_item() {
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        IMAGES_BASE.as_bytes(),
+        "{\"thumbnail\":{\"width\":64,\"height\":32}}".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let (_, items) = &syscall_parameters[0];
+    assert_eq!(items.len(), 4);
+    let ItemUnion::OutputSize(size) = items.get(0).unwrap().to_enum() else {
+        panic!("leading item should be OutputSize");
+    };
+    assert_eq!(size.width().as_slice(), 64u32.to_le_bytes());
+    assert_eq!(size.height().as_slice(), 32u32.to_le_bytes());
+}
+
+#[test]
+fn test_image_metadata_roundtrip_and_merge() {
+    let metadata = ImageMetadata {
+        aspect_ratio: Some([16, 9]),
+        safe_area: Some(SafeArea {
+            x: 0.25,
+            y: 0.0,
+            width: 0.5,
+            height: 1.0,
+        }),
+        shape: Some(Shape::Circle),
+        mask: true,
+        ..Default::default()
+    };
+    let traits = vec![
+        TraitSchema::new("0", ImageType::URI, "URL", Pattern::Raw, None),
+        TraitSchema::new("0", ImageType::URI, "URL", Pattern::Raw, None).with_options(
+            SchemaOptions {
+                metadata,
+                alt: Some("{Name}, age {Age}".to_owned()),
+                ..Default::default()
+            },
+        ),
+    ];
+    let encoded = traits.iter().map(TraitSchema::encode).collect::<Vec<_>>();
+    let decoded = decode_trait_schema(encoded).expect("decode");
+    assert_eq!(traits, decoded);
+
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"aspect_ratio\":[16,9]}]]".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(layer_groups[0].metadata.aspect_ratio, Some([16, 9]));
+    assert_eq!(
+        serde_json::to_string(&layer_groups).unwrap(),
+        "[{\"name\":\"0\",\"layers\":[{\"type\":\"uri\",\"value\":\"http://127.0.0.1:8090\"}],\"aspect_ratio\":[16,9]}]"
+    );
+
+    let invalid = serde_json::from_str("[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"safe_area\":{\"x\":0.8,\"y\":0,\"width\":0.5,\"height\":1}}]]").unwrap();
+    assert!(matches!(
+        decode_trait_schema(invalid),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_render_alt_text_template() {
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"alt\":\"{Name}, age {Age}, score {Score}{Missing} {\"}]]".as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(
+        layer_groups[0].alt.as_deref(),
+        Some("Ethan, age 23, score 136 {")
+    );
+}
+
+#[test]
+fn test_theme_variant_selection() {
+    let images_base = "[[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",{\"default\":\"btcfs://lighti0\",\"dark\":\"btcfs://darki0\"}]]]]";
+    let resolve = |config: &str| {
+        let mut args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        if !config.is_empty() {
+            args.push(config.as_bytes());
+        }
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    assert_eq!(resolve(""), "btcfs://lighti0");
+    assert_eq!(resolve("{\"theme\":\"dark\"}"), "btcfs://darki0");
+    assert_eq!(resolve("{\"theme\":\"sepia\"}"), "btcfs://lighti0");
+}
+
+#[test]
+fn test_animated_variant_selection() {
+    let images_base = "[[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",{\"default\":\"btcfs://pngi0\",\"animated\":\"btcfs://gifi0\",\"dark\":\"btcfs://darki0\",\"dark:animated\":\"btcfs://darkgifi0\"}]]]]";
+    let resolve = |config: &str| {
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    assert_eq!(resolve("{}"), "btcfs://pngi0");
+    assert_eq!(resolve("{\"animated\":true}"), "btcfs://gifi0");
+    assert_eq!(
+        resolve("{\"animated\":true,\"theme\":\"dark\"}"),
+        "btcfs://darkgifi0"
+    );
+    assert_eq!(
+        resolve("{\"animated\":true,\"theme\":\"light\"}"),
+        "btcfs://gifi0"
+    );
+}
+
+#[test]
+fn test_display_traits_with_i18n_directive() {
+    let images_base = "[[\"i18n\",{\"zh\":{\"Name\":{\"name\":\"名字\",\"values\":{\"Ethan\":\"伊森\"}},\"Age\":{\"name\":\"年龄\"}}}],[\"0\",\"uri\",\"URL\",\"raw\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert_eq!(parameters.images_base.len(), 1);
+    let display = dobs_display_traits(&parameters, "zh");
+    assert_eq!(
+        display[..2],
+        [
+            DisplayTrait {
+                name: "名字".to_owned(),
+                value: "伊森".to_owned()
+            },
+            DisplayTrait {
+                name: "年龄".to_owned(),
+                value: "23".to_owned()
+            }
+        ]
+    );
+    assert_eq!(dobs_display_traits(&parameters, "fr")[0].name, "Name");
+
+    let args = vec![DOB0_OUTPUT.as_bytes(), "[[\"unknown\",{}]]".as_bytes()];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidDirective)
+    ));
+}
+
+#[test]
+fn test_schema_version_header() {
+    let images_base = format!("[[\"dob1\",1],{}", &IMAGES_BASE[1..]);
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert_eq!(parameters.directives.version, Some(1));
+    assert_eq!(parameters.images_base.len(), 4);
+    let rows: Vec<Vec<Value>> = serde_json::from_str(&images_base).unwrap();
+    assert_eq!(decode_trait_schema(rows).unwrap().len(), 4);
+
+    for (header, error) in [
+        ("[\"dob1\",2]", Error::SchemaUnsupportedVersion),
+        ("[\"dob1\",0]", Error::SchemaInvalidDirective),
+        ("[\"dob1\",\"1\"]", Error::SchemaInvalidDirective),
+    ] {
+        let images_base = format!("[{header},{}", &IMAGES_BASE[1..]);
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let code = dobs_parse_parameters(args).err().map(|err| err as u64);
+        assert_eq!(code, Some(error as u64));
+    }
+    // only the first row is a header
+    let images_base = format!("{},[\"dob1\",1]]", &IMAGES_BASE[..IMAGES_BASE.len() - 1]);
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidDirective)
+    ));
+}
+
+#[test]
+fn test_locale_flag_translates_traits() {
+    let images_base = "[[\"i18n\",{\"zh\":{\"Name\":{\"name\":\"名字\",\"values\":{\"Ethan\":\"伊森\"}},\"Age\":{\"name\":\"年龄\"}}}],[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]]]";
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+        *buffer_size = 3;
+        buffer[..3].copy_from_slice(b"png");
+        0
+    };
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        "--locale=zh".as_bytes(),
+    ];
+    let output = dobs_decode(args, combiner).expect("decode failed");
+    // the options row still matched the canonical value
+    assert_eq!(output.images.len(), 1);
+    let traits = output.traits.unwrap();
+    assert_eq!(traits[0].name, "名字");
+    assert!(matches!(&traits[0].traits[..], [ParsedTrait::String(value)] if value == "伊森"));
+    assert_eq!(traits[1].name, "年龄");
+    assert!(matches!(traits[1].traits[..], [ParsedTrait::Number(23)]));
+    assert_eq!(traits[2].name, "Score");
+
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        "--locale=".as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::ParseInvalidFlag)
+    ));
+}
+
+#[test]
+fn test_traits_directive() {
+    let images_base = "[[\"traits\",{\"hide\":[\"DNA\"],\"rename\":{\"Name\":\"Character\"}}],[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(layer_groups[0].layers.len(), 1);
+    let display = dobs_display_traits(&parameters, "en");
+    assert_eq!(display.len(), 5);
+    assert_eq!(display[0].name, "Character");
+    assert!(display.iter().all(|display| display.name != "DNA"));
+
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let output = dobs_decode(args, |_, buffer_size, _| {
+        *buffer_size = 0;
+        0
+    })
+    .expect("decode failed");
+    let names = output
+        .traits
+        .unwrap()
+        .into_iter()
+        .map(|output| output.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Character", "Age", "Score", "URL", "Value"]);
+
+    let images_base = "[[\"traits\",{\"only\":[\"Age\",\"URL\"],\"hide\":[\"URL\"]}],[\"0\",\"uri\",\"URL\",\"raw\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let display = dobs_display_traits(&parameters, "en");
+    assert_eq!(display.len(), 1);
+    assert_eq!(display[0].name, "Age");
+
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"traits\",{\"hide\":\"DNA\"}]]".as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidDirective)
+    ));
+}
+
+#[test]
+fn test_synthesized_traits() {
+    let images_base = "[[\"0\",\"uri\",\"Age\",\"range\",[[[0,50],\"btcfs://young\"],[[\"*\"],\"btcfs://old\"]],{\"as_trait\":\"Background Asset\"}],[\"0\",\"uri\",\"URL\",\"raw\",null,{\"as_trait\":\"Ignored\"}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let output = dobs_decode(args, |_, buffer_size, _| {
+        *buffer_size = 0;
+        0
+    })
+    .expect("decode failed");
+    let traits = output.traits.unwrap();
+    // raw rows pick nothing, so only the range row adds a trait
+    assert_eq!(traits.len(), 7);
+    assert_eq!(traits[6].name, "Background Asset");
+    assert!(
+        matches!(traits[6].traits.as_slice(), [ParsedTrait::String(value)] if value == "btcfs://young")
+    );
+}
+
+#[test]
+fn test_shape_mask_item() {
+    let images_base = "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"shape\":{\"rounded_rect\":12},\"mask\":true}],[\"1\",\"uri\",\"URL\",\"raw\",null,{\"shape\":\"circle\"}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(
+        serde_json::to_string(&layer_groups[1].metadata).unwrap(),
+        "{\"shape\":\"circle\"}"
+    );
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let ItemUnion::Mask(mask) = syscall_parameters[0].1.get(0).unwrap().to_enum() else {
+        panic!("leading item should be Mask");
+    };
+    assert_eq!(mask.shape().as_slice(), [2]);
+    assert_eq!(mask.radius().as_slice(), 12u32.to_le_bytes());
+    assert_eq!(syscall_parameters[1].1.len(), 1);
+}
+
+#[test]
+fn test_gradient_color_item() {
+    assert_eq!(
+        parse_gradient("linear:#FF0000, #0000FF,45deg")
+            .ok()
+            .flatten(),
+        Some(Gradient {
+            kind: GradientKind::Linear,
+            angle: 45,
+            stops: vec!["#FF0000".to_owned(), "#0000FF".to_owned()],
+        })
+    );
+    assert_eq!(
+        parse_gradient("radial:#FFF,#000,#FFF")
+            .ok()
+            .flatten()
+            .map(|g| g.angle),
+        Some(0)
+    );
+    assert!(matches!(parse_gradient("#FF0000"), Ok(None)));
+    for value in [
+        "linear:#FF0000",
+        "linear:#FF0000,blue",
+        "linear:#FF0000,#0000FF,360deg",
+        "radial:#FF0000,#0000FF,45deg",
+    ] {
+        assert!(matches!(
+            parse_gradient(value),
+            Err(Error::DecodeBadColorCodeFormat)
+        ));
+    }
+
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"linear:#FF0000,#0000FF\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let ItemUnion::Gradient(gradient) = syscall_parameters[0].1.get(0).unwrap().to_enum() else {
+        panic!("item should be Gradient");
+    };
+    assert_eq!(gradient.kind().as_slice(), [0]);
+    assert_eq!(gradient.angle().as_slice(), 180u32.to_le_bytes());
+    assert_eq!(gradient.stops().len(), 2);
+    assert_eq!(
+        gradient.stops().get(1).unwrap().raw_data(),
+        b"#0000FF".as_slice()
+    );
+
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"linear:#FF0000\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert!(matches!(
+        dobs_resolve_layers(&parameters),
+        Err(Error::DecodeBadColorCodeFormat)
+    ));
+}
+
+#[test]
+fn test_layer_value_checks() {
+    let resolve = |type_: &str, value: &str, config: &str| {
+        let images_base = format!("[[\"0\",\"{type_}\",\"*\",\"raw\",\"{value}\"]]");
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|_| ())
+    };
+    assert!(resolve("color", "#FFF", "{}").is_ok());
+    assert!(resolve("color", "#FFFFFF80", "{}").is_ok());
+    assert!(matches!(
+        resolve("color", "red", "{}"),
+        Err(Error::DecodeBadColorCodeFormat)
+    ));
+    assert!(matches!(
+        resolve("color", "#FFFF", "{}"),
+        Err(Error::DecodeBadColorCodeFormat)
+    ));
+    assert!(resolve("uri", "ckbfs://cell", "{}").is_ok());
+    // any scheme passes unless the config lists the allowed ones
+    assert!(resolve("uri", "http://127.0.0.1", "{}").is_ok());
+    let config = "{\"uri_schemes\":[\"https\",\"ipfs\"]}";
+    assert!(resolve("uri", "ipfs://cid", config).is_ok());
+    assert!(matches!(
+        resolve("uri", "http://127.0.0.1", config),
+        Err(Error::DecodeUnsupportedURIScheme)
+    ));
+    assert!(resolve("image", "iVBORw0KGgo=", "{}").is_ok());
+    assert!(resolve("image", "0x89504e47", "{}").is_ok());
+    assert!(resolve("image", "data:image/png;base64,iVBORw0KGgo=", "{}").is_ok());
+    for value in ["not an image", "data:image/png,iVBORw0KGgo=", ""] {
+        assert!(matches!(
+            resolve("image", value, "{}"),
+            Err(Error::DecodeInvalidRawValue)
+        ));
+    }
+}
+
+#[test]
+fn test_layer_z_order() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#000001\",{\"z\":1}],[\"0\",\"color\",\"*\",\"raw\",\"#000002\"],[\"0\",\"color\",\"*\",\"raw\",\"#000003\",{\"z\":-1}],[\"0\",\"color\",\"*\",\"raw\",\"#000004\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = layer_groups[0]
+        .layers
+        .iter()
+        .map(|layer| layer.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["#000003", "#000002", "#000004", "#000001"]);
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let ItemUnion::Color(color) = syscall_parameters[0].1.get(0).unwrap().to_enum() else {
+        panic!("item should be Color");
+    };
+    assert_eq!(color.raw_data(), b"#000003".as_slice());
+}
+
+#[test]
+fn test_layer_transform_item() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"uri\",\"URL\",\"raw\",null,{\"transform\":{\"x\":10,\"y\":-20,\"scale\":0.5,\"rotation\":-90,\"opacity\":0.8}}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let items = &syscall_parameters[0].1;
+    assert_eq!(items.len(), 3);
+    let ItemUnion::Transform(transform) = items.get(1).unwrap().to_enum() else {
+        panic!("item before the placed layer should be Transform");
+    };
+    assert_eq!(transform.x().as_slice(), 10i32.to_le_bytes());
+    assert_eq!(transform.y().as_slice(), (-20i32).to_le_bytes());
+    assert_eq!(transform.scale().as_slice(), 500u32.to_le_bytes());
+    assert_eq!(transform.rotation().as_slice(), 270u32.to_le_bytes());
+    assert_eq!(transform.opacity().as_slice(), [204]);
+    assert!(matches!(items.get(2).unwrap().to_enum(), ItemUnion::URI(_)));
+
+    for transform in ["{\"scale\":0}", "{\"opacity\":1.5}"] {
+        let images_base =
+            format!("[[\"0\",\"uri\",\"URL\",\"raw\",null,{{\"transform\":{transform}}}]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidOptions)
+        ));
+    }
+}
+
+#[test]
+fn test_uri_rewrite_rules() {
+    let uri_of = |images_base: &str, config: &str| {
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let syscall_parameters =
+            dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+        let ItemUnion::URI(uri) = syscall_parameters[0].1.get(0).unwrap().to_enum() else {
+            panic!("item should be URI");
+        };
+        String::from_utf8(uri.raw_data().to_vec()).unwrap()
+    };
+    let images_base = "[[\"rewrite\",{\"btcfs://\":\"https://ordinals.example/content/\",\"btcfs://aa\":\"https://aa.example/\"}],[\"0\",\"uri\",\"*\",\"raw\",\"btcfs://aabb\"]]";
+    // the longest matching prefix wins
+    assert_eq!(uri_of(images_base, "{}"), "https://aa.example/bb");
+    // the config overrides the pattern's rules per prefix
+    let config = "{\"rewrite\":{\"btcfs://aa\":\"https://gateway.example/\"}}";
+    assert_eq!(uri_of(images_base, config), "https://gateway.example/bb");
+    let images_base = "[[\"0\",\"uri\",\"*\",\"raw\",\"btcfs://aabb\"]]";
+    assert_eq!(uri_of(images_base, "{}"), "btcfs://aabb");
+    let config = "{\"rewrite\":{\"btcfs://\":\"ipfs://\"}}";
+    assert_eq!(uri_of(images_base, config), "ipfs://aabb");
+
+    for rewrite in ["{\"\":\"https://\"}", "{\"btcfs://\":1}", "[]"] {
+        let images_base =
+            format!("[[\"rewrite\",{rewrite}],[\"0\",\"uri\",\"*\",\"raw\",\"btcfs://aabb\"]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidDirective)
+        ));
+    }
+    let config = "{\"rewrite\":{\"\":\"https://\"}}";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        config.as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::ParseInvalidConfig)
+    ));
+}
+
+#[test]
+fn test_layer_fill_item() {
+    let images_base = "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"tile\":true}],[\"0\",\"uri\",\"URL\",\"raw\",null,{\"slice\":[8,16,8,16]}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let items = &syscall_parameters[0].1;
+    assert_eq!(items.len(), 4);
+    let ItemUnion::Fill(tile) = items.get(0).unwrap().to_enum() else {
+        panic!("item before the tiled layer should be Fill");
+    };
+    assert_eq!(tile.mode().as_slice(), [0]);
+    let ItemUnion::Fill(slice) = items.get(2).unwrap().to_enum() else {
+        panic!("item before the sliced layer should be Fill");
+    };
+    assert_eq!(slice.mode().as_slice(), [1]);
+    assert_eq!(slice.top().as_slice(), 8u32.to_le_bytes());
+    assert_eq!(slice.right().as_slice(), 16u32.to_le_bytes());
+    assert_eq!(slice.left().as_slice(), 16u32.to_le_bytes());
+    assert!(matches!(items.get(3).unwrap().to_enum(), ItemUnion::URI(_)));
+
+    for row in [
+        "[\"0\",\"uri\",\"URL\",\"raw\",null,{\"tile\":true,\"slice\":[1,1,1,1]}]",
+        "[\"0\",\"uri\",\"URL\",\"raw\",null,{\"tile\":true,\"transform\":{\"x\":1}}]",
+        "[\"0\",\"uri\",\"URL\",\"raw\",null,{\"slice\":[1,1,1]}]",
+        "[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\",{\"tile\":true}]",
+    ] {
+        let images_base = format!("[{row}]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidOptions)
+        ));
+    }
+}
+
+#[test]
+fn test_text_layer() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"text\",\"Name\",\"template\",\"{Name} <{Age}>\",{\"text\":{\"y\":0.875,\"size\":0.05,\"color\":\"#FF0000\"}}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let ItemUnion::Text(text) = syscall_parameters[0].1.get(1).unwrap().to_enum() else {
+        panic!("text layer should be a Text item");
+    };
+    assert_eq!(text.content().raw_data().as_ref(), b"Ethan <23>");
+    assert_eq!(text.x().as_slice(), 500u32.to_le_bytes());
+    assert_eq!(text.y().as_slice(), 875u32.to_le_bytes());
+    assert_eq!(text.size().as_slice(), 50u32.to_le_bytes());
+    assert_eq!(text.color().raw_data().as_ref(), b"#FF0000");
+
+    let groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert!(compose_svg(&groups[0]).ends_with(
+        "<text x=\"0.5\" y=\"0.875\" font-size=\"0.05\" fill=\"#FF0000\" text-anchor=\"middle\" dominant-baseline=\"middle\">Ethan &lt;23&gt;</text></svg>"
+    ));
+
+    for row in [
+        "[\"0\",\"text\",\"Name\",\"raw\",null,{\"text\":{\"size\":0}}]",
+        "[\"0\",\"text\",\"Name\",\"raw\",null,{\"text\":{\"color\":\"red\"}}]",
+        "[\"0\",\"uri\",\"URL\",\"raw\",null,{\"text\":{}}]",
+    ] {
+        let images_base = format!("[{row}]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidOptions)
+        ));
+    }
+}
+
+#[test]
+fn test_raw_image_values() {
+    let resolve = |value: &str, config: &str| {
+        let images_base = format!("[[\"0\",\"image\",\"*\",\"raw\",\"{value}\"]]");
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers[0].value.clone())
+    };
+    // hex comes out as base64, base64 and data URIs as they are
+    assert_eq!(resolve("0x89504e47", "{}").unwrap(), "iVBORw==");
+    assert_eq!(resolve("ffd8ffe0", "{}").unwrap(), "/9j/4A==");
+    assert_eq!(
+        resolve("UklGRgAAAABXRUJQ", "{}").unwrap(),
+        "UklGRgAAAABXRUJQ"
+    );
+    let data_uri = "data:image/jpeg;base64,/9j/4A==";
+    assert_eq!(resolve(data_uri, "{}").unwrap(), data_uri);
+    // gif and text bytes
+    for value in ["R0lGODlh", "0x68656c6c6f"] {
+        assert!(matches!(
+            resolve(value, "{}"),
+            Err(Error::DecodeUnknownRawImageFormat)
+        ));
+    }
+    assert!(matches!(
+        resolve("iVBORw==", "{\"max_image_bytes\":3}"),
+        Err(Error::DecodeRawImageTooLarge)
+    ));
+    let oversized = "A".repeat(MAX_RAW_IMAGE_BYTES / 3 * 4 + 8);
+    assert!(matches!(
+        resolve(&oversized, "{}"),
+        Err(Error::DecodeRawImageTooLarge)
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_gzip_raw_image() {
+    let png = "iVBORyB0aW55IGltYWdlIGJ5dGVziVBORyB0aW55IGltYWdlIGJ5dGVziVBORyB0aW55IGltYWdlIGJ5dGVziVBORyB0aW55IGltYWdlIGJ5dGVz";
+    let resolve = |stream: &str| {
+        let images_base = format!("[[\"0\",\"image+gzip\",\"*\",\"raw\",\"{stream}\"]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers[0].value.clone())
+    };
+    assert_eq!(
+        resolve("H4sIAAAAAAACA+sM8HNXKMnMq1TIzE1MT1VIqixJLe6kTBAA715fw1QAAAA=").unwrap(),
+        png
+    );
+    assert_eq!(resolve("0x1f8b0800000000000203eb0cf0735728c9ccab54c8cc4d4c4f5548aa2c492deea44c1000ef5e5fc354000000").unwrap(), png);
+    // a flipped crc bit
+    assert!(matches!(
+        resolve("H4sIAAAAAAACA+sM8HNXKMnMq1TIzE1MT1VIqixJLe6kTBAA7l5fw1QAAAA="),
+        Err(Error::DecodeCorruptCompressedImage)
+    ));
+    assert!(matches!(
+        resolve("iVBORw=="),
+        Err(Error::DecodeCorruptCompressedImage)
+    ));
+    // a size past the limit is rejected before inflating, whatever the stream holds
+    assert!(matches!(
+        resolve("0x1f8b0800000000000203eb0cf0735728c9ccab54c8cc4d4c4f5548aa2c492deea44c1000ef5e5fc3ffffffff"),
+        Err(Error::DecodeRawImageTooLarge)
+    ));
+    let images_base = "[[\"0\",\"image+gzip\",\"*\",\"raw\",\"H4sIAAAAAAACA+sM8HNXKMnMq1TIzE1MT1VIqixJLe6kTBAA715fw1QAAAA=\"]]";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        b"{\"max_image_bytes\":83}",
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert!(matches!(
+        dobs_resolve_layers(&parameters),
+        Err(Error::DecodeRawImageTooLarge)
+    ));
+}
+
+#[test]
+fn test_unsupported_image_encoding() {
+    let parse = |type_: &str| {
+        let images_base = format!("[[\"0\",\"{type_}\",\"*\",\"raw\",\"iVBORw==\"]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        dobs_parse_parameters(args).map(|_| ())
+    };
+    for type_ in ["image+lzma", "image+"] {
+        assert!(matches!(
+            parse(type_),
+            Err(Error::SchemaUnsupportedEncoding)
+        ));
+    }
+    if !cfg!(feature = "gzip") {
+        assert!(matches!(
+            parse("image+gzip"),
+            Err(Error::SchemaUnsupportedEncoding)
+        ));
+    }
+    // only raw images are compressed
+    assert!(matches!(parse("uri+gzip"), Err(Error::SchemaTypeMismatch)));
+}
+
+#[test]
+fn test_chained_dob1_images() {
+    let dob1_output = "{\"traits\":[],\"images\":[{\"name\":\"character\",\"type\":\"image/png;base64\",\"content\":\"iVBORw==\"},{\"name\":\"badge\",\"type\":\"image/svg+xml\",\"content\":\"<svg/>\"},{\"name\":\"broken\",\"type\":\"error\",\"content\":\"\",\"error\":12}]}";
+    let images_base = "[[\"0\",\"image\",\"*\",\"raw\",\"dob1:character\"],[\"0\",\"image\",\"*\",\"raw\",\"dob1:badge\"]]";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        b"{}",
+        dob1_output.as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layers = &dobs_resolve_layers(&parameters).expect("resolve layers failed")[0].layers;
+    assert_eq!(layers[0].value, "data:image/png;base64,iVBORw==");
+    assert_eq!(layers[1].value, "data:image/svg+xml;base64,PHN2Zy8+");
+
+    for name in ["broken", "missing"] {
+        let images_base = format!("[[\"0\",\"image\",\"*\",\"raw\",\"dob1:{name}\"]]");
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            b"{}",
+            dob1_output.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        assert!(matches!(
+            dobs_resolve_layers(&parameters),
+            Err(Error::DecodeChainedImageNotFound)
+        ));
+    }
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        b"{}",
+        b"{\"images\":{}}",
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::ParseInvalidChainedOutput)
+    ));
+}
+
+#[test]
+fn test_rng_seeded_from_dna() {
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let mut rng = dobs_rng(&parameters).expect("DNA trait");
+    assert_eq!(rng, Rng::from_seed(&[0xaa, 0xbb, 0xcc]));
+    let sequence = (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    assert_eq!(
+        sequence,
+        [
+            1755768046205110648,
+            6123995894557105025,
+            18185577111339117287
+        ]
+    );
+
+    let fork = Rng::from_seed(b"seed").fork(b"0");
+    assert_ne!(fork, Rng::from_seed(b"seed").fork(b"1"));
+    assert!((0..100).all(|_| rng.below(6) < 6));
+
+    let images_base = format!("[[\"seed\",\"Name\"],{}", &IMAGES_BASE[1..]);
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert_eq!(dobs_rng(&parameters), Some(Rng::from_seed(b"Ethan")));
+
+    // 64 bit numbers seed with their 8 bytes, wider ones with all 16
+    let number = |value: i128| Rng::from_trait(&ParsedTrait::Number(value));
+    assert_eq!(number(-1), Rng::from_seed(&u64::MAX.to_le_bytes()));
+    assert_eq!(
+        number(u64::MAX.into()),
+        Rng::from_seed(&u64::MAX.to_le_bytes())
+    );
+    let wide = (1 << 64) + 5;
+    assert_eq!(number(wide), Rng::from_seed(&wide.to_le_bytes()));
+    assert_ne!(number(wide), number(5));
+}
+
+#[test]
+fn test_range_bounds_checked() {
+    let resolve = |range: &str| {
+        let images_base =
+            format!("[[\"0\",\"color\",\"Score\",\"range\",[[{range},\"#FF0000\"]]]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    assert_eq!(resolve("[136,18446744073709551615]").unwrap(), 1);
+    assert_eq!(resolve("[0,135]").unwrap(), 0);
+    assert!(matches!(
+        resolve("[1000,1]"),
+        Err(Error::SchemaInvalidRange)
+    ));
+    assert_eq!(resolve("[-1,1000]").unwrap(), 1);
+    assert!(matches!(
+        resolve("[true,1000]"),
+        Err(Error::SchemaInvalidArgsElement)
+    ));
+}
+
+#[test]
+fn test_open_and_signed_ranges() {
+    let dob0_output = "[{\"name\":\"Temperature\",\"traits\":[{\"Number\":-12}]},{\"name\":\"Score\",\"traits\":[{\"Number\":136}]}]";
+    let resolve = |trait_name: &str, range: &str| {
+        let images_base =
+            format!("[[\"0\",\"color\",\"{trait_name}\",\"range\",[[{range},\"#FF0000\"]]]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    assert_eq!(resolve("Temperature", "[-20,-10]").unwrap(), 1);
+    assert_eq!(resolve("Temperature", "[\"*\",0]").unwrap(), 1);
+    assert_eq!(resolve("Temperature", "[0,\"*\"]").unwrap(), 0);
+    assert_eq!(resolve("Score", "[100,\"*\"]").unwrap(), 1);
+    assert_eq!(resolve("Score", "[\"*\",50]").unwrap(), 0);
+    assert_eq!(resolve("Temperature", "-12").unwrap(), 1);
+}
+
+#[test]
+fn test_float_traits() {
+    let dob0_output = "[{\"name\":\"Speed\",\"traits\":[{\"Float\":2.5}]},{\"name\":\"Drag\",\"traits\":[{\"Float\":-2.5}]},{\"name\":\"Age\",\"traits\":[{\"Number\":23}]}]";
+    let resolve = |trait_name: &str, pattern: &str, key: &str| {
+        let images_base =
+            format!("[[\"0\",\"color\",\"{trait_name}\",\"{pattern}\",[[{key},\"#FF0000\"]]]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    // float ranges compare the value as is
+    assert_eq!(resolve("Speed", "range", "[2.5,\"*\"]").unwrap(), 1);
+    assert_eq!(resolve("Speed", "range", "[0,2.4]").unwrap(), 0);
+    assert_eq!(resolve("Speed", "options", "2.5").unwrap(), 1);
+    // integer keys round halves away from zero
+    assert_eq!(resolve("Speed", "range", "[3,3]").unwrap(), 1);
+    assert_eq!(resolve("Speed", "options", "2").unwrap(), 0);
+    assert_eq!(resolve("Drag", "options", "-3").unwrap(), 1);
+    // a fractional key never matches an integer trait
+    for (pattern, key) in [("options", "23.0"), ("range", "[0.5,30]")] {
+        assert!(matches!(
+            resolve("Age", pattern, key),
+            Err(Error::DecodeFloatKeyMismatch)
+        ));
+    }
+}
+
+#[test]
+fn test_dob0_render_forms() {
+    let expected = serde_json::to_string(
+        &dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()])
+            .unwrap()
+            .dob0_output,
+    )
+    .unwrap();
+    let parse = |dob0_output: &str| {
+        let args = vec![dob0_output.as_bytes(), IMAGES_BASE.as_bytes()];
+        dobs_parse_parameters(args).map(|parameters| parameters.dob0_output)
+    };
+    // extra fields, bare values and `value` objects, as other DOB/0 renders write them
+    let render = "[{\"name\":\"Name\",\"traits\":[\"Ethan\"],\"total\":1},{\"name\":\"Age\",\"traits\":[{\"value\":23,\"type\":\"Number\"}]},{\"name\":\"Score\",\"traits\":[136]},{\"name\":\"DNA\",\"traits\":[{\"String\":\"0xaabbcc\",\"raw\":\"aabbcc\"}]},{\"name\":\"URL\",\"traits\":[{\"value\":\"http://127.0.0.1:8090\"}]},{\"name\":\"Value\",\"traits\":[{\"Number\":13417386}]}]";
+    let parsed = parse(render).expect("render form");
+    assert_eq!(serde_json::to_string(&parsed).unwrap(), expected);
+    // the render handed over as a json string
+    let parsed = parse(&serde_json::to_string(DOB0_OUTPUT).unwrap()).expect("string form");
+    assert_eq!(serde_json::to_string(&parsed).unwrap(), expected);
+
+    let floats = parse("[{\"name\":\"Speed\",\"traits\":[2.5,{\"Float\":2}]}]").unwrap();
+    assert!(matches!(
+        floats[0].traits[..],
+        [ParsedTrait::Float(a), ParsedTrait::Float(b)] if a == 2.5 && b == 2.0
+    ));
+    for invalid in [
+        "[{\"name\":\"Age\",\"traits\":[{\"Number\":\"2x3\"}]}]",
+        "[{\"name\":\"Age\",\"traits\":[{\"count\":23}]}]",
+        "[{\"name\":\"Age\",\"traits\":[true]}]",
+        "\"[\"",
+    ] {
+        assert!(matches!(parse(invalid), Err(Error::ParseInvalidDOB0Output)));
+    }
+}
+
+#[test]
+fn test_wide_trait_numbers() {
+    let parse = |value: &str| {
+        let dob0_output = format!("[{{\"name\":\"DNA\",\"traits\":[{value}]}}]");
+        let images_base = "[[\"0\",\"color\",\"DNA\",\"range\",[[[\"170141183460469231731687303715884105000\",\"*\"],\"#FF0000\"],[[0,\"*\"],\"#00FF00\"]]]]";
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args)?;
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers[0].value.clone())
+    };
+    // up to 128 bits as decimal strings, matched against ranges in 128 bit arithmetic
+    assert_eq!(
+        parse("{\"Number\":\"170141183460469231731687303715884105727\"}").unwrap(),
+        "#FF0000"
+    );
+    assert_eq!(
+        parse("{\"Number\":\"18446744073709551616\"}").unwrap(),
+        "#00FF00"
+    );
+    assert_eq!(
+        parse("{\"Number\":18446744073709551615}").unwrap(),
+        "#00FF00"
+    );
+    // json numbers past 64 bits arrive as floats, whose precision is already gone
+    for value in [
+        "{\"Number\":\"170141183460469231731687303715884105728\"}",
+        "{\"Number\":340282366920938463463374607431768211455}",
+        "340282366920938463463374607431768211455",
+    ] {
+        assert!(matches!(parse(value), Err(Error::ParseTraitPrecisionLoss)));
+    }
+    let batch =
+        "[[\"1\",[{\"name\":\"DNA\",\"traits\":[340282366920938463463374607431768211455]}]]]";
+    assert!(matches!(
+        dobs_parse_parameters(vec![batch.as_bytes(), b"[]"]),
+        Err(Error::ParseTraitPrecisionLoss)
+    ));
+    assert!(matches!(
+        parse("{\"Number\":2.5}"),
+        Err(Error::ParseInvalidDOB0Output)
+    ));
+}
+
+#[test]
+fn test_hex_trait_range_match() {
+    let resolve = |options: &str| {
+        let images_base = format!(
+            "[[\"0\",\"color\",\"DNA\",\"range\",[[[11189196,11189196],\"#0000FF\"],[[13417386,13417386],\"#00FF00\"]]{options}]]"
+        );
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    assert_eq!(resolve(""), "#0000FF");
+    assert_eq!(resolve(",{\"endian\":\"little\"}"), "#00FF00");
+    // a 32 byte DNA reads as its 8 least significant bytes
+    let dna = format!("0x{}{}", "ff".repeat(24), "0000000000000102");
+    let number = |endian| match hex_trait_as_number(ParsedTrait::String(dna.clone()), endian) {
+        ParsedTrait::Number(number) => number,
+        _ => panic!("hex trait should be a number"),
+    };
+    assert_eq!(number(Endian::Big), 0x0102);
+    assert_eq!(number(Endian::Little), u64::MAX as i128);
+    // a bare `0x` encodes no number at all
+    let empty = hex_trait_as_number(ParsedTrait::String("0x".to_owned()), Endian::Big);
+    assert!(matches!(empty, ParsedTrait::String(value) if value == "0x"));
+    let dob0_output = format!("[{{\"name\":\"DNA\",\"traits\":[{{\"String\":\"{dna}\"}}]}}]");
+    let images_base = "[[\"0\",\"color\",\"DNA\",\"mod\",[4,[2,\"#FF0000\"]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(layer_groups[0].layers[0].value, "#FF0000");
+}
+
+#[test]
+fn test_nfc_string_matching() {
+    let resolve = |name: &str, config: &str| {
+        let dob0_output = format!("[{{\"name\":\"Name\",\"traits\":[{{\"String\":\"{name}\"}}]}}]");
+        let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Chlo\u{e9}\",\"#FF0000\"]]]]";
+        let args = vec![
+            dob0_output.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers.len()
+    };
+    assert_eq!(resolve("Chloe\u{301}", "{}"), 1);
+    assert_eq!(resolve("Chlo\u{200b}e\u{301}", "{}"), 0);
+    assert_eq!(
+        resolve("Chlo\u{200b}e\u{301}", "{\"strip_zero_width\":true}"),
+        1
+    );
+    assert_eq!(resolve("Chloe", "{}"), 0);
+}
+
+#[test]
+fn test_loose_string_matching() {
+    let resolve = |name: &str, row: &str| {
+        let dob0_output = format!("[{{\"name\":\"Name\",\"traits\":[{{\"String\":\"{name}\"}}]}}]");
+        let images_base = format!("[[\"0\",\"color\",\"Name\",{row}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args)?;
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    let exact = "\"options\",[[\"Ethan\",\"#FF0000\"]]";
+    let loose = "\"options\",[[\"Ethan\",\"#FF0000\"]],{\"loose\":true}";
+    assert_eq!(resolve("ethan", exact).unwrap(), 0);
+    assert_eq!(resolve(" ETHAN\\t", loose).unwrap(), 1);
+    assert_eq!(resolve("Ethan", loose).unwrap(), 1);
+    assert_eq!(resolve("Eth an", loose).unwrap(), 0);
+    let prefix = "\"options\",[[[\"prefix\",\"fire\"],\"#FF0000\"]],{\"loose\":true}";
+    assert_eq!(resolve("Fire Dragon", prefix).unwrap(), 1);
+    assert!(matches!(
+        resolve("Ethan", "\"range\",[[[0,9],\"#FF0000\"]],{\"loose\":true}"),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_repeated_trait_occurrences() {
+    let dob0_output = "[{\"name\":\"Part\",\"traits\":[{\"String\":\"ipfs://a\"}]},{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Part\",\"traits\":[{\"String\":\"ipfs://b\"}]},{\"name\":\"Part\",\"traits\":[{\"String\":\"ipfs://c\"}]}]";
+    let resolve = |options: &str| {
+        let images_base = format!("[[\"0\",\"uri\",\"Part\",\"raw\",null,{options}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args)?;
+        dobs_resolve_layers(&parameters).map(|groups| {
+            groups[0]
+                .layers
+                .iter()
+                .map(|layer| layer.value.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+    assert_eq!(resolve("{}").unwrap(), ["ipfs://a"]);
+    assert_eq!(resolve("{\"occurrence\":\"last\"}").unwrap(), ["ipfs://c"]);
+    assert_eq!(resolve("{\"occurrence\":\"nth:1\"}").unwrap(), ["ipfs://b"]);
+    assert!(resolve("{\"occurrence\":\"nth:3\"}").unwrap().is_empty());
+    assert_eq!(
+        resolve("{\"occurrence\":\"all\"}").unwrap(),
+        ["ipfs://a", "ipfs://b", "ipfs://c"]
+    );
+    assert!(matches!(
+        resolve("{\"occurrence\":\"nth:-1\"}"),
+        Err(Error::SchemaInvalidOptions)
+    ));
+
+    // every occurrence is matched on its own and those matching nothing are left out
+    let images_base = "[[\"0\",\"color\",\"Part\",\"options\",[[\"ipfs://a\",\"#FF0000\"],[\"ipfs://c\",\"#0000FF\"]],{\"occurrence\":\"all\",\"as_trait\":\"Colors\"}]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = groups[0]
+        .layers
+        .iter()
+        .map(|layer| layer.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["#FF0000", "#0000FF"]);
+    assert_eq!(groups[0].traits[0].traits.len(), 2);
+
+    let images_base =
+        "[[\"0\",\"color\",[\"Part\",\"Name\"],\"options\",[],{\"occurrence\":\"last\"}]]";
+    assert!(matches!(
+        dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()]),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_trait_name_globs() {
+    let dob0_output = "[{\"name\":\"Accessory2\",\"traits\":[{\"String\":\"ring\"}]},{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Accessory1\",\"traits\":[{\"String\":\"hat\"}]},{\"name\":\"AccessoryX\",\"traits\":[{\"String\":\"cape\"}]}]";
+    let resolve = |dob0_trait: &str, options: &str| {
+        let images_base = format!("[[\"0\",\"color\",{dob0_trait},\"options\",[[\"hat\",\"#FF0000\"],[\"ring\",\"#00FF00\"],[[\"*\"],\"#0000FF\"]],{options}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args)?;
+        dobs_resolve_layers(&parameters).map(|groups| {
+            groups[0]
+                .layers
+                .iter()
+                .map(|layer| layer.value.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+    // a layer per matching entry, in DOB/0 output order
+    assert_eq!(
+        resolve("\"Accessory*\"", "{}").unwrap(),
+        ["#00FF00", "#FF0000", "#0000FF"]
+    );
+    assert_eq!(resolve("\"Accessory1\"", "{}").unwrap(), ["#FF0000"]);
+    assert!(resolve("\"Hat*\"", "{}").unwrap().is_empty());
+    assert!(matches!(
+        resolve("\"Accessory*\"", "{\"occurrence\":\"last\"}"),
+        Err(Error::SchemaInvalidOptions)
+    ));
+    assert!(matches!(
+        resolve("[\"Name\",\"Accessory*\"]", "{}"),
+        Err(Error::SchemaInvalidTraitName)
+    ));
+}
+
+#[test]
+fn test_raw_number_formats() {
+    let resolve = |number: i64, row: &str| {
+        let dob0_output = format!("[{{\"name\":\"Index\",\"traits\":[{{\"Number\":{number}}}]}}]");
+        let images_base = format!("[[\"0\",\"uri\",\"Index\",\"raw\",{row}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args)?;
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers[0].value.clone())
+    };
+    assert!(matches!(
+        resolve(42, "\"ipfs://cid/{}.png\""),
+        Err(Error::DecodeInvalidRawValue)
+    ));
+    let row = |format: &str| format!("\"ipfs://cid/{{}}.png\",{{\"number\":\"{format}\"}}");
+    assert_eq!(resolve(42, &row("decimal")).unwrap(), "ipfs://cid/42.png");
+    assert_eq!(resolve(42, &row("pad:4")).unwrap(), "ipfs://cid/0042.png");
+    assert_eq!(resolve(42, &row("hex")).unwrap(), "ipfs://cid/2a.png");
+    assert_eq!(resolve(42, &row("hex:4")).unwrap(), "ipfs://cid/002a.png");
+    assert_eq!(resolve(-42, &row("pad:4")).unwrap(), "ipfs://cid/-042.png");
+    assert!(matches!(
+        resolve(-42, &row("hex")),
+        Err(Error::DecodeInvalidRawValue)
+    ));
+    for options in [
+        "null,{\"number\":\"octal\"}",
+        "null,{\"number\":\"decimal:4\"}",
+    ] {
+        assert!(matches!(
+            resolve(42, options),
+            Err(Error::SchemaInvalidOptions)
+        ));
+    }
+}
+
+#[test]
+fn test_row_guards() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#000000\"],[\"0\",\"uri\",\"Aura\",\"raw\",null,{\"if\":{\"Rarity\":[\"Legendary\",\"Mythic\"],\"Level\":[[10,99]]}}],[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"]]";
+    let resolve = |rarity: &str, level: u64| {
+        let dob0_output = format!("[{{\"name\":\"Rarity\",\"traits\":[{{\"String\":\"{rarity}\"}}]}},{{\"name\":\"Level\",\"traits\":[{{\"Number\":{level}}}]}},{{\"name\":\"Aura\",\"traits\":[{{\"String\":\"ipfs://aura\"}}]}}]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        groups[0]
+            .layers
+            .iter()
+            .map(|layer| layer.value.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(resolve("Mythic", 12), ["#000000", "ipfs://aura", "#FFFFFF"]);
+    // a failed guard skips the row alone
+    assert_eq!(resolve("Common", 12), ["#000000", "#FFFFFF"]);
+    assert_eq!(resolve("Legendary", 3), ["#000000", "#FFFFFF"]);
+
+    let images_base = "[[\"0\",\"uri\",\"Aura\",\"raw\",null,{\"if\":{\"Missing\":[[\"*\"]]}}]]";
+    let parameters =
+        dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()]).unwrap();
+    assert!(dobs_resolve_layers(&parameters).unwrap()[0]
+        .layers
+        .is_empty());
+    let stats = pattern_stats(&parameters.images_base);
+    assert_eq!(stats[0].combinations, 2);
+}
+
+#[test]
+fn test_exclusive_row_chains() {
+    // hat A for Alice, otherwise hat B for a legendary, otherwise none
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#000000\"],[\"0\",\"uri\",\"Name\",\"options\",[[\"Alice\",\"ipfs://hat-a\"]],{\"exclusive\":\"hat\"}],[\"0\",\"uri\",\"Rarity\",\"options\",[[\"Legendary\",\"ipfs://hat-b\"]],{\"exclusive\":\"hat\"}],[\"0\",\"uri\",\"Name\",\"options\",[[[\"*\"],\"ipfs://hat-c\"]],{\"exclusive\":\"hat\",\"if\":{\"Rarity\":[\"Legendary\",\"Common\"]}}],[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"]]";
+    let resolve = |name: &str, rarity: Option<&str>| {
+        let rarity = rarity
+            .map(|rarity| {
+                format!(",{{\"name\":\"Rarity\",\"traits\":[{{\"String\":\"{rarity}\"}}]}}")
+            })
+            .unwrap_or_default();
+        let dob0_output =
+            format!("[{{\"name\":\"Name\",\"traits\":[{{\"String\":\"{name}\"}}]}}{rarity}]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        groups[0]
+            .layers
+            .iter()
+            .map(|layer| layer.value.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        resolve("Alice", Some("Legendary")),
+        ["#000000", "ipfs://hat-a", "#FFFFFF"]
+    );
+    assert_eq!(
+        resolve("Ethan", Some("Legendary")),
+        ["#000000", "ipfs://hat-b", "#FFFFFF"]
+    );
+    assert_eq!(
+        resolve("Ethan", Some("Common")),
+        ["#000000", "ipfs://hat-c", "#FFFFFF"]
+    );
+    // neither a match nor a missing trait ends the group within a chain
+    assert_eq!(resolve("Ethan", None), ["#000000", "#FFFFFF"]);
+
+    // the alternatives of a chain may read the same trait, even in strict mode
+    let images_base = "[[\"0\",\"uri\",\"Name\",\"options\",[[\"Alice\",\"ipfs://a\"]],{\"exclusive\":\"hat\"}],[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"ipfs://e\"]],{\"exclusive\":\"hat\"}]]";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        "{\"strict\":true}".as_bytes(),
+    ];
+    assert!(dobs_parse_parameters(args).is_ok());
+
+    let images_base = "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"exclusive\":\"\"}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_reserved_context_selectors() {
+    let images_base = "[[\"0\",\"uri\",\"#spore_id\",\"raw\"],[\"0\",\"color\",\"#index\",\"range\",[[[0,9],\"#FF0000\"]]]]";
+    let config = "{\"context\":{\"spore_id\":\"btcfs://sporei0\",\"index\":7}}";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        config.as_bytes(),
+    ];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = layer_groups[0]
+        .layers
+        .iter()
+        .map(|layer| layer.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["btcfs://sporei0", "#FF0000"]);
+
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"0\",\"uri\",\"#owner\",\"raw\"]]".as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidTraitName)
+    ));
+}
+
+#[test]
+fn test_static_wildcard_layer() {
+    let images_base =
+        "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"uri\",\"URL\",\"raw\"]]";
+    let dob0_output = "[{\"name\":\"URL\",\"traits\":[{\"String\":\"btcfs://urli0\"}]}]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let layers = &layer_groups[0].layers;
+    assert_eq!(layers.len(), 2);
+    assert_eq!(layers[0].type_, ImageType::ColorCode);
+    assert_eq!(layers[0].value, "#FFFFFF");
+    assert_eq!(layers[1].value, "btcfs://urli0");
+
+    for images_base in [
+        "[[\"0\",\"color\",\"*\",\"raw\"]]",
+        "[[\"0\",\"color\",\"*\",\"options\",[[\"*\",\"#FFFFFF\"]]]]",
+    ] {
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        assert!(dobs_parse_parameters(args).is_err());
+    }
+}
+
+#[test]
+fn test_numeric_string_coercion() {
+    let resolve = |dob0_output: &str, args: &str, config: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"Age\",\"{args}]]");
+        let args = vec![
+            dob0_output.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    let number = "[{\"name\":\"Age\",\"traits\":[{\"Number\":23}]}]";
+    let string = "[{\"name\":\"Age\",\"traits\":[{\"String\":\"23\"}]}]";
+    let string_key = "options\",[[\"23\",\"#FF0000\"]]";
+    let number_key = "options\",[[23,\"#FF0000\"]]";
+    let range = "range\",[[[20,29],\"#FF0000\"]]";
+    let coerce = "{\"coerce\":true}";
+    assert!(matches!(
+        resolve(number, string_key, "{}"),
+        Err(Error::SchemaInvalidParsedTraitType)
+    ));
+    assert_eq!(resolve(number, string_key, coerce).unwrap(), 1);
+    assert_eq!(resolve(string, number_key, coerce).unwrap(), 1);
+    assert_eq!(resolve(string, range, coerce).unwrap(), 1);
+    assert!(matches!(
+        resolve(string, range, "{}"),
+        Err(Error::SchemaInvalidParsedTraitType)
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_analyze_pattern() {
+    use crate::decoder::{coverage::analyze_pattern, types::ShadowedEntry};
+
+    let images_base = "[[\"0\",\"uri\",\"Age\",\"range\",[[[0,50],\"btcfs://a\"],[[61,100],\"btcfs://b\"],[[70,80],\"btcfs://c\"]]],[\"0\",\"color\",\"Score\",\"mod\",[4,[0,\"#000000\"],[2,\"#FFFFFF\"]]],[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"],[\"Bob\",\"#00FF00\"]]]]";
+    let report = analyze_pattern(images_base.as_bytes());
+    assert_eq!(report.error, None);
+    let age = &report.rows[0];
+    assert_eq!(age.covered, [[0, 50], [61, 100]]);
+    assert_eq!(age.gaps, [[51, 60]]);
+    assert!(age.open);
+    assert_eq!(age.unreachable, [ShadowedEntry { index: 2, by: 1 }]);
+    let score = &report.rows[1];
+    assert_eq!(score.covered, [[0, 0], [2, 2]]);
+    assert_eq!(score.gaps, [[1, 1], [3, 3]]);
+    let name = &report.rows[2];
+    assert!(!name.open);
+    assert_eq!(name.unreachable, [ShadowedEntry { index: 2, by: 1 }]);
+
+    let report = analyze_pattern(IMAGES_BASE.as_bytes());
+    assert!(report
+        .rows
+        .iter()
+        .all(|row| !row.open && row.gaps.is_empty()));
+    assert_eq!(
+        analyze_pattern(b"[[\"0\",\"uri\",\"Age\",\"bogus\"]]").error,
+        Some(Error::SchemaPatternMismatch as u64)
+    );
+}
+
+#[test]
+fn test_pattern_stats() {
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let stats = pattern_stats(&parameters.images_base);
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].combinations, 4 * 3 * 2);
+    assert!(!stats[0].unbounded);
+    let fallbacks = stats[0]
+        .fallbacks
+        .iter()
+        .map(|branch| (branch.row, branch.dob0_trait.as_str(), branch.index))
+        .collect::<Vec<_>>();
+    assert_eq!(fallbacks, [(0, "Name", 3), (1, "Age", 2), (2, "Score", 1)]);
+    assert_eq!(stats[1].combinations, 2);
+
+    // without a fallback the first row may end the group, empty or with a single layer
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Alice\",\"#0000FF\"],[\"Bob\",\"#00FF00\"]]],[\"0\",\"uri\",\"URL\",\"raw\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let stats = pattern_stats(&parameters.images_base);
+    assert_eq!(stats[0].combinations, 3);
+    assert!(stats[0].unbounded);
+    assert!(stats[0].fallbacks.is_empty());
+}
+
+#[test]
+fn test_schema_macro_expansion() {
+    let dob0_output = "[{\"name\":\"LeftEye\",\"traits\":[{\"String\":\"blue\"}]},{\"name\":\"RightEye\",\"traits\":[{\"String\":\"green\"}]}]";
+    let images_base = "[[\"macros\",{\"eye\":[\"color\",\"options\",[[\"blue\",\"#0000FF\"],[\"green\",\"#00FF00\"]]]}],[\"0\",\"$eye\",\"LeftEye\"],[\"0\",\"$eye\",\"RightEye\"]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    assert_eq!(parameters.images_base.len(), 2);
+    assert_eq!(parameters.images_base[1].dob0_trait, "RightEye");
+    assert_eq!(parameters.images_base[1].pattern, Pattern::Options);
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = layer_groups[0]
+        .layers
+        .iter()
+        .map(|layer| layer.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["#0000FF", "#00FF00"]);
+
+    let args = vec![
+        dob0_output.as_bytes(),
+        "[[\"0\",\"$mouth\",\"LeftEye\"]]".as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaUndefinedMacro)
+    ));
+}
+
+#[test]
+fn test_asset_definitions() {
+    let dob0_output = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Handle\",\"traits\":[{\"String\":\"x\"}]}]";
+    let images_base = "[[\"defs\",{\"gold_bg\":\"btcfs://aa\"}],[\"bg\",\"uri\",\"*\",\"raw\",\"@gold_bg\"],[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"@gold_bg\"],[\"*\",\"btcfs://bb\"]]],[\"1\",\"text\",\"Handle\",\"options\",[[\"x\",\"@@home\"]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = layer_groups
+        .iter()
+        .flat_map(|group| group.layers.iter().map(|layer| layer.value.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["btcfs://aa", "btcfs://aa", "@home"]);
+
+    // without a defs directive values starting with `@` stay as they are
+    let images_base = "[[\"1\",\"text\",\"Handle\",\"options\",[[\"x\",\"@home\"]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(layer_groups[0].layers[0].value, "@home");
+
+    let images_base = "[[\"defs\",{\"gold_bg\":\"btcfs://aa\"}],[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"@silver_bg\"]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaUndefinedAsset)
+    ));
+    for defs in ["{\"\":\"btcfs://aa\"}", "[\"btcfs://aa\"]"] {
+        let images_base =
+            format!("[[\"defs\",{defs}],[\"bg\",\"uri\",\"*\",\"raw\",\"btcfs://aa\"]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidDirective)
+        ));
+    }
+}
+
+// a host holding a few cell deps and nothing else
+struct CellDeps;
+
+impl Host for CellDeps {
+    fn combine(&mut self, _: &mut [u8], _: &mut u64, _: &[u8]) -> u64 {
+        1
+    }
+
+    fn write(&mut self, _: &[u8]) -> u64 {
+        0
+    }
+
+    fn reads_cells(&self) -> bool {
+        true
+    }
+
+    fn load_cell(&mut self, cell: &CellReference) -> Option<Vec<u8>> {
+        let parent = "[[\"bg\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]]]";
+        match cell {
+            CellReference::DataHash([0x11, ..]) => Some(parent.as_bytes().to_vec()),
+            CellReference::DataHash([0x33, ..]) => Some(b"cell image".to_vec()),
+            CellReference::OutPoint([0x44, ..], 1) => Some(b"out point image".to_vec()),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_inherit_parent_pattern() {
+    let mut host = CellDeps;
+    let run = Run::new(&mut host);
+    let inherit = |hash: u8| {
+        let images_base = format!(
+            "[[\"inherit\",\"0x{}\"],[\"0\",\"uri\",\"URL\",\"raw\"]]",
+            hex::encode([hash; 32])
+        );
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        parse_parameters(args, &run)
+    };
+    let parameters = inherit(0x11).expect("parse parameters failed");
+    let rows = parameters
+        .images_base
+        .iter()
+        .map(|schema| (schema.name.as_str(), schema.dob0_trait.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [("bg", "*"), ("0", "URL")]);
+    assert!(matches!(
+        inherit(0x22),
+        Err(Error::ParseParentPatternNotFound)
+    ));
+    // without a host there are no cells to inherit from
+    let images_base = format!(
+        "[[\"inherit\",\"0x{}\"],[\"0\",\"uri\",\"URL\",\"raw\"]]",
+        hex::encode([0x11; 32])
+    );
+    assert!(matches!(
+        dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()]),
+        Err(Error::ParseParentPatternNotFound)
+    ));
+}
+
+#[test]
+fn test_ckbfs_cell_images() {
+    let mut host = CellDeps;
+    let run = Run::new(&mut host);
+    let items = |uri: &str| {
+        let images_base = format!("[[\"0\",\"uri\",\"*\",\"raw\",\"{uri}\"]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = parse_parameters(args, &run).expect("parse parameters failed");
+        let groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        build_item_vec(&groups[0], &parameters.config, &run)
+    };
+    let raw_image = |uri: &str| match items(uri).unwrap().get(0).unwrap().to_enum() {
+        ItemUnion::RawImage(image) => image.raw_data().to_vec(),
+        _ => panic!("item should be RawImage"),
+    };
+    assert_eq!(
+        raw_image(&format!("ckbfs://{}", hex::encode([0x33; 32]))),
+        STANDARD.encode("cell image").as_bytes()
+    );
+    assert_eq!(
+        raw_image(&format!("ckbfs://0x{}i1", hex::encode([0x44; 32]))),
+        STANDARD.encode("out point image").as_bytes()
+    );
+    // a reference to a missing cell fails, anything else is left to the combiner
+    assert!(matches!(
+        items(&format!("ckbfs://{}i0", hex::encode([0x44; 32]))),
+        Err(Error::DecodeCellDataNotFound)
+    ));
+    let item = items("ckbfs://not-a-cell").unwrap().get(0).unwrap();
+    assert!(matches!(item.to_enum(), ItemUnion::URI(_)));
+    // hosts reading no cells leave every uri to the combiner
+    let images_base = format!(
+        "[[\"0\",\"uri\",\"*\",\"raw\",\"ckbfs://{}\"]]",
+        hex::encode([0x33; 32])
+    );
+    let parameters = dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()])
+        .expect("parse parameters failed");
+    let groups = dobs_parse_syscall_parameters(&parameters).expect("build items failed");
+    let item = groups[0].1.get(0).unwrap();
+    assert!(matches!(item.to_enum(), ItemUnion::URI(_)));
+}
+
+#[test]
+fn test_object_form_args() {
+    let resolve = |trait_name: &str, pattern: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"{trait_name}\",{pattern}]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    let options = "\"options\",{\"*\":\"#FFFFFF\",\"Ethan\":\"#FF0000\",\"Bob\":\"#00FF00\"}";
+    assert_eq!(resolve("Name", options), "#FF0000");
+    let range = "\"range\",{\"*\":\"#FFFFFF\",\"[0,50]\":\"#0000FF\",\"[51,100]\":\"#00FF00\"}";
+    assert_eq!(resolve("Age", range), "#0000FF");
+    assert_eq!(resolve("Score", range), "#FFFFFF");
+    let number = "\"options\",{\"23\":\"#0000FF\"}";
+    assert_eq!(resolve("Age", number), "#0000FF");
+    // numeric keys are read as json numbers, signed, fractional or past 64 bits
+    let dob0_output = "[{\"name\":\"Delta\",\"traits\":[{\"Number\":-5}]},{\"name\":\"Ratio\",\"traits\":[{\"Float\":1.5}]},{\"name\":\"Supply\",\"traits\":[{\"Number\":\"1267650600228229401496703205376\"}]}]";
+    let resolve_numeric = |trait_name: &str, key: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"{trait_name}\",\"options\",{{\"{key}\":\"#FF0000\",\"[0,\\\"*\\\"]\":\"#000000\",\"*\":\"#FFFFFF\"}}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    assert_eq!(resolve_numeric("Delta", "-5"), "#FF0000");
+    assert_eq!(resolve_numeric("Ratio", "1.5"), "#FF0000");
+    // still tried before the ranges
+    let supply = "1267650600228229401496703205376";
+    assert_eq!(resolve_numeric("Supply", supply), "#FF0000");
+    assert_eq!(resolve_numeric("Supply", "1"), "#000000");
+    // ranges keep the order of their keys as strings, so they may not overlap
+    for ranges in [
+        "{\"[0,50]\":\"#0000FF\",\"[40,100]\":\"#00FF00\"}",
+        "{\"[0,10]\":\"#0000FF\",\"[10.2,10.4]\":\"#00FF00\"}",
+    ] {
+        let images_base = format!("[[\"0\",\"color\",\"Age\",\"range\",{ranges}]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_parse_parameters(args),
+            Err(Error::SchemaInvalidRange)
+        ));
+    }
+
+    let pairs =
+        "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]]]";
+    let object =
+        "[[\"0\",\"color\",\"Name\",\"options\",{\"*\":\"#FFFFFF\",\"Ethan\":\"#FF0000\"}]]";
+    let decode = |images_base: &str| {
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        dobs_parse_parameters(args)
+            .expect("parse parameters failed")
+            .images_base
+    };
+    assert_eq!(decode(pairs), decode(object));
+}
+
+#[test]
+fn test_exclusion_keys() {
+    let resolve = |trait_name: &str, args: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"{trait_name}\",\"options\",{args}]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|layer_groups| {
+            layer_groups[0]
+                .layers
+                .first()
+                .map(|layer| layer.value.clone())
+        })
+    };
+    let not_bob = "[[[\"!\",\"Bob\"],\"#FF0000\"]]";
+    assert_eq!(
+        resolve("Name", not_bob).unwrap().as_deref(),
+        Some("#FF0000")
+    );
+    let not_ethan = "[[[\"!\",\"Ethan\"],\"#FF0000\"]]";
+    assert_eq!(resolve("Name", not_ethan).unwrap(), None);
+    // the listed keys may be ranges as well
+    let not_in = "[[[\"not-in\",[1,[20,30]]],\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]";
+    assert_eq!(resolve("Age", not_in).unwrap().as_deref(), Some("#FFFFFF"));
+    assert_eq!(
+        resolve("Score", not_in).unwrap().as_deref(),
+        Some("#FF0000")
+    );
+    assert!(matches!(
+        resolve("Age", "[[[\"not-in\",5],\"#FF0000\"]]"),
+        Err(Error::SchemaInvalidArgsElement)
+    ));
+    // in object form exclusions are tried before the catch-all
+    let object = "{\"*\":\"#FFFFFF\",\"[\\\"!\\\",\\\"Bob\\\"]\":\"#FF0000\"}";
+    assert_eq!(resolve("Name", object).unwrap().as_deref(), Some("#FF0000"));
+}
+
+#[test]
+fn test_partial_string_keys() {
+    let dob0_output = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Fire Dragon #12\"}]},{\"name\":\"Age\",\"traits\":[{\"Number\":23}]}]";
+    let resolve = |trait_name: &str, key: &str| {
+        let images_base =
+            format!("[[\"0\",\"color\",\"{trait_name}\",\"options\",[[{key},\"#FF0000\"]]]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|groups| groups[0].layers.len())
+    };
+    assert_eq!(resolve("Name", "[\"prefix\",\"Fire\"]").unwrap(), 1);
+    assert_eq!(resolve("Name", "[\"prefix\",\"Dragon\"]").unwrap(), 0);
+    assert_eq!(resolve("Name", "[\"suffix\",\"#12\"]").unwrap(), 1);
+    assert_eq!(resolve("Name", "[\"contains\",\"Dragon\"]").unwrap(), 1);
+    assert_eq!(resolve("Name", "[\"contains\",\"Water\"]").unwrap(), 0);
+    assert!(matches!(
+        resolve("Name", "[\"prefix\",1]"),
+        Err(Error::SchemaInvalidArgsElement)
+    ));
+    assert!(matches!(
+        resolve("Age", "[\"prefix\",\"2\"]"),
+        Err(Error::SchemaInvalidParsedTraitType)
+    ));
+}
+
+#[test]
+fn test_multi_value_args() {
+    let dob0_output = "[{\"name\":\"Set\",\"traits\":[{\"String\":\"Samurai\"}]}]";
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"uri\",\"Set\",\"options\",[[\"Samurai\",[\"ipfs://helmet\",{\"default\":\"ipfs://armor\"},\"ipfs://weapon\"]]]]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let syscall_parameters =
+        dobs_parse_syscall_parameters(&parameters).expect("parse syscall parameters failed");
+    let uris = syscall_parameters[0]
+        .1
+        .clone()
+        .into_iter()
+        .skip(1)
+        .map(|item| match item.to_enum() {
+            ItemUnion::URI(uri) => uri.raw_data().to_vec(),
+            _ => panic!("stacked items should be URIs"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        uris,
+        [&b"ipfs://helmet"[..], b"ipfs://armor", b"ipfs://weapon"]
+    );
+
+    // a content hash belongs to a single value
+    for dob1_value in ["[]", "[\"ipfs://helmet\"],\"0xaa\""] {
+        let images_base =
+            format!("[[\"0\",\"uri\",\"Set\",\"options\",[[\"Samurai\",{dob1_value}]]]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        assert!(matches!(
+            dobs_resolve_layers(&parameters),
+            Err(Error::SchemaInvalidArgsElement)
+        ));
+    }
+}
+
+#[test]
+fn test_schema_option_extensions() {
+    let images_base = "[[\"0\",\"uri\",\"URL\",\"raw\",null,{\"aspect_ratio\":[1,1],\"x-rarity\":\"legendary\"}],[\"0\",\"uri\",\"URL\",\"raw\",null,{\"x-rarity\":\"common\",\"x-artist\":\"ada\"}]]";
+    let resolve = |config: &str| {
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        assert_eq!(parameters.images_base[0].options.extensions.len(), 1);
+        assert_eq!(
+            parameters.images_base[0].options.metadata.aspect_ratio,
+            Some([1, 1])
+        );
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        serde_json::to_value(&layer_groups[0].metadata).unwrap()
+    };
+    assert_eq!(resolve("{}"), serde_json::json!({"aspect_ratio": [1, 1]}));
+    assert_eq!(
+        resolve("{\"extensions\":true}"),
+        serde_json::json!({
+            "aspect_ratio": [1, 1],
+            "extensions": {"x-rarity": "legendary", "x-artist": "ada"}
+        })
+    );
+}
+
+#[test]
+fn test_trait_index_selector() {
+    let dob0_output = "[{\"name\":\"Age\",\"traits\":[{\"Number\":23},{\"Number\":77}]},{\"name\":\"Tag[x]\",\"traits\":[{\"String\":\"odd\"}]}]";
+    let resolve = |trait_name: &str| {
+        let images_base = format!(
+            "[[\"0\",\"color\",\"{trait_name}\",\"range\",[[[0,50],\"#0000FF\"],[[51,100],\"#00FF00\"]]]]"
+        );
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0]
+            .layers
+            .first()
+            .map(|layer| layer.value.clone())
+    };
+    assert_eq!(resolve("Age").as_deref(), Some("#0000FF"));
+    assert_eq!(resolve("Age[0]").as_deref(), Some("#0000FF"));
+    assert_eq!(resolve("Age[1]").as_deref(), Some("#00FF00"));
+    assert_eq!(resolve("Age[2]"), None);
+
+    let images_base = "[[\"0\",\"uri\",\"Tag[x]\",\"raw\"]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    assert_eq!(layer_groups[0].layers[0].value, "odd");
+}
+
+#[test]
+fn test_args_first_match_order() {
+    let dob0_output = "[{\"name\":\"Age\",\"traits\":[{\"Number\":30}]}]";
+    let resolve = |args: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"Age\",\"range\",{args}]]");
+        let parameters =
+            dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()])
+                .expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0].layers[0].value.clone()
+    };
+    // overlapping ranges resolve to whichever came first in the pattern
+    assert_eq!(
+        resolve("[[[20,40],\"#111111\"],[[0,100],\"#222222\"]]"),
+        "#111111"
+    );
+    assert_eq!(
+        resolve("[[[0,100],\"#222222\"],[[20,40],\"#111111\"]]"),
+        "#222222"
+    );
+    // a trailing catch-all only sees values missed before it, a leading one shadows the rest
+    assert_eq!(
+        resolve("[[[20,40],\"#111111\"],[[\"*\"],\"#333333\"]]"),
+        "#111111"
+    );
+    assert_eq!(
+        resolve("[[[\"*\"],\"#333333\"],[[20,40],\"#111111\"]]"),
+        "#333333"
+    );
+}
+
+#[test]
+fn test_template_pattern() {
+    let images_base = "[[\"0\",\"uri\",\"DNA\",\"template\",\"btcfs://{DNA}i0\"],[\"0\",\"uri\",\"*\",\"template\",\"ipfs://collection/{Name}-{Age}.png\"],[\"1\",\"uri\",\"Name\",\"template\",\"ipfs://{Missing}.png\"]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let parameters = dobs_parse_parameters(args).expect("parse parameters failed");
+    let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+    let values = layer_groups[0]
+        .layers
+        .iter()
+        .map(|layer| layer.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        ["btcfs://0xaabbcci0", "ipfs://collection/Ethan-23.png"]
+    );
+    // a placeholder naming an absent trait ends the group like a missing trait would
+    assert!(layer_groups[1].layers.is_empty());
+
+    let images_base = "[[\"0\",\"uri\",\"DNA\",\"template\",[[\"*\"],\"x\"]]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_parse_parameters(args),
+        Err(Error::SchemaInvalidArgs)
+    ));
+}
+
+#[test]
+fn test_multi_trait_rows() {
+    let dob0_output = "[{\"name\":\"Background\",\"traits\":[{\"String\":\"Sky\"}]},{\"name\":\"Rarity\",\"traits\":[{\"String\":\"Legendary\"}]},{\"name\":\"Level\",\"traits\":[{\"Number\":7}]}]";
+    let resolve = |images_base: &str| {
+        let parameters =
+            dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()])
+                .expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0]
+            .layers
+            .iter()
+            .map(|layer| layer.value.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        resolve("[[\"0\",\"color\",[\"Background\",\"Rarity\"],\"options\",[[[\"Sky\",\"Common\"],\"#111111\"],[[\"Sky\",\"Legendary\"],\"#FFD700\"],[[\"*\"],\"#000000\"]]]]"),
+        ["#FFD700"]
+    );
+    assert_eq!(
+        resolve("[[\"0\",\"color\",[\"Background\",\"Rarity\"],\"options\",[[[\"Sea\",\"Legendary\"],\"#FFD700\"],[[\"*\"],\"#000000\"]]]]"),
+        ["#000000"]
+    );
+    // each key of the tuple may itself be a range or a catch-all
+    assert_eq!(
+        resolve("[[\"0\",\"color\",[\"Level\",\"Background\"],\"range\",[[[[0,5],[\"*\"]],\"#111111\"],[[[6,10],[\"*\"]],\"#222222\"]]]]"),
+        ["#222222"]
+    );
+    // a missing trait ends the group
+    assert!(resolve(
+        "[[\"0\",\"color\",[\"Background\",\"Mood\"],\"options\",[[[\"*\"],\"#000000\"]]]]"
+    )
+    .is_empty());
+
+    let parse = |images_base: &str| {
+        dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()]).err()
+    };
+    assert!(matches!(
+        parse("[[\"0\",\"uri\",[\"Background\",\"Rarity\"],\"raw\"]]"),
+        Some(Error::SchemaPatternMismatch)
+    ));
+    assert!(matches!(
+        parse("[[\"0\",\"uri\",[],\"options\",[]]]"),
+        Some(Error::SchemaInvalidTraitName)
+    ));
+    let parameters = dobs_parse_parameters(vec![
+        dob0_output.as_bytes(),
+        "[[\"0\",\"color\",[\"Background\",\"Rarity\"],\"options\",[[[\"Sky\"],\"#000000\"]]]]"
+            .as_bytes(),
+    ])
+    .expect("parse parameters failed");
+    assert!(matches!(
+        dobs_resolve_layers(&parameters),
+        Err(Error::SchemaInvalidArgsElement)
+    ));
+}
+
+#[test]
+fn test_weighted_random_pattern() {
+    let images_base = "[[\"0\",\"color\",\"DNA\",\"weighted\",[[1,\"#111111\"],[0,\"#222222\"],[3,\"#333333\"]]],[\"0\",\"color\",\"DNA\",\"weighted\",[[0,\"#444444\"],[5,\"#555555\"]]]]";
+    let resolve = |dob0_output: &str| {
+        let parameters =
+            dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()])
+                .expect("parse parameters failed");
+        let layer_groups = dobs_resolve_layers(&parameters).expect("resolve layers failed");
+        layer_groups[0]
+            .layers
+            .iter()
+            .map(|layer| layer.value.clone())
+            .collect::<Vec<_>>()
+    };
+    let mut drawn = BTreeSet::new();
+    for dna in 0..64u32 {
+        let dob0_output =
+            format!("[{{\"name\":\"DNA\",\"traits\":[{{\"String\":\"0x{dna:08x}\"}}]}}]");
+        let layers = resolve(&dob0_output);
+        // the same DNA always draws the same layers
+        assert_eq!(layers, resolve(&dob0_output));
+        assert_eq!(layers[1], "#555555");
+        drawn.insert(layers[0].clone());
+    }
+    assert!(drawn.contains("#111111") && drawn.contains("#333333"));
+    assert!(!drawn.contains("#222222"));
+
+    let images_base = "[[\"0\",\"color\",\"DNA\",\"weighted\",[[0,\"#111111\"]]]]";
+    let parameters = dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()])
+        .expect("parse parameters failed");
+    assert!(matches!(
+        dobs_resolve_layers(&parameters),
+        Err(Error::SchemaInvalidArgs)
+    ));
+    let stats = pattern_stats(&dobs_parse_parameters(vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"0\",\"color\",\"DNA\",\"weighted\",[[1,\"#111111\"],[0,\"#222222\"],[3,\"#333333\"]]]]".as_bytes(),
+    ])
+    .expect("parse parameters failed")
+    .images_base);
+    assert_eq!(stats[0].combinations, 2);
+}
+
+#[test]
+fn test_mod_and_bucket_patterns() {
+    let dob0_output = "[{\"name\":\"TokenId\",\"traits\":[{\"Number\":1234}]},{\"name\":\"Offset\",\"traits\":[{\"Number\":-1}]}]";
+    let resolve = |trait_name: &str, pattern: &str, args: &str| {
+        let images_base = format!("[[\"0\",\"color\",\"{trait_name}\",\"{pattern}\",{args}]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        dobs_parse_parameters(args).and_then(|parameters| {
+            dobs_resolve_layers(&parameters)
+                .map(|groups| groups[0].layers.first().map(|layer| layer.value.clone()))
+        })
+    };
+    // 1234 % 4 == 2
+    let modulo = "[4,[0,\"#000000\"],[[1,2],\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]";
+    assert_eq!(
+        resolve("TokenId", "mod", modulo).unwrap().as_deref(),
+        Some("#FF0000")
+    );
+    // remainders of negative numbers are never negative
+    assert_eq!(
+        resolve("Offset", "mod", modulo).unwrap().as_deref(),
+        Some("#FFFFFF")
+    );
+    // 1234 / 500 == 2
+    let bucket = "[500,\"#000000\",\"#111111\",\"#222222\"]";
+    assert_eq!(
+        resolve("TokenId", "bucket", bucket).unwrap().as_deref(),
+        Some("#222222")
+    );
+    assert_eq!(
+        resolve("TokenId", "bucket", "[1000,\"#000000\"]").unwrap(),
+        None
+    );
+    assert_eq!(resolve("Offset", "bucket", bucket).unwrap(), None);
+    for args in ["[0,\"#000000\"]", "[4]", "[\"4\",\"#000000\"]"] {
+        assert!(matches!(
+            resolve("TokenId", "bucket", args),
+            Err(Error::SchemaInvalidArgs)
+        ));
+    }
+}
+
+#[test]
+fn test_expr_pattern() {
+    let resolve = |expression: &str| {
+        let images_base = format!("[[\"0\",\"uri\",\"Score\",\"expr\",[\"{expression}\",[[0,10],\"btcfs://low\"],[[11,20],\"btcfs://mid\"],[[\"*\"],\"btcfs://high\"]]]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        dobs_parse_parameters(args).and_then(|parameters| {
+            dobs_resolve_layers(&parameters)
+                .map(|groups| groups[0].layers.first().map(|layer| layer.value.clone()))
+        })
+    };
+    // (136 + 23) / 10 == 15
+    assert_eq!(
+        resolve("(Score + Age) / 10").unwrap().as_deref(),
+        Some("btcfs://mid")
+    );
+    assert_eq!(
+        resolve("{Score} - Age * 2 % 7").unwrap().as_deref(),
+        Some("btcfs://high")
+    );
+    // -2 / 3 rounds down like bucket rows
+    assert_eq!(
+        resolve("-(Age - 21) / 3 + 1").unwrap().as_deref(),
+        Some("btcfs://low")
+    );
+    // a trait the expression reads is missing, so nothing matches
+    assert_eq!(resolve("Missing + 1").unwrap(), None);
+    assert!(matches!(
+        resolve("Score / (Age - 23)"),
+        Err(Error::ArithmeticOverflow)
+    ));
+    for expression in ["", "(Score", "Score +", "Score Age", "{}", "Score ^ 2"] {
+        assert!(
+            matches!(resolve(expression), Err(Error::SchemaInvalidArgs)),
+            "{expression}"
+        );
+    }
+    let nested = "(".repeat(64) + "1" + &")".repeat(64);
+    assert!(matches!(resolve(&nested), Err(Error::SchemaInvalidArgs)));
+    // and so are chains of too many operands, however flat
+    let chain = "0 + ".repeat(255) + "Score";
+    assert_eq!(resolve(&chain).unwrap().as_deref(), Some("btcfs://high"));
+    let chain = "0 + ".repeat(100_000) + "Score";
+    assert!(matches!(resolve(&chain), Err(Error::SchemaInvalidArgs)));
+}
+
+#[test]
+fn test_missing_trait_policy() {
+    let resolve = |policy: &str| {
+        let images_base = format!(
+            "[[\"0\",\"color\",\"Name\",\"options\",[[[\"*\"],\"#FFFFFF\"]]],[\"0\",\"uri\",\"Mood\",\"raw\",null,{{\"missing\":\"{policy}\"}}],[\"0\",\"uri\",\"URL\",\"raw\"]]"
+        );
+        let parameters =
+            dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()])
+                .expect("parse parameters failed");
+        dobs_resolve_layers(&parameters).map(|layer_groups| {
+            layer_groups[0]
+                .layers
+                .iter()
+                .map(|layer| layer.value.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+    assert_eq!(resolve("truncate").unwrap(), ["#FFFFFF"]);
+    assert_eq!(
+        resolve("skip").unwrap(),
+        ["#FFFFFF", "http://127.0.0.1:8090"]
+    );
+    assert_eq!(
+        resolve("default:ipfs://fallback").unwrap(),
+        ["#FFFFFF", "ipfs://fallback", "http://127.0.0.1:8090"]
+    );
+    assert!(matches!(resolve("error"), Err(Error::DecodeMissingTrait)));
+
+    let images_base = "[[\"0\",\"uri\",\"Mood\",\"raw\",null,{\"missing\":\"ignore\"}]]";
+    assert!(matches!(
+        dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()]),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_hex_encoded_args() {
+    let dob0_output = hex::encode(DOB0_OUTPUT);
+    let images_base = format!("0x{}", hex::encode(IMAGES_BASE));
+    let config = hex::encode("{\"mode\":\"traits\"}");
+    let parameters = dobs_parse_parameters(vec![
+        dob0_output.as_bytes(),
+        images_base.as_bytes(),
+        config.as_bytes(),
+    ])
+    .expect("parse parameters failed");
+    let expected = dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()])
+        .expect("parse parameters failed");
+    assert_eq!(parameters.images_base, expected.images_base);
+    assert!(parameters.config.mode() == OutputMode::Traits);
+
+    assert!(matches!(
+        dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), b"0x5b5dz"]),
+        Err(Error::ParseInvalidHexArg)
+    ));
+
+    // a json number made of hex digits stays json, only the `0x` prefix forces hex
+    assert_eq!(decode_hex_arg(b"10").unwrap().as_ref(), b"10");
+    assert_eq!(decode_hex_arg(b"0x10").unwrap().as_ref(), [0x10]);
+    assert_eq!(decode_hex_arg(b"5b5d").unwrap().as_ref(), b"[]");
+}
+
+fn molecule_bytes(bytes: &[u8]) -> generated::Bytes {
+    generated::Bytes::new_builder()
+        .set(bytes.iter().copied().map(Byte::new).collect())
+        .build()
+}
+
+fn molecule_number(number: u64) -> generated::Uint64 {
+    generated::Uint64::new_builder()
+        .set(number.to_le_bytes().map(Byte::new))
+        .build()
+}
+
+#[test]
+fn test_molecule_encoded_args() {
+    let dob0_output: Vec<Value> = serde_json::from_str(DOB0_OUTPUT).unwrap();
+    let entries = dob0_output.iter().map(|entry| {
+        let traits = entry["traits"].as_array().unwrap().iter().map(|value| {
+            let value = match (&value["String"], &value["Number"]) {
+                (Value::String(text), _) => {
+                    generated::TraitValueUnion::from(molecule_bytes(text.as_bytes()))
+                }
+                (_, number) => {
+                    generated::TraitValueUnion::from(molecule_number(number.as_u64().unwrap()))
+                }
+            };
+            generated::TraitValue::new_builder().set(value).build()
+        });
+        generated::TraitEntry::new_builder()
+            .name(molecule_bytes(entry["name"].as_str().unwrap().as_bytes()))
+            .traits(
+                generated::TraitValueVec::new_builder()
+                    .extend(traits)
+                    .build(),
+            )
+            .build()
+    });
+    let entries = generated::TraitEntryVec::new_builder()
+        .extend(entries)
+        .build();
+
+    let traits_pool: Vec<Vec<Value>> = serde_json::from_str(IMAGES_BASE).unwrap();
+    let rows = encode_molecule_rows(&traits_pool);
+
+    let tagged = |data: &[u8]| format!("{:02x}{}", MOLECULE_TAG, hex::encode(data));
+    let dob0_output = tagged(entries.as_slice());
+    let images_base = tagged(rows.as_slice());
+    let parameters = dobs_parse_parameters(vec![dob0_output.as_bytes(), images_base.as_bytes()])
+        .expect("parse parameters failed");
+    let expected = dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()])
+        .expect("parse parameters failed");
+    assert_eq!(parameters.images_base, expected.images_base);
+    assert_eq!(
+        serde_json::to_string(&parameters.dob0_output).unwrap(),
+        serde_json::to_string(&expected.dob0_output).unwrap()
+    );
+
+    let truncated = tagged(&rows.as_slice()[..8]);
+    assert!(matches!(
+        dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), truncated.as_bytes()]),
+        Err(Error::ParseInvalidTraitsBase)
+    ));
+}
+
+#[test]
+fn test_error_codes() {
+    let mut code = 1;
+    while let Some(error) = Error::from_code(code) {
+        assert_eq!(error.code(), code);
+        assert!(!error.name().is_empty() && !format!("{error}").is_empty());
+        code += 1;
+    }
+    assert_eq!(code, Error::SchemaUnsupportedEncoding as u64 + 1);
+    assert!(Error::from_code(0).is_none());
+    assert_eq!(
+        format!(
+            "{}",
+            Error::from_code(Error::ParseInvalidConfig as u64).unwrap()
+        ),
+        "the config is malformed or inconsistent"
+    );
+
+    let json = serde_json::from_str::<Value>("{").unwrap_err();
+    assert!(matches!(Error::from(json), Error::SerializeFailed));
+    let molecule = generated::BytesReader::from_slice(&[1]).unwrap_err();
+    assert!(matches!(
+        Error::from(molecule),
+        Error::ParseInvalidDOB0Output
+    ));
+}
+
+#[test]
+fn test_pattern_builder() {
+    let builder = || {
+        PatternBuilder::layer("0")
+            .color("Name")
+            .option("Alice", "#0000FF")
+            .option("Bob", "#00FF00")
+            .option("Ethan", "#FF0000")
+            .fallback("#FFFFFF")
+            .uri("Age")
+            .range(
+                0,
+                50,
+                "btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0",
+            )
+            .range(
+                51,
+                100,
+                "btcfs://eb3910b3e32a5ed9460bd0d75168c01ba1b8f00cc0faf83e4d8b67b48ea79676i0",
+            )
+            .fallback("btcfs://11b6303eb7d887d7ade459ac27959754cd55f9f9e50345ced8e1e8f47f4581fai0")
+            .uri("Score")
+            .range(
+                0,
+                1000,
+                "btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0",
+            )
+            .fallback("btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0")
+            .group("1")
+            .uri("Value")
+            .range(
+                0,
+                100000,
+                "btcfs://11d6cc654f4c0759bfee520966937a4304db2b33880c88c2a6c649e30c7b9aaei0",
+            )
+            .fallback("btcfs://e1484915b27e45b120239080fe5032580550ff9ff759eb26ee86bf8aaf90068bi0")
+    };
+    let expected: Value = serde_json::from_str(IMAGES_BASE).unwrap();
+    assert_eq!(builder().to_json().expect("json pattern"), expected);
+
+    let images_base = hex::encode(builder().to_molecule().expect("molecule pattern"));
+    let parameters = dobs_parse_parameters(vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()])
+        .expect("parse parameters failed");
+    assert_eq!(parameters.images_base, builder().build().unwrap());
+
+    // rows the decoder would reject fail to build, with the first misuse reported
+    let template_options = PatternBuilder::layer("0")
+        .uri("Name")
+        .template("ipfs://{Name}")
+        .option("Ethan", "ipfs://e");
+    assert!(matches!(
+        template_options.build(),
+        Err(Error::SchemaPatternMismatch)
+    ));
+    let gzip_color = PatternBuilder::layer("0").color("Name").gzip().raw();
+    assert!(matches!(gzip_color.build(), Err(Error::SchemaTypeMismatch)));
+    assert!(matches!(
+        PatternBuilder::layer("0").fallback("#FFFFFF").build(),
+        Err(Error::SchemaInsufficientElements)
+    ));
+    assert!(matches!(
+        PatternBuilder::layer("0").color("Name").raw().build(),
+        Err(Error::SchemaPatternMismatch)
+    ));
+}
+
+#[test]
+fn test_trait_schema_round_trip() {
+    let pattern = r##"[
+        ["0","color","Name","options",{"Ethan":"#FF0000","*":"#FFFFFF"},{"z":2,"alt":"{Name}"}],
+        ["0","uri",["Name","Age"],"options",[[["Ethan",[0,50]],"ipfs://young"]],{"missing":"skip"}],
+        ["0","text","Score","raw",null,{"text":{"x":0.25,"size":0.05,"color":"#123456"},"transform":{"x":4,"opacity":0.5}}],
+        ["1","uri","DNA","range",[[[0,100],"ipfs://a"]],{"endian":"little","missing":"default:ipfs://none","format":"svg","shape":{"rounded_rect":8},"mask":true}],
+        ["1","uri","*","raw","ipfs://static",{"vendor.tag":[1,2]}],
+        ["1","color","Value","mod",[3,[0,"#000000"],[[1,2],"#FFFFFF"]]]
+    ]"##;
+    let traits_base = decode_trait_schema(serde_json::from_str(pattern).unwrap()).unwrap();
+    let encoded = encode_trait_schema(traits_base.clone());
+    assert_eq!(decode_trait_schema(encoded.clone()).unwrap(), traits_base);
+    // encoding is stable, the decoded form writes back the same bytes
+    let decoded = decode_trait_schema(encoded.clone()).unwrap();
+    assert_eq!(
+        serde_json::to_string(&encode_trait_schema(decoded)).unwrap(),
+        serde_json::to_string(&encoded).unwrap()
+    );
+
+    // one layer read, tweaked and written back
+    let mut row = TraitSchema::from_value(&Value::Array(encoded[3].clone())).unwrap();
+    assert_eq!(row, traits_base[3]);
+    row.options.z = Some(-1);
+    let row = TraitSchema::from_value(&row.to_value()).unwrap();
+    assert_eq!(row.options.z, Some(-1));
+    assert!(matches!(
+        TraitSchema::from_value(&Value::from("0")),
+        Err(Error::ParseInvalidTraitsBase)
+    ));
+}
+
+#[test]
+fn test_strict_schema() {
+    let parse = |images_base: &str, config: &str| {
+        dobs_parse_parameters(vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ])
+        .err()
+    };
+    let extra_column = "[[\"0\",\"color\",\"Name\",\"options\",[],{},[[\"Ethan\",\"#FF0000\"]]]]";
+    let duplicate_row =
+        "[[\"0\",\"color\",\"Name\",\"options\",[]],[\"0\",\"uri\",\"Name\",\"options\",[]]]";
+    let shadowed_entry =
+        "[[\"0\",\"color\",\"Name\",\"options\",[[[\"*\"],\"#FFFFFF\"],[\"Ethan\",\"#FF0000\"]]]]";
+    for images_base in [extra_column, duplicate_row, shadowed_entry] {
+        assert!(parse(images_base, "{}").is_none());
+    }
+    assert!(matches!(
+        parse(extra_column, "{\"strict\":true}"),
+        Some(Error::SchemaUnexpectedColumn)
+    ));
+    assert!(matches!(
+        parse(duplicate_row, "{\"strict\":true}"),
+        Some(Error::SchemaDuplicateRow)
+    ));
+    assert!(matches!(
+        parse(shadowed_entry, "{\"strict\":true}"),
+        Some(Error::SchemaShadowedEntry)
+    ));
+    // static rows repeat freely and a catch-all may close the args
+    assert!(parse(
+        "[[\"0\",\"color\",\"*\",\"raw\",\"#000000\"],[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]]]",
+        "{\"strict\":true}"
+    )
+    .is_none());
+}
@@ -0,0 +1,28 @@
+use molecule::prelude::{Builder, Entity};
+
+use crate::generated::{Color, Item, ItemVec, OutputSize, RawImage, URI};
+
+#[test]
+fn test_item_constructors() {
+    let items = ItemVec::new_builder()
+        .push(Item::color("#FF0000"))
+        .push(Item::uri("btcfs://abc"))
+        .push(Item::raw(&[0x89, b'P', b'N', b'G']))
+        .push(Item::output_size(64, 48))
+        .build();
+    let items = ItemVec::from_slice(items.as_slice()).expect("invalid molecule");
+
+    let color = Color::try_from(items.get(0).unwrap()).expect("not a color");
+    assert_eq!(color.text().as_deref(), Some("#FF0000"));
+    let uri = URI::try_from(items.get(1).unwrap()).expect("not a uri");
+    assert_eq!(uri.text().as_deref(), Some("btcfs://abc"));
+    let image = RawImage::try_from(items.get(2).unwrap()).expect("not a raw image");
+    assert_eq!(image.raw_data().as_ref(), [0x89, b'P', b'N', b'G']);
+    let size = OutputSize::try_from(items.get(3).unwrap()).expect("not a size");
+    assert_eq!(size.width().as_slice(), 64u32.to_le_bytes());
+
+    // the item comes back when it holds something else
+    let item = items.get(0).unwrap();
+    let err = URI::try_from(item.clone()).expect_err("a color is no uri");
+    assert_eq!(err.as_slice(), item.as_slice());
+}
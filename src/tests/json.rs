@@ -0,0 +1,100 @@
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use serde_json::Value;
+
+use super::decoder::{DOB0_OUTPUT, IMAGES_BASE};
+use crate::decoder::{dobs_decode, json, types::ParsedTrait};
+
+#[test]
+fn test_json_parse() {
+    for text in [
+        DOB0_OUTPUT,
+        IMAGES_BASE,
+        " { \"a\" : [ 1 , -2 , 0.5 , 1e3 , -0 , 18446744073709551615 ] , \"b\" : null } ",
+        "[true,false,\"\\u00e9\\ud83d\\ude00\\n\\\"\\/\",{}]",
+        "[-9223372036854775808,18446744073709551616,1.5E-7,123456789012345678901234567890]",
+        "\"\"",
+    ] {
+        let expected: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(json::parse(text.as_bytes()).unwrap(), expected, "{text}");
+    }
+    for text in [
+        "",
+        "[",
+        "[1,]",
+        "{\"a\"}",
+        "01",
+        "1.",
+        "-",
+        "1e",
+        "nul",
+        "\"\\x\"",
+        "\"\\ud800\"",
+        "[1] 2",
+    ] {
+        assert!(json::parse(text.as_bytes()).is_err(), "{text}");
+    }
+    let deep = "[".repeat(200) + &"]".repeat(200);
+    assert!(json::parse(deep.as_bytes()).is_err());
+}
+
+#[test]
+fn test_json_write() {
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let output = dobs_decode(args, |buffer, buffer_size, _| {
+        *buffer_size = 3;
+        if buffer.len() >= 3 {
+            buffer[..3].copy_from_slice(b"\t\"\x01");
+        }
+        0
+    })
+    .expect("decode failed");
+    let mut bytes = Vec::new();
+    json::write(&output, &mut bytes).unwrap();
+    assert_eq!(bytes, serde_json::to_vec(&output).unwrap());
+
+    let value = serde_json::json!({
+        "z": [1, -1, 0.25, 1e300, null, true, "\u{1f}\\"],
+        "a": {"": []},
+    });
+    let mut bytes = Vec::new();
+    json::write(&value, &mut bytes).unwrap();
+    assert_eq!(bytes, serde_json::to_vec(&value).unwrap());
+}
+
+#[test]
+fn test_json_canonical() {
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let output = dobs_decode(args, |buffer, buffer_size, _| {
+        *buffer_size = 3;
+        if buffer.len() >= 3 {
+            buffer[..3].copy_from_slice(b"\t\"\x01");
+        }
+        0
+    })
+    .expect("decode failed");
+    // without floats the bytes are serde_json's of the sorted map
+    let sorted = serde_json::to_value(&output).unwrap();
+    assert_eq!(
+        json::to_canonical_vec(&output).unwrap(),
+        serde_json::to_vec(&sorted).unwrap()
+    );
+
+    #[derive(serde::Serialize)]
+    struct Unordered {
+        z: Vec<ParsedTrait>,
+        a: Value,
+    }
+    let value = Unordered {
+        z: vec![
+            ParsedTrait::Float(1e21),
+            ParsedTrait::Float(-0.0),
+            ParsedTrait::Number(i128::MAX),
+            ParsedTrait::String("\u{1f}é\"".to_owned()),
+        ],
+        a: serde_json::json!({"b\"": 1, "b": null}),
+    };
+    assert_eq!(
+        String::from_utf8(json::to_canonical_vec(&value).unwrap()).unwrap(),
+        "{\"a\":{\"b\":null,\"b\\\"\":1},\"z\":[{\"Float\":\"1000000000000000000000\"},{\"Float\":\"-0\"},{\"Number\":170141183460469231731687303715884105727},{\"String\":\"\\u001fé\\\"\"}]}"
+    );
+}
@@ -1 +1,9 @@
+#[cfg(feature = "compositor")]
+mod compositor;
 mod decoder;
+mod items;
+mod json;
+mod output;
+mod pipeline;
+#[cfg(feature = "std")]
+mod simulator;
@@ -0,0 +1,144 @@
+use crate::decoder::{
+    output::{
+        frame, frame_chunks, frame_dob1_output, frame_dob1_prelude, unchunk, unframe, Channel,
+    },
+    types::{DOB0Output, DOB1Output, DisplayTrait, Image, ImageMetadata, ParsedTrait},
+};
+use crate::log::{self, Level};
+use crate::stats::{self, Counter, Counters, Stats};
+
+#[test]
+fn test_frame_dob1_output_roundtrip() {
+    let output = DOB1Output {
+        traits: Some(vec![DOB0Output {
+            name: "Name".to_owned(),
+            traits: vec![ParsedTrait::String("Ethan".to_owned())],
+        }]),
+        images: vec![Image {
+            name: "0".to_owned(),
+            type_: "image/png;base64".to_owned(),
+            content: "aGVsbG8=".to_owned(),
+            sources: vec![],
+            preview: false,
+            metadata: ImageMetadata::default(),
+            alt: None,
+            error: None,
+            items: None,
+            combine_error: None,
+            ref_: None,
+        }],
+        layers: None,
+        display: None,
+        context: Some(serde_json::json!({"spore_id": "0x01"})),
+        stats: None,
+        report: None,
+    };
+    let stream = frame_dob1_output(&output).unwrap().concat();
+
+    let (channel, traits, rest) = unframe(&stream).expect("traits frame");
+    assert_eq!(channel, Channel::Traits);
+    assert_eq!(
+        traits,
+        b"[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]}]"
+    );
+    let (channel, context, rest) = unframe(rest).expect("context frame");
+    assert_eq!(channel, Channel::Context);
+    assert_eq!(context, b"{\"spore_id\":\"0x01\"}");
+    let (channel, image, rest) = unframe(rest).expect("image frame");
+    assert_eq!(channel, Channel::Image);
+    assert_eq!(
+        image,
+        b"{\"name\":\"0\",\"type\":\"image/png;base64\",\"content\":\"aGVsbG8=\"}"
+    );
+    assert!(rest.is_empty());
+
+    let json = serde_json::to_string(&output).unwrap();
+    assert!(json.ends_with(",\"context\":{\"spore_id\":\"0x01\"}}"));
+}
+
+#[test]
+fn test_unframe_rejects_truncated_frame() {
+    let framed = frame(Channel::Diagnostic, b"{\"error\":1}");
+    assert_eq!(&framed[..9], b"D0000000b");
+    assert!(unframe(&framed[..framed.len() - 1]).is_none());
+    assert!(unframe(b"X00000000\0").is_none());
+}
+
+#[test]
+fn test_frame_dob1_prelude_order() {
+    let traits = vec![DOB0Output {
+        name: "Age".to_owned(),
+        traits: vec![ParsedTrait::Number(23)],
+    }];
+    let display = vec![DisplayTrait {
+        name: "年龄".to_owned(),
+        value: "23".to_owned(),
+    }];
+    let stream = frame_dob1_prelude(Some(&traits), Some(&display), None, None)
+        .unwrap()
+        .concat();
+    let (channel, _, rest) = unframe(&stream).expect("traits frame");
+    assert_eq!(channel, Channel::Traits);
+    let (channel, payload, rest) = unframe(rest).expect("display frame");
+    assert_eq!(channel, Channel::Display);
+    assert_eq!(payload, "[{\"name\":\"年龄\",\"value\":\"23\"}]".as_bytes());
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn test_log_record_frame() {
+    let record = log::record(Level::Warn, format_args!("trait {} missing", "Age")).unwrap();
+    let (channel, payload, rest) = unframe(&record).expect("log frame");
+    assert_eq!(channel, Channel::Diagnostic);
+    assert_eq!(
+        payload,
+        b"{\"level\":\"warn\",\"message\":\"trait Age missing\"}"
+    );
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn test_stats_output() {
+    let counters = Counters::default();
+    counters.add(Counter::SchemasParsed, 3);
+    let snapshot = counters.snapshot();
+    if stats::ENABLED {
+        assert_eq!(snapshot.schemas_parsed, 3);
+    } else {
+        assert_eq!(snapshot.schemas_parsed, 0);
+    }
+
+    let output = DOB1Output {
+        traits: Some(vec![]),
+        images: vec![],
+        layers: None,
+        display: None,
+        context: None,
+        stats: Some(Stats {
+            combine_syscalls: 2,
+            ..Default::default()
+        }),
+        report: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        "{\"traits\":[],\"images\":[],\"stats\":{\"schemas_parsed\":0,\"layers_matched\":0,\"combine_syscalls\":2,\"write_syscalls\":0,\"syscall_budget\":0,\"peak_heap\":0,\"output_bytes\":0}}"
+    );
+}
+
+#[test]
+fn test_chunk_roundtrip() {
+    let output = b"{\"traits\":[],\"images\":[]}";
+    let chunks = frame_chunks(output, 10);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(&chunks[0][..25], b"K0000001a0000000000000003");
+    assert!(chunks.iter().all(|chunk| chunk.len() <= 9 + 16 + 10 + 1));
+    assert_eq!(unchunk(&chunks.concat()).as_deref(), Some(&output[..]));
+    // a missing or reordered chunk never reassembles
+    assert_eq!(
+        unchunk(&[chunks[0].clone(), chunks[2].clone()].concat()),
+        None
+    );
+    assert_eq!(unchunk(&chunks[..2].concat()), None);
+    assert_eq!(unchunk(&frame_chunks(b"", 10).concat()), Some(vec![]));
+}
@@ -0,0 +1,1431 @@
+use alloc::{format, vec, vec::Vec};
+
+use super::decoder::{DOB0_OUTPUT, IMAGES_BASE};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use molecule::prelude::{Builder, Entity, Reader};
+
+use crate::decoder::{
+    dobs_decode,
+    input::decode_dob0_output,
+    output::{unchunk, unframe, Channel},
+    pipeline::{
+        dobs_run, dobs_run_host, COMBINE_FETCH_FAILED, COMBINE_INTEGRITY_MISMATCH,
+        COMBINE_UNDECODABLE_ASSET, COMBINE_UNSUPPORTED_ITEM,
+    },
+    types::{Error, ParsedTrait},
+};
+use crate::generated::{
+    Image, ImageVec, ItemUnionReader, ItemVecReader, ItemVecVecReader, OutputReader,
+};
+use crate::host::{Host, SyscallTable};
+
+// drives `dobs_run` exactly as the binary's `main` does, with `combiner` and `sink`
+// standing in for the image combining and debug write syscalls
+fn run_with<C, W>(argv: &[&str], combiner: C, sink: W) -> u64
+where
+    C: FnMut(&mut [u8], &mut u64, &[u8]) -> u64,
+    W: FnMut(&[u8]),
+{
+    let args = argv.iter().map(|arg| arg.as_bytes()).collect();
+    dobs_run(args, combiner, sink)
+}
+
+// reports the size of `image` and fills the buffer with it whenever it fits
+fn fixed_combiner<'a>(
+    image: &'static [u8],
+    calls: &'a mut usize,
+) -> impl FnMut(&mut [u8], &mut u64, &[u8]) -> u64 + 'a {
+    move |buffer, buffer_size, _| {
+        *calls += 1;
+        *buffer_size = image.len() as u64;
+        if buffer.len() >= image.len() {
+            buffer[..image.len()].copy_from_slice(image);
+        }
+        0
+    }
+}
+
+#[test]
+fn test_run_blob_output() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    // both images fit the initial buffer, one combine call each
+    assert_eq!(calls, 2);
+    assert_eq!(chunks.len(), 1);
+    let (json, nul) = chunks[0].split_at(chunks[0].len() - 1);
+    assert_eq!(nul, b"\0");
+    let output: serde_json::Value = serde_json::from_slice(json).unwrap();
+    let images = output["images"].as_array().unwrap();
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0]["name"], "0");
+    assert_eq!(images[0]["content"], "cG5n");
+    assert_eq!(output["traits"].as_array().unwrap().len(), 6);
+}
+
+#[test]
+fn test_run_host() {
+    struct Recorder {
+        calls: usize,
+        output: Vec<u8>,
+    }
+
+    impl Host for Recorder {
+        fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+            self.calls += 1;
+            fixed_combiner(b"png", &mut 0)(buffer, buffer_size, pattern)
+        }
+
+        fn write(&mut self, output: &[u8]) -> u64 {
+            self.output.extend_from_slice(output);
+            0
+        }
+    }
+
+    let mut args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        b"--syscall-write=3001",
+        IMAGES_BASE.as_bytes(),
+        b"--syscall-combine=3000",
+    ];
+    let syscalls = SyscallTable::default().take_overrides(&mut args).unwrap();
+    assert_eq!(
+        syscalls,
+        SyscallTable {
+            combine: 3000,
+            write: 3001,
+            ..SyscallTable::DOB_SERVER
+        }
+    );
+    assert_eq!(args, [DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()]);
+    // batching is only there for hosts opting in
+    assert_eq!(SyscallTable::DOB_SERVER.combine_batch, None);
+    let mut flags = vec!["--syscall-combine-batch=2078".as_bytes()];
+    let syscalls = SyscallTable::DOB_SERVER.take_overrides(&mut flags).unwrap();
+    assert_eq!(
+        syscalls.combine_batch,
+        Some(SyscallTable::PROPOSED_COMBINE_BATCH)
+    );
+    for flag in ["--syscall-write", "--syscall-write=x", "--syscall-exit=93"] {
+        let mut args = vec![flag.as_bytes()];
+        assert!(matches!(
+            SyscallTable::DOB_SERVER.take_overrides(&mut args),
+            Err(Error::ParseInvalidFlag)
+        ));
+    }
+
+    let mut host = Recorder {
+        calls: 0,
+        output: Vec::new(),
+    };
+    assert_eq!(dobs_run_host(args, &mut host), 0);
+    assert_eq!(host.calls, 2);
+    assert_eq!(host.output.last(), Some(&0));
+}
+
+#[test]
+fn test_run_combiner_mime_type() {
+    let mut calls = 0;
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let dob1_output = dobs_decode(args, fixed_combiner(b"\0image/webp\0webp", &mut calls))
+        .expect("decode failed");
+    assert_eq!(dob1_output.images[0].type_, "image/webp;base64");
+    assert_eq!(dob1_output.images[0].content, STANDARD.encode("webp"));
+
+    // a header that never ends or carries parameters is rejected
+    for image in [
+        &b"\0image/webp"[..],
+        b"\0image/webp;q=1\0webp",
+        b"\0webp\0webp",
+    ] {
+        let mut calls = 0;
+        let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+        let result = dobs_decode(args, fixed_combiner(image, &mut calls));
+        assert!(matches!(result, Err(Error::ComposeImageFailed)));
+    }
+    let mut calls = 0;
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let result = dobs_decode(args, fixed_combiner(b"\0image/\xff\0webp", &mut calls));
+    assert!(matches!(result, Err(Error::OutputNotUtf8)));
+}
+
+#[test]
+fn test_run_integrity_mismatch() {
+    let digest = "ab".repeat(32);
+    let images_base = format!("[[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"ipfs://asset\",\"sha256:{digest}\"]]],[\"1\",\"uri\",\"URL\",\"raw\"]]");
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]| {
+        let items = ItemVecReader::from_slice(pattern).unwrap();
+        let checked = items.iter().find_map(|item| match item.to_enum() {
+            ItemUnionReader::Integrity(integrity) => Some(integrity.sha256().as_slice().to_vec()),
+            _ => None,
+        });
+        if let Some(sha256) = checked {
+            assert_eq!(sha256, [0xab; 32]);
+            return COMBINE_INTEGRITY_MISMATCH;
+        }
+        *buffer_size = 3;
+        buffer[..3].copy_from_slice(b"png");
+        0
+    };
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let dob1_output = dobs_decode(args, combiner).expect("decode failed");
+    assert_eq!(dob1_output.images[0].type_, "integrity-failed");
+    assert_eq!(
+        dob1_output.images[0].error,
+        Some(Error::ComposeIntegrityMismatch as u64)
+    );
+    assert_eq!(dob1_output.images[1].type_, "image/png;base64");
+
+    let images_base =
+        "[[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",\"ipfs://asset\",\"sha256:abcd\"]]]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_decode(args, combiner),
+        Err(Error::SchemaInvalidArgsElement)
+    ));
+}
+
+#[test]
+fn test_run_combiner_errors() {
+    let images_base = "[[\"0\",\"uri\",\"*\",\"raw\",\"btcfs://dead\"],[\"1\",\"uri\",\"*\",\"raw\",\"btcfs://alive\"]]";
+    let combiner = |answer: u64| {
+        move |buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]| {
+            let items = ItemVecReader::from_slice(pattern).unwrap();
+            let dead = items.iter().any(|item| match item.to_enum() {
+                ItemUnionReader::URI(uri) => uri.raw_data() == b"btcfs://dead",
+                _ => false,
+            });
+            if dead {
+                return answer;
+            }
+            *buffer_size = 3;
+            if buffer.len() >= 3 {
+                buffer[..3].copy_from_slice(b"png");
+            }
+            0
+        }
+    };
+    let decode = |answer: u64, config: &str| {
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            config.as_bytes(),
+        ];
+        dobs_decode(args, combiner(answer))
+    };
+    for (answer, error) in [
+        (COMBINE_FETCH_FAILED, Error::ComposeFetchFailed),
+        (COMBINE_UNDECODABLE_ASSET, Error::ComposeUndecodableAsset),
+        (COMBINE_UNSUPPORTED_ITEM, Error::ComposeUnsupportedItem),
+    ] {
+        assert_eq!(
+            decode(answer, "{}").err().map(|err| err as u64),
+            Some(error as u64)
+        );
+        // recovered images name both the decoder's error and the combiner's answer
+        let output = decode(answer, "{\"recover\":true}").expect("decode failed");
+        assert_eq!(output.images[0].error, Some(error as u64));
+        assert_eq!(output.images[0].combine_error, Some(answer));
+        assert_eq!(output.images[1].type_, "image/png;base64");
+        assert!(output.images[1].combine_error.is_none());
+    }
+}
+
+#[test]
+fn test_run_batch_tokens() {
+    let tokens = format!("[[\"0x01\",{DOB0_OUTPUT}],[\"0x02\",[{{\"name\":\"Name\",\"traits\":[{{\"String\":\"Alice\"}}]}}]]]");
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[&tokens, IMAGES_BASE],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(chunks.len(), 1);
+    let outputs: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let outputs = outputs.as_array().unwrap();
+    assert_eq!(outputs.len(), 2);
+    assert_eq!(outputs[0]["token_id"], "0x01");
+    assert_eq!(outputs[0]["images"].as_array().unwrap().len(), 2);
+    assert_eq!(outputs[1]["token_id"], "0x02");
+    assert_eq!(outputs[1]["traits"][0]["traits"][0]["String"], "Alice");
+    // the second token has no Age, so its group ends after the name color
+    assert_eq!(outputs[1]["images"].as_array().unwrap().len(), 2);
+
+    let code = run_with(
+        &[&tokens, IMAGES_BASE, "{\"output\":\"framed\"}"],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, Error::ParseInvalidConfig as u64);
+    let args = vec![tokens.as_bytes(), IMAGES_BASE.as_bytes()];
+    assert!(matches!(
+        dobs_decode(args, fixed_combiner(b"png", &mut calls)),
+        Err(Error::ParseInvalidDOB0Output)
+    ));
+}
+
+#[test]
+fn test_run_animated_frames() {
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[[\"*\"],\"#000000\"]]],[\"0\",\"uri\",\"Name\",\"options\",[[\"Ethan\",[\"btcfs://a\",\"btcfs://b\"]]],{\"frames\":120}]]";
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]| {
+        let items = ItemVecReader::from_slice(pattern).unwrap();
+        assert_eq!(items.len(), 2);
+        let ItemUnionReader::Frames(frames) = items.get(1).unwrap().to_enum() else {
+            panic!("the stacked uris should be a single animated layer");
+        };
+        assert_eq!(frames.duration().as_slice(), 120u32.to_le_bytes());
+        let uris = frames.uris();
+        assert_eq!(uris.len(), 2);
+        assert_eq!(uris.get(1).unwrap().raw_data(), b"btcfs://b");
+        *buffer_size = 4;
+        buffer[..4].copy_from_slice(b"apng");
+        0
+    };
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    let dob1_output = dobs_decode(args, combiner).expect("decode failed");
+    assert_eq!(dob1_output.images[0].type_, "image/apng;base64");
+
+    // the layers output lists every frame
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        b"{\"mode\":\"traits\"}",
+    ];
+    let dob1_output = dobs_decode(args, |_, _, _| 1).expect("decode failed");
+    let layers = serde_json::to_value(dob1_output.layers.unwrap()).unwrap();
+    assert_eq!(
+        layers[0]["layers"][1]["animation"],
+        serde_json::json!({"duration": 120, "frames": ["btcfs://a", "btcfs://b"]})
+    );
+
+    for options in ["{\"frames\":0}", "{\"frames\":-1}"] {
+        let images_base = format!("[[\"0\",\"uri\",\"URL\",\"raw\",null,{options}]]");
+        let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_decode(args, |_, _, _| 1),
+            Err(Error::SchemaInvalidOptions)
+        ));
+    }
+    let images_base =
+        "[[\"0\",\"color\",\"Name\",\"options\",[[[\"*\"],\"#000000\"]],{\"frames\":100}]]";
+    let args = vec![DOB0_OUTPUT.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_decode(args, |_, _, _| 1),
+        Err(Error::SchemaInvalidOptions)
+    ));
+}
+
+#[test]
+fn test_run_cluster_config() {
+    let dob0_output = "[{\"name\":\"Asset\",\"traits\":[{\"String\":\"ar://abc\"}]}]";
+    let images_base = "[[\"0\",\"uri\",\"Asset\",\"raw\"]]";
+    let cluster = "{\"canvas\":{\"width\":64,\"height\":48},\"background\":\"#000000\",\"mime\":\"image/webp\",\"uri_aliases\":{\"ar\":\"https://arweave.net/\"}}";
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]| {
+        let items = ItemVecReader::from_slice(pattern).unwrap();
+        let ItemUnionReader::OutputSize(size) = items.get(0).unwrap().to_enum() else {
+            panic!("the canvas should lead the pattern");
+        };
+        assert_eq!(size.width().as_slice(), 64u32.to_le_bytes());
+        assert_eq!(size.height().as_slice(), 48u32.to_le_bytes());
+        let ItemUnionReader::Color(color) = items.get(1).unwrap().to_enum() else {
+            panic!("the background should be the bottom layer");
+        };
+        assert_eq!(color.raw_data(), b"#000000");
+        let ItemUnionReader::URI(uri) = items.get(2).unwrap().to_enum() else {
+            panic!("the asset should be a URI");
+        };
+        assert_eq!(uri.raw_data(), b"https://arweave.net/abc");
+        *buffer_size = 4;
+        buffer[..4].copy_from_slice(b"webp");
+        0
+    };
+    let args = vec![
+        dob0_output.as_bytes(),
+        images_base.as_bytes(),
+        b"{}",
+        b"{}",
+        cluster.as_bytes(),
+    ];
+    let dob1_output = dobs_decode(args, combiner).expect("decode failed");
+    assert_eq!(dob1_output.images[0].type_, "image/webp;base64");
+
+    for cluster in [
+        "{\"background\":\"black\"}",
+        "{\"mime\":\"webp\"}",
+        "{\"canvas\":64}",
+    ] {
+        let args = vec![
+            dob0_output.as_bytes(),
+            images_base.as_bytes(),
+            b"{}",
+            b"{}",
+            cluster.as_bytes(),
+        ];
+        assert!(matches!(
+            dobs_decode(args, combiner),
+            Err(Error::ParseInvalidClusterConfig)
+        ));
+    }
+}
+
+#[test]
+fn test_run_canvas_row() {
+    let dob0_output = "[{\"name\":\"Asset\",\"traits\":[{\"String\":\"ipfs://abc\"}]}]";
+    let images_base = "[[\"0\",\"canvas\",{\"width\":512,\"height\":256,\"background\":\"#FFFFFF\"}],[\"0\",\"uri\",\"Asset\",\"raw\"],[\"1\",\"uri\",\"Asset\",\"raw\"]]";
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]| {
+        let items = ItemVecReader::from_slice(pattern).unwrap();
+        match items.get(0).unwrap().to_enum() {
+            ItemUnionReader::Canvas(canvas) => {
+                assert_eq!(canvas.width().as_slice(), 512u32.to_le_bytes());
+                assert_eq!(canvas.height().as_slice(), 256u32.to_le_bytes());
+                assert_eq!(canvas.background().raw_data(), b"#FFFFFF");
+                assert_eq!(items.len(), 2);
+            }
+            // a group without a canvas row leaves the size to the combiner
+            ItemUnionReader::URI(_) => assert_eq!(items.len(), 1),
+            _ => panic!("the canvas should lead the pattern"),
+        }
+        *buffer_size = 3;
+        buffer[..3].copy_from_slice(b"png");
+        0
+    };
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let dob1_output = dobs_decode(args, combiner).expect("decode failed");
+    assert_eq!(dob1_output.images.len(), 2);
+
+    for canvas in [
+        "{\"width\":0,\"height\":256}",
+        "{\"width\":512,\"height\":256,\"background\":\"white\"}",
+        "512",
+    ] {
+        let images_base =
+            format!("[[\"0\",\"canvas\",{canvas}],[\"0\",\"uri\",\"Asset\",\"raw\"]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_decode(args, combiner),
+            Err(Error::SchemaInvalidArgs)
+        ));
+    }
+}
+
+#[test]
+fn test_run_mime_row() {
+    let dob0_output = "[{\"name\":\"Asset\",\"traits\":[{\"String\":\"ipfs://abc\"}]}]";
+    let images_base = "[[\"1\",\"mime\",\"image/webp\"],[\"0\",\"uri\",\"Asset\",\"raw\"],[\"1\",\"uri\",\"Asset\",\"raw\"]]";
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+        *buffer_size = 3;
+        buffer[..3].copy_from_slice(b"img");
+        0
+    };
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    let dob1_output = dobs_decode(args, combiner).expect("decode failed");
+    assert_eq!(dob1_output.images[0].type_, "image/png;base64");
+    assert_eq!(dob1_output.images[1].type_, "image/webp;base64");
+
+    // a combiner composing another type than the declared one
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+        let image = b"\0image/gif\0img";
+        *buffer_size = image.len() as u64;
+        if buffer.len() >= image.len() {
+            buffer[..image.len()].copy_from_slice(image);
+        }
+        0
+    };
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_decode(args, combiner),
+        Err(Error::ComposeImageFailed)
+    ));
+
+    let images_base = "[[\"0\",\"mime\",\"image/png\"],[\"0\",\"uri\",\"Asset\",\"raw\",null,{\"format\":\"svg\"}]]";
+    let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+    assert!(matches!(
+        dobs_decode(args, combiner),
+        Err(Error::ComposeImageFailed)
+    ));
+
+    for rows in [
+        "[\"0\",\"mime\",\"text/plain\"]",
+        "[\"0\",\"mime\",\"image/png\"],[\"0\",\"mime\",\"image/png\"]",
+    ] {
+        let images_base = format!("[{rows},[\"0\",\"uri\",\"Asset\",\"raw\"]]");
+        let args = vec![dob0_output.as_bytes(), images_base.as_bytes()];
+        assert!(matches!(
+            dobs_decode(args, combiner),
+            Err(Error::SchemaInvalidArgs)
+        ));
+    }
+}
+
+#[test]
+fn test_run_commitments() {
+    let commitment = |image: &serde_json::Value| {
+        let items = hex::decode(image["items"].as_str().unwrap()).unwrap();
+        let mut hash = [0u8; 32];
+        let mut blake2b = blake2b_ref::Blake2bBuilder::new(32)
+            .personal(b"ckb-default-hash")
+            .build();
+        blake2b.update(&3u64.to_le_bytes());
+        blake2b.update(b"png");
+        blake2b.update(&items);
+        blake2b.finalize(&mut hash);
+        hex::encode(hash)
+    };
+    let images_base = format!("[[\"commit\",true],{}", &IMAGES_BASE[1..]);
+    for argv in [
+        [DOB0_OUTPUT, IMAGES_BASE, "--commit"],
+        [DOB0_OUTPUT, &images_base, "{}"],
+    ] {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(&argv, fixed_combiner(b"png", &mut calls), |chunk| {
+            chunks.push(chunk.to_vec())
+        });
+        assert_eq!(code, 0);
+        let output: serde_json::Value =
+            serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+        for image in output["images"].as_array().unwrap() {
+            assert_eq!(image["type"], "image/png;commitment");
+            assert_eq!(image["content"], commitment(image).as_str());
+        }
+    }
+
+    let images_base = format!("[[\"commit\",\"yes\"],{}", &IMAGES_BASE[1..]);
+    let mut calls = 0;
+    let code = run_with(
+        &[DOB0_OUTPUT, &images_base],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, Error::SchemaInvalidDirective as u64);
+}
+
+#[test]
+fn test_run_framed_output() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"output\":\"framed\"}"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    let channels = chunks
+        .iter()
+        .map(|chunk| {
+            let (channel, _, rest) = unframe(chunk).expect("frame");
+            assert!(rest.is_empty());
+            channel
+        })
+        .filter(|channel| *channel != Channel::Diagnostic)
+        .collect::<Vec<_>>();
+    assert_eq!(channels, [Channel::Traits, Channel::Image, Channel::Image]);
+}
+
+#[test]
+fn test_run_chunked_output() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            IMAGES_BASE,
+            "{\"output\":\"chunked\",\"chunk_size\":64}",
+        ],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert!(chunks.len() > 1);
+    let output = unchunk(&chunks.concat()).expect("reassemble chunks");
+    let output: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(output["images"].as_array().unwrap().len(), 2);
+    assert_eq!(output["images"][0]["content"], "cG5n");
+}
+
+#[test]
+fn test_run_traits_only_skips_combiner() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "--traits-only"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(calls, 0);
+    assert_eq!(chunks.len(), 1);
+}
+
+#[test]
+fn test_run_output_modes() {
+    let run = |images_base: &str, config: &str| {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &[DOB0_OUTPUT, images_base, config],
+            fixed_combiner(b"png", &mut calls),
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        assert_eq!(code, 0);
+        let output: serde_json::Value =
+            serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+        (output, calls)
+    };
+    let (output, calls) = run(IMAGES_BASE, "{\"mode\":\"images\",\"locale\":\"en\"}");
+    assert_eq!(calls, 2);
+    assert!(output.get("traits").is_none() && output.get("display").is_none());
+    assert_eq!(output["images"].as_array().unwrap().len(), 2);
+
+    // the pattern's directive applies unless the config picks a mode
+    let images_base = format!("[[\"mode\",\"traits\"],{}", &IMAGES_BASE[1..]);
+    let (output, calls) = run(&images_base, "{}");
+    assert_eq!(calls, 0);
+    assert!(output["images"].as_array().unwrap().is_empty());
+    assert_eq!(output["layers"].as_array().unwrap().len(), 2);
+    let (output, calls) = run(&images_base, "{\"mode\":\"full\"}");
+    assert_eq!(calls, 2);
+    assert!(output.get("layers").is_none());
+    assert_eq!(output["traits"].as_array().unwrap().len(), 6);
+}
+
+#[test]
+fn test_run_traits_only_pattern() {
+    let images_base = "[[\"i18n\",{\"zh\":{\"Name\":{\"name\":\"名字\"}}}]]";
+    let cases = [
+        (images_base, "{}"),
+        (images_base, "{\"locale\":\"zh\"}"),
+        (images_base, "{\"mode\":\"traits\"}"),
+        (images_base, "{\"mode\":\"images\"}"),
+        // images disabled by the mode compose nothing either
+        (IMAGES_BASE, "{\"mode\":\"traits\"}"),
+    ];
+    for (images_base, config) in cases {
+        let argv = [DOB0_OUTPUT, images_base, config];
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &argv,
+            |_, _, _| panic!("a run without images composes nothing"),
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        assert_eq!(code, 0);
+        // the output the decoding without writing gives, apart from the stats of `trace`
+        // builds which only a run has
+        let args = argv.iter().map(|arg| arg.as_bytes()).collect();
+        let decoded = dobs_decode(args, |_, _, _| 1).expect("decode failed");
+        let expected = serde_json::to_value(&decoded).unwrap();
+        let [chunk] = chunks.as_slice() else {
+            panic!("one output chunk expected");
+        };
+        let mut output: serde_json::Value =
+            serde_json::from_slice(&chunk[..chunk.len() - 1]).unwrap();
+        output.as_object_mut().unwrap().remove("stats");
+        assert_eq!(output, expected);
+    }
+
+    // the report still counts the layers the traits mode resolves
+    let argv = [
+        DOB0_OUTPUT,
+        IMAGES_BASE,
+        "{\"mode\":\"traits\"}",
+        "--report-only",
+    ];
+    let mut chunks = Vec::new();
+    let code = run_with(&argv, |_, _, _| 1, |chunk| chunks.push(chunk.to_vec()));
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert!(output["report"]["layers_matched"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn test_run_output_encodings() {
+    let run = |dob0_output: &str, config: &str| {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &[dob0_output, IMAGES_BASE, config],
+            fixed_combiner(b"png", &mut calls),
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        (code, chunks)
+    };
+    let (code, chunks) = run(DOB0_OUTPUT, "{\"encoding\":\"cbor\",\"mode\":\"images\"}");
+    assert_eq!(code, 0);
+    let cbor = hex::decode(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    // a map, `trace` builds add the stats, whose first key is the 6 byte text "images"
+    // holding an array of 2
+    assert_eq!(cbor[0] >> 5, 5);
+    assert_eq!(cbor[1..9], [0x66, b'i', b'm', b'a', b'g', b'e', b's', 0x82]);
+
+    let (code, chunks) = run(DOB0_OUTPUT, "{\"encoding\":\"molecule\"}");
+    assert_eq!(code, 0);
+    let bytes = hex::decode(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let output = OutputReader::from_slice(&bytes).unwrap();
+    assert_eq!(output.traits().len(), 6);
+    assert_eq!(output.images().len(), 2);
+    let image = output.images().get(0).unwrap();
+    assert_eq!(image.image_type().raw_data(), b"image/png;base64");
+    assert!(image.error().is_none());
+
+    // numbers beyond `Uint64` and floats survive a round trip through the DOB/0 decoder
+    let dob0_output = "[{\"name\":\"Age\",\"traits\":[{\"Number\":-5}]},{\"name\":\"Score\",\"traits\":[{\"Float\":1.5}]}]";
+    let (code, chunks) = run(
+        dob0_output,
+        "{\"encoding\":\"molecule\",\"mode\":\"traits\"}",
+    );
+    assert_eq!(code, 0);
+    let bytes = hex::decode(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let output = OutputReader::from_slice(&bytes).unwrap();
+    let traits = decode_dob0_output(output.traits().as_slice()).unwrap();
+    assert!(matches!(traits[0].traits[..], [ParsedTrait::Number(-5)]));
+    assert!(matches!(traits[1].traits[..], [ParsedTrait::Float(score)] if score == 1.5));
+
+    // neither json frames nor batches can carry a binary encoding
+    let (code, _) = run(DOB0_OUTPUT, "{\"encoding\":\"cbor\",\"output\":\"framed\"}");
+    assert_eq!(code, Error::ParseInvalidConfig as u64);
+}
+
+#[test]
+fn test_run_invalid_argv() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{}", "{}", "{}", "{}"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 1);
+    assert!(chunks.is_empty());
+}
+
+#[test]
+fn test_run_validation_only() {
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[IMAGES_BASE],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(calls, 0);
+    let report: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(report["valid"], true);
+
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Alice\",\"#00F\"],[\"Bob\",\"red\"],[\"Alice\",\"#FFF\"]]],[\"1\",\"uri\",\"Age\",\"range\",[[[0,50],\"ftp://a\"],[[40,60],\"ipfs://b\"],[[\"*\"],\"ipfs://c\"],[[70,80],\"ipfs://d\"]]],[\"0\",\"uri\",\"URL\",\"raw\"]]";
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[images_base],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, Error::ValidatePatternFailed as u64);
+    let report: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(report["valid"], false);
+    let issues = report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| {
+            format!(
+                "{} {}:{} {}",
+                issue["severity"].as_str().unwrap(),
+                issue["group"].as_str().unwrap(),
+                issue["row"],
+                issue["message"].as_str().unwrap()
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        issues,
+        [
+            "error 0:2 duplicate group name",
+            "error 0:0 invalid color code red",
+            "warning 0:0 args entry 2 is shadowed by entry 0",
+            "error 1:1 unsupported uri scheme in ftp://a",
+            "warning 1:1 args entry 1 overlaps entry 0",
+            "error 1:1 args entry 3 is shadowed by entry 2",
+        ]
+    );
+    // strict violations carry the error a strict decoding run fails with
+    let codes = report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["code"].as_u64())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Error::SchemaShadowedEntry as u64)
+        ]
+    );
+
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &["[[\"0\",\"color\",\"Name\",\"options\",[]],[\"0\",\"color\",\"Name\",\"options\",[]]]"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, Error::ValidatePatternFailed as u64);
+    let report: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(report["issues"][0]["row"], 1);
+    assert_eq!(
+        report["issues"][0]["code"],
+        Error::SchemaDuplicateRow as u64
+    );
+
+    // columns past the options fail parsing in validation
+    let code = run_with(
+        &["[[\"0\",\"color\",\"*\",\"raw\",\"#000000\",{},null]]"],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, Error::SchemaUnexpectedColumn as u64);
+
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &["[[\"0\"]]"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, Error::SchemaInsufficientElements as u64);
+    assert_eq!(
+        chunks[0],
+        format!("{{\"valid\":false,\"error\":{code},\"issues\":[]}}\0").as_bytes()
+    );
+}
+
+#[test]
+fn test_run_recover_failed_group() {
+    // the second group fails inside the combiner while filling the probed buffer
+    let mut calls = 0;
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+        calls += 1;
+        if calls > 3 {
+            return 1;
+        }
+        *buffer_size = 3;
+        if buffer.len() >= 3 {
+            buffer[..3].copy_from_slice(b"png");
+        }
+        0
+    };
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            IMAGES_BASE,
+            "{\"recover\":true,\"combine_buffer_size\":0}",
+        ],
+        combiner,
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let images = output["images"].as_array().unwrap();
+    assert_eq!(images[0]["content"], "cG5n");
+    assert!(images[0].get("error").is_none());
+    assert_eq!(images[1]["name"], "1");
+    assert_eq!(images[1]["type"], "error");
+    assert_eq!(images[1]["error"], Error::CombineSyscallFailed as u64);
+    assert_eq!(images[1]["content"], "CombineSyscallFailed");
+
+    let mut calls = 0;
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"combine_buffer_size\":0}"],
+        |_: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+            calls += 1;
+            *buffer_size = 3;
+            (calls > 1) as u64
+        },
+        |_| {},
+    );
+    assert_eq!(code, Error::CombineSyscallFailed as u64);
+
+    // `lenient` is the same switch, here every image fails on a mime header that is not utf-8
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"lenient\":true}"],
+        fixed_combiner(b"\0\xff\0png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    for image in output["images"].as_array().unwrap() {
+        assert_eq!(image["type"], "error");
+        assert_eq!(image["content"], "OutputNotUtf8");
+    }
+}
+
+#[test]
+fn test_run_recover_unresolved_group() {
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]],[\"1\",\"color\",\"Name\",\"options\"]]";
+    let mut calls = 0;
+    let code = run_with(
+        &[DOB0_OUTPUT, images_base],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, Error::DecodeInvalidOptionArgs as u64);
+    assert_eq!(calls, 0);
+
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            images_base,
+            "{\"recover\":true,\"output\":\"framed\"}",
+        ],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(calls, 1);
+    let (channel, image, _) = unframe(&chunks[2]).expect("image frame");
+    assert_eq!(channel, Channel::Image);
+    assert_eq!(
+        image,
+        format!(
+            "{{\"name\":\"1\",\"type\":\"error\",\"content\":\"DecodeInvalidOptionArgs\",\"error\":{}}}",
+            Error::DecodeInvalidOptionArgs as u64
+        )
+        .as_bytes()
+    );
+}
+
+#[test]
+fn test_run_output_limits() {
+    let run = |config: &str| {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &[DOB0_OUTPUT, IMAGES_BASE, config],
+            fixed_combiner(&[1; 96], &mut calls),
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        (code, chunks)
+    };
+    let images = |chunk: &[u8]| {
+        let output: serde_json::Value = serde_json::from_slice(&chunk[..chunk.len() - 1]).unwrap();
+        output["images"].as_array().unwrap().clone()
+    };
+
+    let (code, _) = run("{\"max_image_bytes\":95}");
+    assert_eq!(code, Error::OutputTooLarge as u64);
+    let (code, chunks) = run("{\"max_image_bytes\":95,\"recover\":true}");
+    assert_eq!(code, 0);
+    assert_eq!(images(&chunks[0])[1]["content"], "OutputTooLarge");
+
+    // the claimed size alone fails the image, before a buffer of it is allocated
+    let mut calls = 0;
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"max_image_bytes\":1024}"],
+        |_: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+            calls += 1;
+            *buffer_size = u64::MAX;
+            0
+        },
+        |_| {},
+    );
+    assert_eq!(code, Error::OutputTooLarge as u64);
+    assert_eq!(calls, 1);
+
+    let (_, chunks) = run("{}");
+    let size = chunks[0].len();
+    let (code, chunks) = run(&format!("{{\"max_output_bytes\":{}}}", size - 1));
+    assert_eq!(code, Error::OutputTooLarge as u64);
+    assert!(chunks.is_empty());
+
+    // images give way to markers from the last one on until the output fits
+    let (code, chunks) = run(&format!(
+        "{{\"max_output_bytes\":{},\"overflow\":\"truncate\"}}",
+        size - 1
+    ));
+    assert_eq!(code, 0);
+    assert!(chunks[0].len() < size);
+    let truncated = images(&chunks[0]);
+    assert_eq!(truncated[0]["type"], "image/png;base64");
+    assert_eq!(truncated[1]["type"], "truncated");
+    assert_eq!(truncated[1]["error"], Error::OutputTooLarge as u64);
+    let (code, _) = run("{\"max_output_bytes\":10,\"overflow\":\"truncate\"}");
+    assert_eq!(code, Error::OutputTooLarge as u64);
+
+    // without the stats frame of `trace` builds, which is dropped when it doesn't fit
+    let (_, chunks) = run("{\"output\":\"framed\"}");
+    let size = chunks
+        .iter()
+        .filter(|chunk| !matches!(unframe(chunk), Some((Channel::Diagnostic, _, _))))
+        .map(Vec::len)
+        .sum::<usize>();
+    let (code, chunks) = run(&format!(
+        "{{\"output\":\"framed\",\"max_output_bytes\":{},\"overflow\":\"truncate\"}}",
+        size - 1
+    ));
+    assert_eq!(code, 0);
+    let last = chunks
+        .iter()
+        .rfind(|chunk| matches!(unframe(chunk), Some((Channel::Image, _, _))))
+        .unwrap();
+    let (_, image, _) = unframe(last).expect("image frame");
+    let image: serde_json::Value = serde_json::from_slice(image).unwrap();
+    assert_eq!(image["type"], "truncated");
+}
+
+#[test]
+fn test_run_report() {
+    // a second row of group "1" matching nothing is skipped
+    let images_base = format!(
+        "{},[\"1\",\"color\",\"Name\",\"options\",[[\"Bob\",\"#00FF00\"]]]]",
+        &IMAGES_BASE[..IMAGES_BASE.len() - 1]
+    );
+    let run = |argv: &[&str]| {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(argv, fixed_combiner(b"png", &mut calls), |chunk| {
+            chunks.push(chunk.to_vec())
+        });
+        (code, chunks)
+    };
+    let expected = serde_json::json!({
+        "images": [
+            { "name": "0", "type": "image/png;base64", "bytes": 3 },
+            { "name": "1", "type": "image/png;base64", "bytes": 3 },
+        ],
+        "layers_matched": 4,
+        "layers_skipped": 1,
+    });
+
+    let (code, chunks) = run(&[DOB0_OUTPUT, "--report", &images_base]);
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(output["images"].as_array().unwrap().len(), 2);
+    assert_eq!(output["report"], expected);
+
+    let (code, chunks) = run(&[DOB0_OUTPUT, &images_base, "--report-only"]);
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(output, serde_json::json!({ "report": expected }));
+
+    // framed runs write the report in a diagnostic frame after the images
+    let (code, chunks) = run(&[
+        DOB0_OUTPUT,
+        &images_base,
+        "{\"output\":\"framed\"}",
+        "--report",
+    ]);
+    assert_eq!(code, 0);
+    let (channel, report, _) = unframe(chunks.last().unwrap()).expect("report frame");
+    assert_eq!(channel, Channel::Diagnostic);
+    let report: serde_json::Value = serde_json::from_slice(report).unwrap();
+    assert_eq!(report, serde_json::json!({ "report": expected }));
+
+    let (code, _) = run(&[
+        DOB0_OUTPUT,
+        &images_base,
+        "{\"output\":\"framed\"}",
+        "--report-only",
+    ]);
+    assert_eq!(code, Error::ParseInvalidConfig as u64);
+    let (code, _) = run(&[DOB0_OUTPUT, &images_base, "--verbose"]);
+    assert_eq!(code, Error::ParseInvalidFlag as u64);
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_run_diagnostics() {
+    // a host keeping the diagnostics apart from the output
+    struct Logger(Vec<Vec<u8>>);
+
+    impl Host for Logger {
+        fn combine(&mut self, _: &mut [u8], _: &mut u64, _: &[u8]) -> u64 {
+            1
+        }
+
+        fn write(&mut self, _: &[u8]) -> u64 {
+            0
+        }
+
+        fn write_log(&mut self, frame: &[u8]) -> u64 {
+            self.0.push(frame.to_vec());
+            0
+        }
+    }
+
+    let run = |args: &[&str]| {
+        let mut logger = Logger(Vec::new());
+        let args = args.iter().map(|arg| arg.as_bytes()).collect();
+        let code = dobs_run_host(args, &mut logger);
+        (code, logger.0)
+    };
+    let find = |frames: &[Vec<u8>], name: &str| {
+        frames.iter().find_map(|frame| {
+            let (channel, payload, _) = unframe(frame)?;
+            let diagnostic: serde_json::Value = serde_json::from_slice(payload).ok()?;
+            (channel == Channel::Diagnostic && diagnostic["name"] == name).then_some(diagnostic)
+        })
+    };
+
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]],[\"1\",\"color\",\"Level\",\"template\",\"x\"]]";
+    let (code, frames) = run(&[DOB0_OUTPUT, images_base]);
+    assert_eq!(code, Error::SchemaPatternMismatch as u64);
+    let diagnostic = find(&frames, "SchemaPatternMismatch").expect("schema diagnostic");
+    assert_eq!(diagnostic["error"], code);
+    assert_eq!(diagnostic["row"], 1);
+    assert_eq!(diagnostic["trait"], "Level");
+    assert_eq!(diagnostic["value"][3], "template");
+
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]],[\"1\",\"uri\",\"Name\",\"raw\"]]";
+    let config = "{\"uri_schemes\":[\"https\"]}";
+    let (code, frames) = run(&[DOB0_OUTPUT, images_base, config]);
+    assert_eq!(code, Error::DecodeUnsupportedURIScheme as u64);
+    let diagnostic = find(&frames, "DecodeUnsupportedURIScheme").expect("layer diagnostic");
+    assert_eq!(diagnostic["row"], 1);
+    assert_eq!(diagnostic["trait"], "Name");
+    assert_eq!(diagnostic["value"], serde_json::json!(["Ethan"]));
+
+    // diagnostics spend the syscall budget like the output, past it they are dropped
+    let config = "{\"uri_schemes\":[\"https\"],\"syscall_budget\":0}";
+    let (code, frames) = run(&[DOB0_OUTPUT, images_base, config]);
+    assert_eq!(code, Error::DecodeUnsupportedURIScheme as u64);
+    assert!(frames.is_empty());
+}
+
+#[test]
+fn test_run_rejects_overclaimed_image_size() {
+    // always asks for one byte more than the buffer it was given
+    let mut calls = 0;
+    let combiner = |buffer: &mut [u8], buffer_size: &mut u64, _: &[u8]| {
+        calls += 1;
+        *buffer_size = buffer.len() as u64 + 1;
+        0
+    };
+    let code = run_with(&[DOB0_OUTPUT, IMAGES_BASE], combiner, |_| {});
+    assert_eq!(code, Error::ComposeImageFailed as u64);
+    // retried once with the size asked for, then given up
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_run_syscall_budget() {
+    // two groups take four combine calls and one write
+    let mut calls = 0;
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            IMAGES_BASE,
+            "{\"syscall_budget\":5,\"combine_buffer_size\":0}",
+        ],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, 0);
+
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            IMAGES_BASE,
+            "{\"syscall_budget\":4,\"output\":\"framed\",\"recover\":true,\"combine_buffer_size\":0}",
+        ],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, Error::SyscallBudgetExceeded as u64);
+    assert_eq!(calls, 2);
+    let (channel, payload, _) = unframe(chunks.last().unwrap()).expect("error frame");
+    assert_eq!(channel, Channel::Diagnostic);
+    assert_eq!(
+        payload,
+        format!("{{\"error\":{}}}", Error::SyscallBudgetExceeded as u64).as_bytes()
+    );
+}
+
+#[test]
+fn test_run_audit_items() {
+    let mut calls = 0;
+    let mut seen = Vec::new();
+    let mut chunks = Vec::new();
+    let mut combiner = fixed_combiner(b"png", &mut calls);
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"audit\":true}"],
+        |buffer: &mut [u8], buffer_size: &mut u64, items: &[u8]| {
+            seen.push(hex::encode(items));
+            combiner(buffer, buffer_size, items)
+        },
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    assert_eq!(output["images"][0]["items"], seen[0].as_str());
+    assert_eq!(output["images"][1]["items"], seen[1].as_str());
+}
+
+#[test]
+fn test_run_per_group_format() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\",{\"format\":\"svg\",\"aspect_ratio\":[3,4]}],[\"0\",\"uri\",\"URL\",\"raw\"],[\"1\",\"uri\",\"URL\",\"raw\",null,{\"format\":\"urls\"}],[\"2\",\"uri\",\"URL\",\"raw\"]]";
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, images_base],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(calls, 1);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let images = output["images"].as_array().unwrap();
+    assert_eq!(images[0]["type"], "image/svg+xml");
+    assert_eq!(
+        images[0]["content"],
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 3 4\"><rect width=\"100%\" height=\"100%\" fill=\"#FFFFFF\"/><image href=\"http://127.0.0.1:8090\" width=\"100%\" height=\"100%\"/></svg>"
+    );
+    assert_eq!(images[1]["type"], "text/uri-list");
+    assert_eq!(images[1]["content"], "http://127.0.0.1:8090");
+    assert_eq!(images[2]["type"], "image/png;base64");
+}
+
+#[test]
+fn test_run_svg_fragments() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"0\",\"svg\",\"Age\",\"range\",[[[0,50],\"<circle r=\\\"1\\\"/>\"],[[\"*\"],\"<path d=\\\"M0 0\\\"/>\"]]],[\"1\",\"color\",\"*\",\"raw\",\"#000000\"]]";
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, images_base],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    // only the plain color group reaches the combiner
+    assert_eq!(calls, 1);
+    let output: serde_json::Value =
+        serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+    let images = output["images"].as_array().unwrap();
+    assert_eq!(images[0]["type"], "image/svg+xml");
+    assert_eq!(
+        images[0]["content"],
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"><rect width=\"100%\" height=\"100%\" fill=\"#FFFFFF\"/><circle r=\"1\"/></svg>"
+    );
+    assert_eq!(images[1]["type"], "image/png;base64");
+}
+
+#[cfg(feature = "compositor")]
+#[test]
+fn test_run_compositor_fallback() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FF0000\"]]";
+    // a host without the combiner and one that doesn't know the item
+    for answer in [1, COMBINE_UNSUPPORTED_ITEM] {
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &[
+                DOB0_OUTPUT,
+                images_base,
+                "{\"thumbnail\":{\"width\":1,\"height\":1}}",
+            ],
+            |_: &mut [u8], _: &mut u64, _: &[u8]| answer,
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        assert_eq!(code, 0);
+        let output: serde_json::Value =
+            serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+        let png = STANDARD
+            .decode(output["images"][0]["content"].as_str().unwrap())
+            .unwrap();
+        let bitmap = crate::decoder::compositor::decode_png(&png).unwrap();
+        assert_eq!(bitmap.pixels, [255, 0, 0, 255]);
+    }
+}
+
+#[test]
+fn test_decode_without_writing() {
+    let mut calls = 0;
+    let args = vec![DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()];
+    let dob1_output = dobs_decode(args, fixed_combiner(b"png", &mut calls)).expect("decode failed");
+    assert_eq!(calls, 2);
+    assert_eq!(dob1_output.traits.unwrap().len(), 6);
+    assert_eq!(dob1_output.images.len(), 2);
+    assert_eq!(dob1_output.images[0].content, "cG5n");
+    assert!(dob1_output.stats.is_none());
+
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        "[[\"0\",\"uri\",\"URL\"]]".as_bytes(),
+    ];
+    assert!(matches!(
+        dobs_decode(args, fixed_combiner(b"png", &mut calls)),
+        Err(Error::SchemaInsufficientElements)
+    ));
+}
+
+// answers every batch of two or more patterns with `image<index>` per pattern and turns
+// down single pattern batches as a host without the syscall would
+#[derive(Default)]
+struct Batcher {
+    batches: usize,
+    calls: usize,
+    output: Vec<u8>,
+}
+
+impl Host for Batcher {
+    fn combine(&mut self, buffer: &mut [u8], buffer_size: &mut u64, pattern: &[u8]) -> u64 {
+        fixed_combiner(b"png", &mut self.calls)(buffer, buffer_size, pattern)
+    }
+
+    fn write(&mut self, output: &[u8]) -> u64 {
+        self.output.extend_from_slice(output);
+        0
+    }
+
+    // only the output is looked at, whatever the log level
+    fn write_log(&mut self, _: &[u8]) -> u64 {
+        0
+    }
+
+    fn combines_batches(&self) -> bool {
+        true
+    }
+
+    fn combine_batch(&mut self, buffer: &mut [u8], buffer_size: &mut u64, patterns: &[u8]) -> u64 {
+        self.batches += 1;
+        let patterns = ItemVecVecReader::from_slice(patterns).expect("batched patterns");
+        if patterns.len() < 2 {
+            return 1;
+        }
+        let images = (0..patterns.len())
+            .map(|index| {
+                Image::new_builder()
+                    .set(
+                        format!("image{index}")
+                            .bytes()
+                            .map(molecule::prelude::Byte::new)
+                            .collect(),
+                    )
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let images = ImageVec::new_builder().set(images).build();
+        *buffer_size = images.as_slice().len() as u64;
+        if buffer.len() >= images.as_slice().len() {
+            buffer[..images.as_slice().len()].copy_from_slice(images.as_slice());
+        }
+        0
+    }
+}
+
+#[test]
+fn test_run_batched_combine() {
+    let run = |images_base: &str| {
+        let mut host = Batcher::default();
+        let args = vec![
+            DOB0_OUTPUT.as_bytes(),
+            images_base.as_bytes(),
+            b"{\"batch\":true}",
+        ];
+        assert_eq!(dobs_run_host(args, &mut host), 0);
+        let output: serde_json::Value =
+            serde_json::from_slice(&host.output[..host.output.len() - 1]).unwrap();
+        (output, host.batches, host.calls)
+    };
+    let (output, batches, calls) = run(IMAGES_BASE);
+    assert_eq!((batches, calls), (1, 0));
+    assert_eq!(output["images"][0]["content"], STANDARD.encode("image0"));
+    assert_eq!(output["images"][1]["content"], STANDARD.encode("image1"));
+
+    // a declined batch falls back to one combine call per image
+    let (output, batches, calls) = run("[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"]]");
+    assert_eq!((batches, calls), (1, 1));
+    assert_eq!(output["images"][0]["content"], "cG5n");
+
+    // so does a host without the batched syscall
+    let mut calls = 0;
+    let mut chunks = Vec::new();
+    let code = run_with(
+        &[DOB0_OUTPUT, IMAGES_BASE, "{\"batch\":true}"],
+        fixed_combiner(b"png", &mut calls),
+        |chunk| chunks.push(chunk.to_vec()),
+    );
+    assert_eq!(code, 0);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_run_deduplicated_images() {
+    let images_base = "[[\"0\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"1\",\"color\",\"*\",\"raw\",\"#FFFFFF\"],[\"2\",\"color\",\"*\",\"raw\",\"#000000\"]]";
+    let run = |config: &str| {
+        let mut calls = 0;
+        let mut chunks = Vec::new();
+        let code = run_with(
+            &[DOB0_OUTPUT, images_base, config],
+            fixed_combiner(b"png", &mut calls),
+            |chunk| chunks.push(chunk.to_vec()),
+        );
+        assert_eq!(code, 0);
+        let output: serde_json::Value =
+            serde_json::from_slice(&chunks[0][..chunks[0].len() - 1]).unwrap();
+        (output, calls)
+    };
+
+    // the shared pattern is combined once and its image repeated
+    let (output, calls) = run("{}");
+    assert_eq!(calls, 2);
+    assert_eq!(output["images"][1]["content"], "cG5n");
+    assert!(output["images"][1].get("ref").is_none());
+
+    let (output, calls) = run("{\"dedupe\":true}");
+    assert_eq!(calls, 2);
+    assert_eq!(output["images"][0]["content"], "cG5n");
+    assert_eq!(output["images"][1]["content"], "");
+    assert_eq!(output["images"][1]["ref"], "0");
+    assert_eq!(output["images"][1]["type"], "image/png;base64");
+    assert!(output["images"][2].get("ref").is_none());
+
+    // a chained reference resolves to the image holding the content
+    let chained = serde_json::to_string(&output).unwrap();
+    let images_base = "[[\"0\",\"image\",\"*\",\"raw\",\"dob1:1\"]]";
+    let mut calls = 0;
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        b"{}",
+        chained.as_bytes(),
+    ];
+    dobs_decode(args, fixed_combiner(b"png", &mut calls)).expect("decode failed");
+
+    let mut calls = 0;
+    let code = run_with(
+        &[
+            DOB0_OUTPUT,
+            images_base,
+            "{\"dedupe\":true,\"encoding\":\"molecule\"}",
+        ],
+        fixed_combiner(b"png", &mut calls),
+        |_| {},
+    );
+    assert_eq!(code, Error::ParseInvalidConfig as u64);
+}
@@ -0,0 +1,56 @@
+use alloc::{format, vec, vec::Vec};
+
+use crate::simulator::{syscall_combine_image, PLACEHOLDER_PNG};
+
+#[cfg(not(feature = "compositor"))]
+#[test]
+fn test_simulated_combine() {
+    let mut buffer = vec![];
+    let mut buffer_size = 0u64;
+    assert_eq!(syscall_combine_image(&mut buffer, &mut buffer_size, &[]), 0);
+    assert_eq!(buffer_size, PLACEHOLDER_PNG.len() as u64);
+    buffer.resize(buffer_size as usize, 0);
+    assert_eq!(syscall_combine_image(&mut buffer, &mut buffer_size, &[]), 0);
+    assert_eq!(buffer, PLACEHOLDER_PNG);
+}
+
+#[cfg(feature = "compositor")]
+#[test]
+fn test_simulated_combine_defers_to_compositor() {
+    let mut buffer = vec![];
+    let mut buffer_size = 0u64;
+    assert_ne!(syscall_combine_image(&mut buffer, &mut buffer_size, &[]), 0);
+    assert_eq!(buffer_size, 0);
+    let bitmap = crate::decoder::compositor::decode_png(&PLACEHOLDER_PNG).expect("decode");
+    assert_eq!((bitmap.width, bitmap.height), (1, 1));
+}
+
+#[test]
+fn test_write_output() {
+    use super::decoder::DOB0_OUTPUT;
+    use crate::decoder::pipeline::dobs_decode;
+    use crate::simulator::write_output;
+
+    // color layers only, which the compositor can draw too once it knows the size
+    let images_base = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Ethan\",\"#FF0000\"]]],[\"a b\",\"color\",\"*\",\"raw\",\"#00FF00\"]]";
+    let config = "{\"thumbnail\":{\"width\":1,\"height\":1}}";
+    let args = vec![
+        DOB0_OUTPUT.as_bytes(),
+        images_base.as_bytes(),
+        config.as_bytes(),
+    ];
+    let output = dobs_decode(args, syscall_combine_image).expect("decode failed");
+    let dir = std::env::temp_dir().join(format!("dob1-cli-{}", std::process::id()));
+    let paths = write_output(&dir, &output).expect("write failed");
+    let names = paths
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["traits.json", "0.png", "a_b.png"]);
+    let png = std::fs::read(dir.join("0.png")).unwrap();
+    assert_eq!(png[..8], PLACEHOLDER_PNG[..8]);
+    let traits: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(dir.join("traits.json")).unwrap()).unwrap();
+    assert_eq!(traits.as_array().unwrap().len(), 6);
+    std::fs::remove_dir_all(dir).unwrap();
+}
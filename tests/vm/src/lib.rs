@@ -0,0 +1,182 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use ckb_vm::{
+    machine::{DefaultCoreMachine, DefaultMachineBuilder, SupportMachine, VERSION2},
+    memory::{sparse::SparseMemory, wxorx::WXorXMemory, Memory},
+    registers::{A0, A1, A2, A3, A7},
+    Bytes, Error, Register, Syscalls, ISA_A, ISA_B, ISA_IMC, ISA_MOP,
+};
+
+const SYSCALL_COMBINE_IMAGE: u64 = 2077;
+const SYSCALL_LOAD_TRANSACTION: u64 = 2051;
+const SYSCALL_LOAD_CELL_BY_FIELD: u64 = 2081;
+const SYSCALL_LOAD_CELL_DATA: u64 = 2092;
+const SYSCALL_DEBUG: u64 = 2177;
+
+// what loaders answer for a cell that isn't there
+const INDEX_OUT_OF_BOUND: u64 = 1;
+
+const MAX_CYCLES: u64 = 3_500_000_000;
+
+// the host side of the combine syscall, same shape as `dobs_run`'s combiner: the buffer
+// to fill, the full size of the image and the molecule `ItemVec` describing it
+pub type Combiner = Box<dyn FnMut(&mut Vec<u8>, &[u8]) -> u64 + Send>;
+
+// reports `image` for every combine call, the default of most tests
+pub fn fixed_combiner(image: &'static [u8]) -> Combiner {
+    Box::new(move |buffer, _| {
+        buffer.extend_from_slice(image);
+        0
+    })
+}
+
+#[derive(Debug, Default)]
+pub struct Run {
+    pub exit_code: i8,
+    pub cycles: u64,
+    // every string passed to the debug syscall, output chunks and log records alike
+    pub writes: Vec<Vec<u8>>,
+    // the `ItemVec` of every combine call in order
+    pub combines: Vec<Vec<u8>>,
+}
+
+impl Run {
+    // everything the decoder wrote, the host reads each write up to its nul
+    pub fn output(&self) -> Vec<u8> {
+        self.writes.concat()
+    }
+}
+
+struct Host {
+    combiner: Combiner,
+    run: Arc<Mutex<Run>>,
+}
+
+impl<Mac: SupportMachine> Syscalls<Mac> for Host {
+    fn initialize(&mut self, _machine: &mut Mac) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn ecall(&mut self, machine: &mut Mac) -> Result<bool, Error> {
+        let code = match machine.registers()[A7].to_u64() {
+            SYSCALL_DEBUG => {
+                let text = load_c_string(machine, machine.registers()[A0].to_u64())?;
+                self.run.lock().unwrap().writes.push(text);
+                0
+            }
+            SYSCALL_COMBINE_IMAGE => self.combine(machine)?,
+            SYSCALL_LOAD_TRANSACTION | SYSCALL_LOAD_CELL_BY_FIELD | SYSCALL_LOAD_CELL_DATA => {
+                INDEX_OUT_OF_BOUND
+            }
+            _ => return Ok(false),
+        };
+        machine.set_register(A0, Mac::REG::from_u64(code));
+        Ok(true)
+    }
+}
+
+impl Host {
+    // the buffer size goes in as the room the decoder gave and comes back as the size of
+    // the whole image, which is only copied when it fits
+    fn combine<Mac: SupportMachine>(&mut self, machine: &mut Mac) -> Result<u64, Error> {
+        let buffer = machine.registers()[A0].to_u64();
+        let size_ptr = machine.registers()[A1].to_u64();
+        let molecule = machine.registers()[A2].to_u64();
+        let length = machine.registers()[A3].to_u64();
+        let capacity = machine
+            .memory_mut()
+            .load64(&Mac::REG::from_u64(size_ptr))?
+            .to_u64();
+        let items = load_bytes(machine, molecule, length)?;
+        let mut image = Vec::new();
+        let code = (self.combiner)(&mut image, &items);
+        self.run.lock().unwrap().combines.push(items);
+        if code != 0 {
+            return Ok(code);
+        }
+        if image.len() as u64 <= capacity {
+            machine.memory_mut().store_bytes(buffer, &image)?;
+        }
+        machine.memory_mut().store64(
+            &Mac::REG::from_u64(size_ptr),
+            &Mac::REG::from_u64(image.len() as u64),
+        )?;
+        Ok(0)
+    }
+}
+
+fn load_bytes<Mac: SupportMachine>(
+    machine: &mut Mac,
+    addr: u64,
+    len: u64,
+) -> Result<Vec<u8>, Error> {
+    (addr..addr + len)
+        .map(|addr| {
+            let byte = machine.memory_mut().load8(&Mac::REG::from_u64(addr))?;
+            Ok(byte.to_u8())
+        })
+        .collect()
+}
+
+fn load_c_string<Mac: SupportMachine>(machine: &mut Mac, mut addr: u64) -> Result<Vec<u8>, Error> {
+    let mut text = Vec::new();
+    loop {
+        let byte = machine
+            .memory_mut()
+            .load8(&Mac::REG::from_u64(addr))?
+            .to_u8();
+        if byte == 0 {
+            return Ok(text);
+        }
+        text.push(byte);
+        addr += 1;
+    }
+}
+
+// the release binary of the decoder, `SPORE_DOB_1_BINARY` points elsewhere
+pub fn binary() -> Bytes {
+    let path = env::var_os("SPORE_DOB_1_BINARY")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../../target/riscv64imac-unknown-none-elf/release/spore-dob-1")
+        });
+    let binary = fs::read(&path)
+        .unwrap_or_else(|err| panic!("no decoder binary at {}: {err}", path.display()));
+    Bytes::from(binary)
+}
+
+// runs the decoder with `argv` the way a DOB/1 host does, `combiner` answering the
+// combine syscalls
+pub fn run(argv: &[&[u8]], combiner: Combiner) -> Result<Run, Error> {
+    let run = Arc::new(Mutex::new(Run::default()));
+    let core = DefaultCoreMachine::<u64, WXorXMemory<SparseMemory<u64>>>::new(
+        ISA_IMC | ISA_A | ISA_B | ISA_MOP,
+        VERSION2,
+        MAX_CYCLES,
+    );
+    let host = Host {
+        combiner,
+        run: run.clone(),
+    };
+    let mut machine = DefaultMachineBuilder::new(core)
+        .instruction_cycle_func(Box::new(|_| 1))
+        .syscall(Box::new(host))
+        .build();
+    let args = argv.iter().map(|arg| Ok(Bytes::copy_from_slice(arg)));
+    machine.load_program(&binary(), args)?;
+    let exit_code = machine.run()?;
+    let cycles = machine.cycles();
+    drop(machine);
+    let mut run = Arc::try_unwrap(run)
+        .ok()
+        .and_then(|run| run.into_inner().ok())
+        .unwrap_or_default();
+    run.exit_code = exit_code;
+    run.cycles = cycles;
+    Ok(run)
+}
@@ -0,0 +1,75 @@
+use serde_json::Value;
+use spore_dob_1_vm::{fixed_combiner, run};
+
+const DOB0_OUTPUT: &str = "[{\"name\":\"Name\",\"traits\":[{\"String\":\"Ethan\"}]},{\"name\":\"Age\",\"traits\":[{\"Number\":23}]}]";
+const IMAGES_BASE: &str = "[[\"0\",\"color\",\"Name\",\"options\",[[\"Alice\",\"#0000FF\"],[\"Ethan\",\"#FF0000\"],[[\"*\"],\"#FFFFFF\"]]],[\"0\",\"uri\",\"Age\",\"range\",[[[0,50],\"btcfs://b2f4560f17679d3e3fca66209ac425c660d28a252ef72444c3325c6eb0364393i0\"],[[\"*\"],\"btcfs://11b6303eb7d887d7ade459ac27959754cd55f9f9e50345ced8e1e8f47f4581fai0\"]]],[\"1\",\"color\",\"Name\",\"options\",[[[\"*\"],\"#000000\"]]]]";
+
+#[test]
+fn test_vm_decode() {
+    let result = run(
+        &[DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()],
+        fixed_combiner(b"png"),
+    )
+    .expect("vm failed");
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.combines.len(), 2);
+    let output: Value = serde_json::from_slice(&result.output()).expect("output is not json");
+    let images = output["images"].as_array().unwrap();
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0]["name"], "0");
+    assert_eq!(images[0]["content"], "cG5n");
+    assert_eq!(output["traits"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_vm_hex_args() {
+    let dob0_output = hex(DOB0_OUTPUT.as_bytes());
+    let images_base = hex(IMAGES_BASE.as_bytes());
+    let result = run(
+        &[dob0_output.as_bytes(), images_base.as_bytes()],
+        fixed_combiner(b"png"),
+    )
+    .expect("vm failed");
+    assert_eq!(result.exit_code, 0);
+    let output: Value = serde_json::from_slice(&result.output()).expect("output is not json");
+    assert_eq!(output["images"].as_array().unwrap().len(), 2);
+}
+
+// an image larger than the first buffer takes the probe and a second fill
+#[test]
+fn test_vm_combine_refill() {
+    static IMAGE: [u8; 100_000] = [7; 100_000];
+    let result = run(
+        &[DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()],
+        fixed_combiner(&IMAGE),
+    )
+    .expect("vm failed");
+    assert_eq!(result.exit_code, 0);
+    assert_eq!(result.combines.len(), 4);
+}
+
+#[test]
+fn test_vm_error_exit_codes() {
+    let result = run(&[DOB0_OUTPUT.as_bytes()], fixed_combiner(b"png")).expect("vm failed");
+    assert_ne!(result.exit_code, 0);
+    assert!(result.combines.is_empty());
+
+    let result = run(
+        &[DOB0_OUTPUT.as_bytes(), b"[[\"0\",\"color\"]]"],
+        fixed_combiner(b"png"),
+    )
+    .expect("vm failed");
+    assert_ne!(result.exit_code, 0);
+
+    // a host without the combine syscall fails the run instead of emitting empty images
+    let result = run(
+        &[DOB0_OUTPUT.as_bytes(), IMAGES_BASE.as_bytes()],
+        Box::new(|_, _| 1),
+    )
+    .expect("vm failed");
+    assert_ne!(result.exit_code, 0);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}